@@ -1,7 +1,7 @@
 use miette::{IntoDiagnostic, Result};
 use wgsl_bindgen::{
-  GlamWgslTypeMap, Regex, WgslBindgenOptionBuilder, WgslShaderIrCapabilities,
-  WgslShaderSourceType, WgslTypeSerializeStrategy,
+  GlamWgslTypeMap, Regex, VertexStepMode, WgslBindgenOptionBuilder,
+  WgslShaderIrCapabilities, WgslShaderSourceType, WgslTypeSerializeStrategy,
 };
 
 fn main() -> Result<()> {
@@ -27,11 +27,13 @@ fn generate_demo_bindings() -> Result<()> {
         | WgslShaderIrCapabilities::TEXTURE_AND_SAMPLER_BINDING_ARRAY,
     )
     .add_custom_padding_field_regexp(Regex::new("_pad.*").unwrap())
+    .override_vertex_step_mode([(".*Instance$", VertexStepMode::Instance).into()])
     .short_constructor(2)
     .shader_source_type(
       WgslShaderSourceType::EmbedSource | WgslShaderSourceType::ComposerWithRelativePath,
     )
     .derive_serde(false)
+    .layout_tests(true)
     .output("src/shader_bindings.rs")
     .build()?
     .generate()
@@ -1,7 +1,11 @@
 #import global_bindings::{get_time, get_frame_size}
 
-struct VertexInput {
+struct QuadVertex {
     @location(0) quad_pos: vec2<f32>,           // Quad vertex position (-1 to 1)
+}
+
+// Stepped per instance, see `override_vertex_step_mode` in build.rs.
+struct ParticleInstance {
     @location(1) position_and_size: vec4<f32>,  // xyz = particle position, w = particle_type + energy
 }
 
@@ -14,7 +18,7 @@ struct VertexOutput {
 }
 
 @vertex
-fn vs_main(input: VertexInput) -> VertexOutput {
+fn vs_main(vertex: QuadVertex, instance: ParticleInstance) -> VertexOutput {
     var output: VertexOutput;
     
     // Get frame size for proper aspect ratio
@@ -22,8 +26,8 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     let aspect_ratio = frame.x / frame.y;
     
     // Extract particle data
-    let particle_world_pos = input.position_and_size.xyz;
-    let particle_data = input.position_and_size.w;
+    let particle_world_pos = instance.position_and_size.xyz;
+    let particle_data = instance.position_and_size.w;
     let particle_type = floor(particle_data) % 4.0;
     let energy = fract(particle_data) * 10.0;
     
@@ -56,15 +60,15 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     
     // Apply quad offset to create billboard, accounting for aspect ratio to keep particles round
     let quad_offset = vec2<f32>(
-        input.quad_pos.x * particle_size / aspect_ratio,  // Compress X to counteract stretch
-        input.quad_pos.y * particle_size
+        vertex.quad_pos.x * particle_size / aspect_ratio,  // Compress X to counteract stretch
+        vertex.quad_pos.y * particle_size
     );
     let billboard_pos = screen_center + quad_offset;
     
     output.position = vec4<f32>(billboard_pos, 0.0, 1.0);
     
     // Store world position and quad coordinate for fragment shader
-    output.world_position = input.quad_pos; // Use quad pos for distance calculation
+    output.world_position = vertex.quad_pos; // Use quad pos for distance calculation
     
     // Set output values
     output.particle_type = particle_type;
@@ -65,26 +65,15 @@ impl Demo for BufferLayoutsDemo {
     let runtime_colors = RUNTIME_COLORS[..3].to_vec();
     let runtime = RuntimeLayout::new(runtime_header, &runtime_colors);
 
-    let uniform_buffer = buffer(
-      device,
-      "Layout uniform",
-      bytemuck::bytes_of(&uniform),
-      wgpu::BufferUsages::UNIFORM,
-    );
-    let direct_buffer = buffer(
-      device,
-      "Direct layout",
-      bytemuck::bytes_of(&direct),
-      wgpu::BufferUsages::STORAGE,
-    );
+    let uniform_buffer = FixedLayout::create_buffer(device, &uniform);
+    let direct_buffer = FixedLayout::create_buffer(device, &direct);
     let array_buffer = buffer(
       device,
       "Layout array",
       bytemuck::cast_slice(&array),
       wgpu::BufferUsages::STORAGE,
     );
-    let runtime_buffer =
-      buffer(device, "Runtime layout", runtime.as_bytes(), wgpu::BufferUsages::STORAGE);
+    let runtime_buffer = RuntimeLayout::create_buffer(device, &runtime);
     let bind_group =
       bind_group(device, &uniform_buffer, &direct_buffer, &array_buffer, &runtime_buffer);
 
@@ -142,8 +131,7 @@ impl Demo for BufferLayoutsDemo {
 
     self.runtime_header.tag = self.runtime_colors.len() as u32;
     let runtime = RuntimeLayout::new(self.runtime_header, &self.runtime_colors);
-    self.runtime_buffer =
-      buffer(device, "Runtime layout", runtime.as_bytes(), wgpu::BufferUsages::STORAGE);
+    self.runtime_buffer = RuntimeLayout::create_buffer(device, &runtime);
     self.bind_group = bind_group(
       device,
       &self.uniform_buffer,
@@ -256,4 +244,34 @@ mod tests {
     colors = RUNTIME_COLORS.to_vec();
     assert!(!resize_runtime_colors(&mut colors, KeyCode::Equal));
   }
+
+  #[test]
+  fn runtime_layout_reads_back_from_bytes() {
+    let header = fixed_layout(
+      rgb(0.55, 0.24, 0.9),
+      [rgb(0.78, 0.52, 1.0), rgb(0.30, 0.12, 0.58)],
+      3,
+    );
+    let runtime = RuntimeLayout::new(header, &RUNTIME_COLORS[..3]);
+
+    let read = RuntimeLayout::from_bytes(runtime.as_bytes()).unwrap();
+    assert_eq!(read.header, header);
+    assert_eq!(read.colors, RUNTIME_COLORS[..3]);
+
+    let (read_header, colors) =
+      RuntimeLayout::tail_from_bytes(runtime.as_bytes()).unwrap();
+    assert_eq!(read_header.header, header);
+    assert_eq!(colors, &RUNTIME_COLORS[..3]);
+
+    // A partial trailing element is not part of the runtime-sized array.
+    let truncated = &runtime.as_bytes()[..runtime.as_bytes().len() - 4];
+    assert_eq!(RuntimeLayout::from_bytes(truncated).unwrap().colors.len(), 2);
+
+    let fixed = FixedLayout::from_bytes(bytemuck::bytes_of(&header)).unwrap();
+    assert_eq!(*fixed, header);
+    assert!(matches!(
+      FixedLayout::from_bytes(&bytemuck::bytes_of(&header)[..16]),
+      Err(buffer_layouts::LayoutError::TooShort { .. })
+    ));
+  }
 }
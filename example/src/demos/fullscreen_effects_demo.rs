@@ -193,7 +193,10 @@ impl FullscreenEffectsDemo {
       let push_constant = shader_bindings::fullscreen_effects::Immediates {
         color_matrix: glam::Mat4::IDENTITY,
       };
-      bundle_encoder.set_immediates(0, bytemuck::cast_slice(&[push_constant]));
+      shader_bindings::fullscreen_effects::set_immediates(
+        &mut bundle_encoder,
+        &push_constant,
+      );
 
       // Include global bind group in the render bundle for fullscreen effects
       // The bind group reference stays the same, we just update the buffer contents
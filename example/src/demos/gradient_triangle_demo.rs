@@ -1,7 +1,6 @@
 use crate::demos::Demo;
 use crate::shader_bindings::gradient_triangle::{self, VertexInput};
 use glam::Vec3;
-use wgpu::util::DeviceExt;
 use winit::event::KeyEvent;
 
 pub struct GradientTriangleDemo {
@@ -22,11 +21,7 @@ impl Demo for GradientTriangleDemo {
       VertexInput(Vec3::new(0.0, 0.5, 0.0), 3),   // Top - Blue
     ];
 
-    let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-      label: Some("Gradient Triangle Vertex Buffer"),
-      contents: bytemuck::cast_slice(vertices),
-      usage: wgpu::BufferUsages::VERTEX,
-    });
+    let vertex_buffer = VertexInput::create_vertex_buffer(device, vertices);
 
     let pipeline_layout = gradient_triangle::create_pipeline_layout(device);
     let shader_module = gradient_triangle::create_shader_module_relative_path(
@@ -101,7 +96,10 @@ impl Demo for GradientTriangleDemo {
     render_pass: &mut wgpu::RenderPass<'a>,
   ) {
     render_pass.set_pipeline(&self.render_pipeline);
-    render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+    gradient_triangle::VsMainVertexBuffers {
+      vertex_input: self.vertex_buffer.slice(..),
+    }
+    .set(render_pass);
     render_pass.draw(0..3, 0..1); // Draw single triangle with gradient
   }
 
@@ -201,33 +201,10 @@ impl Demo for ParticleComputeDemo {
       device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
         label: Some("Particle Visualization Pipeline"),
         layout: Some(&render_pipeline_layout),
-        vertex: wgpu::VertexState {
-          module: &render_shader,
-          entry_point: Some(particle_renderer::ENTRY_VS_MAIN),
-          compilation_options: wgpu::PipelineCompilationOptions::default(),
-          buffers: &[
-            // Quad vertices (per vertex) - matches @location(0) quad_pos
-            Some(wgpu::VertexBufferLayout {
-              array_stride: std::mem::size_of::<glam::Vec2>() as u64,
-              step_mode: wgpu::VertexStepMode::Vertex,
-              attributes: &[wgpu::VertexAttribute {
-                format: wgpu::VertexFormat::Float32x2,
-                offset: 0,
-                shader_location: 0, // @location(0) quad_pos - matches generated VertexInput
-              }],
-            }),
-            // Instance data (per instance) - matches @location(1) position_and_size
-            Some(wgpu::VertexBufferLayout {
-              array_stride: std::mem::size_of::<glam::Vec4>() as u64,
-              step_mode: wgpu::VertexStepMode::Instance,
-              attributes: &[wgpu::VertexAttribute {
-                format: wgpu::VertexFormat::Float32x4,
-                offset: 0,
-                shader_location: 1, // @location(1) position_and_size - matches generated VertexInput
-              }],
-            }),
-          ],
-        },
+        vertex: particle_renderer::vertex_state(
+          &render_shader,
+          &particle_renderer::vs_main_entry(wgpu::VertexStepMode::Vertex),
+        ),
         fragment: Some(particle_renderer::fragment_state(
           &render_shader,
           &particle_renderer::fs_main_entry([Some(wgpu::ColorTargetState {
@@ -434,8 +411,11 @@ impl Demo for ParticleComputeDemo {
     render_pass: &mut wgpu::RenderPass<'a>,
   ) {
     render_pass.set_pipeline(&self.render_pipeline);
-    render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..)); // Quad vertices
-    render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..)); // Instance data
+    particle_renderer::VsMainVertexBuffers {
+      quad_vertex: self.vertex_buffer.slice(..),
+      particle_instance: self.instance_buffer.slice(..),
+    }
+    .set(render_pass);
     render_pass.draw(0..6, 0..self.num_jobs); // 6 vertices per quad, num_jobs instances
   }
 
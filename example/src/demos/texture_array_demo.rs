@@ -161,7 +161,7 @@ impl Demo for TextureArrayDemo {
       color_matrix: glam::Mat4::IDENTITY,
     };
 
-    render_pass.set_immediates(0, bytemuck::cast_slice(&[push_constant]));
+    shader_bindings::simple_array_demo::set_immediates(render_pass, &push_constant);
 
     // Global bind group is already set by main.rs, only set shader-specific bind groups
     self.bind_group1.set(render_pass);
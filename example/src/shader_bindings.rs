@@ -1,8 +1,8 @@
 // File automatically generated by wgsl_bindgen^
 //
-// ^ wgsl_bindgen version 0.23.1
+// ^ wgsl_bindgen version 0.23.3
 // Changes made to this file will not be saved.
-// SourceHash: abeec3d4123fe919b871a487131794ec0bd4718b5a0e141aed2105e1bb6066e5
+// SourceHash: 0e06901a600e1970de7479a45c1cd59963a7b23ca1e28184268aa55ec2b490f8
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ShaderEntry {
@@ -294,6 +294,32 @@ impl ShaderEntry {
 #[allow(unused, non_snake_case, non_camel_case_types, non_upper_case_globals)]
 mod _root {
   pub use super::*;
+  pub trait SetVertexBuffer {
+    fn set_vertex_buffer(&mut self, slot: u32, buffer_slice: wgpu::BufferSlice<'_>);
+  }
+  impl SetVertexBuffer for wgpu::RenderPass<'_> {
+    fn set_vertex_buffer(&mut self, slot: u32, buffer_slice: wgpu::BufferSlice<'_>) {
+      self.set_vertex_buffer(slot, buffer_slice);
+    }
+  }
+  impl SetVertexBuffer for wgpu::RenderBundleEncoder<'_> {
+    fn set_vertex_buffer(&mut self, slot: u32, buffer_slice: wgpu::BufferSlice<'_>) {
+      self.set_vertex_buffer(slot, buffer_slice);
+    }
+  }
+  pub trait SetImmediates {
+    fn set_immediates(&mut self, offset: u32, data: &[u8]);
+  }
+  impl SetImmediates for wgpu::RenderPass<'_> {
+    fn set_immediates(&mut self, offset: u32, data: &[u8]) {
+      self.set_immediates(offset, data);
+    }
+  }
+  impl SetImmediates for wgpu::RenderBundleEncoder<'_> {
+    fn set_immediates(&mut self, offset: u32, data: &[u8]) {
+      self.set_immediates(offset, data);
+    }
+  }
   pub trait SetBindGroup {
     fn set_bind_group(
       &mut self,
@@ -355,12 +381,34 @@ pub mod layout_asserts {
     assert!(std::mem::align_of::<glam::Vec3>() == 4);
     assert!(std::mem::size_of::<glam::Vec4>() == 16);
     assert!(std::mem::align_of::<glam::Vec4>() == 16);
+    assert!(std::mem::size_of::<glam::DVec2>() == 16);
+    assert!(std::mem::align_of::<glam::DVec2>() == 8);
+    assert!(std::mem::size_of::<glam::DVec3>() == 24);
+    assert!(std::mem::align_of::<glam::DVec3>() == 8);
+    assert!(std::mem::size_of::<glam::DVec4>() == 32);
+    assert!(std::mem::align_of::<glam::DVec4>() == 8);
+    assert!(std::mem::size_of::<glam::I64Vec2>() == 16);
+    assert!(std::mem::align_of::<glam::I64Vec2>() == 8);
+    assert!(std::mem::size_of::<glam::I64Vec3>() == 24);
+    assert!(std::mem::align_of::<glam::I64Vec3>() == 8);
+    assert!(std::mem::size_of::<glam::I64Vec4>() == 32);
+    assert!(std::mem::align_of::<glam::I64Vec4>() == 8);
+    assert!(std::mem::size_of::<glam::U64Vec2>() == 16);
+    assert!(std::mem::align_of::<glam::U64Vec2>() == 8);
+    assert!(std::mem::size_of::<glam::U64Vec3>() == 24);
+    assert!(std::mem::align_of::<glam::U64Vec3>() == 8);
+    assert!(std::mem::size_of::<glam::U64Vec4>() == 32);
+    assert!(std::mem::align_of::<glam::U64Vec4>() == 8);
     assert!(std::mem::size_of::<glam::Mat2>() == 16);
     assert!(std::mem::align_of::<glam::Mat2>() == 16);
     assert!(std::mem::size_of::<glam::Mat3A>() == 48);
     assert!(std::mem::align_of::<glam::Mat3A>() == 16);
     assert!(std::mem::size_of::<glam::Mat4>() == 64);
     assert!(std::mem::align_of::<glam::Mat4>() == 16);
+    assert!(std::mem::size_of::<glam::DMat2>() == 32);
+    assert!(std::mem::align_of::<glam::DMat2>() == 8);
+    assert!(std::mem::size_of::<glam::DMat4>() == 128);
+    assert!(std::mem::align_of::<glam::DMat4>() == 8);
   };
   const GLOBAL_BINDINGS_GLOBAL_UNIFORMS_ASSERTS: () = {
     assert!(std::mem::offset_of!(global_bindings::GlobalUniforms, time) == 0);
@@ -460,6 +508,130 @@ pub mod global_bindings {
       }
     }
   }
+  impl GlobalUniforms {
+    pub const OFFSET_TIME: u64 = 0;
+    pub const SIZE_TIME: u64 = 4;
+    pub const OFFSET_SCALE_FACTOR: u64 = 4;
+    pub const SIZE_SCALE_FACTOR: u64 = 4;
+    pub const OFFSET_FRAME_SIZE: u64 = 8;
+    pub const SIZE_FRAME_SIZE: u64 = 8;
+    pub const OFFSET_MOUSE_POS: u64 = 16;
+    pub const SIZE_MOUSE_POS: u64 = 8;
+    pub const SIZE: u64 = 24;
+    #[doc = "Writes `time` of the `GlobalUniforms` stored at `base_offset` in `buffer`."]
+    pub fn write_field_time(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.time = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_TIME as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_TIME,
+        &bytes[..Self::SIZE_TIME as usize],
+      );
+    }
+    #[doc = "Writes `scale_factor` of the `GlobalUniforms` stored at `base_offset` in `buffer`."]
+    pub fn write_field_scale_factor(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.scale_factor = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_SCALE_FACTOR as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_SCALE_FACTOR,
+        &bytes[..Self::SIZE_SCALE_FACTOR as usize],
+      );
+    }
+    #[doc = "Writes `frame_size` of the `GlobalUniforms` stored at `base_offset` in `buffer`."]
+    pub fn write_field_frame_size(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Vec2,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.frame_size = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_FRAME_SIZE as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_FRAME_SIZE,
+        &bytes[..Self::SIZE_FRAME_SIZE as usize],
+      );
+    }
+    #[doc = "Writes `mouse_pos` of the `GlobalUniforms` stored at `base_offset` in `buffer`."]
+    pub fn write_field_mouse_pos(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Vec2,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.mouse_pos = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_MOUSE_POS as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_MOUSE_POS,
+        &bytes[..Self::SIZE_MOUSE_POS as usize],
+      );
+    }
+  }
+  impl GlobalUniforms {
+    #[doc = "Reads a `GlobalUniforms` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 8 })
+    }
+  }
+  impl GlobalUniforms {
+    #[doc = "The usages of a buffer holding a `GlobalUniforms`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::UNIFORM);
+    #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("global_bindings::GlobalUniforms"),
+          contents: bytemuck::bytes_of(value),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
+  }
+  #[doc = r" Why bytes could not be read as a struct with its WGSL layout."]
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum LayoutError {
+    #[doc = r" There are fewer bytes than the struct needs."]
+    TooShort { expected: usize, actual: usize },
+    #[doc = r" The bytes do not start at a multiple of the struct alignment."]
+    Misaligned { alignment: usize },
+  }
+  impl ::core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      match self {
+        Self::TooShort { expected, actual } => {
+          write!(f, "expected at least {expected} bytes, got {actual}")
+        }
+        Self::Misaligned { alignment } => {
+          write!(f, "bytes are not aligned to {alignment}")
+        }
+      }
+    }
+  }
+  impl std::error::Error for LayoutError {}
   #[derive(Debug)]
   pub struct WgpuBindGroup0EntriesParams<'a> {
     pub globals: wgpu::BufferBinding<'a>,
@@ -555,11 +727,197 @@ pub mod bytemuck_impls {
   unsafe impl bytemuck::Pod for compute_demo::particle_physics::Job {}
   unsafe impl bytemuck::Zeroable for compute_demo::particle_physics::Params {}
   unsafe impl bytemuck::Pod for compute_demo::particle_physics::Params {}
-  unsafe impl bytemuck::Zeroable for compute_demo::particle_renderer::VertexInput {}
-  unsafe impl bytemuck::Pod for compute_demo::particle_renderer::VertexInput {}
+  unsafe impl bytemuck::Zeroable for compute_demo::particle_renderer::QuadVertex {}
+  unsafe impl bytemuck::Pod for compute_demo::particle_renderer::QuadVertex {}
+  unsafe impl bytemuck::Zeroable for compute_demo::particle_renderer::ParticleInstance {}
+  unsafe impl bytemuck::Pod for compute_demo::particle_renderer::ParticleInstance {}
   unsafe impl bytemuck::Zeroable for buffer_layouts::FixedLayout {}
   unsafe impl bytemuck::Pod for buffer_layouts::FixedLayout {}
 }
+#[cfg(test)]
+#[allow(unused, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub mod layout_tests {
+  use super::{_root, _root::*};
+  #[doc = r" Reads a field value from its WGSL bytes, zero-extended to the size"]
+  #[doc = r" of the Rust type."]
+  fn sentinel<T: bytemuck::Pod>(bytes: &[u8]) -> T {
+    let mut value = T::zeroed();
+    let len = bytes.len().min(::core::mem::size_of::<T>());
+    bytemuck::bytes_of_mut(&mut value)[..len].copy_from_slice(&bytes[..len]);
+    value
+  }
+  fn assert_field_bytes(bytes: &[u8], field: &str, offset: usize, expected: &[u8]) {
+    assert_eq!(
+      bytes.get(offset..offset + expected.len()),
+      Some(expected),
+      "`{field}` does not match its WGSL layout at offset {offset}",
+    );
+  }
+  #[test]
+  fn global_bindings_global_uniforms() {
+    const TIME: &[u8] = &[0, 0, 128, 63];
+    const SCALE_FACTOR: &[u8] = &[0, 0, 0, 64];
+    const FRAME_SIZE: &[u8] = &[0, 0, 64, 64, 0, 0, 128, 64];
+    const MOUSE_POS: &[u8] = &[0, 0, 160, 64, 0, 0, 192, 64];
+    let value = global_bindings::GlobalUniforms {
+      time: sentinel(TIME),
+      scale_factor: sentinel(SCALE_FACTOR),
+      frame_size: sentinel(FRAME_SIZE),
+      mouse_pos: sentinel(MOUSE_POS),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "time", 0, TIME);
+    assert_field_bytes(bytes, "scale_factor", 4, SCALE_FACTOR);
+    assert_field_bytes(bytes, "frame_size", 8, FRAME_SIZE);
+    assert_field_bytes(bytes, "mouse_pos", 16, MOUSE_POS);
+  }
+  #[test]
+  fn fullscreen_effects_uniforms() {
+    const COLOR_RGB: &[u8] = &[0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64];
+    let value = fullscreen_effects::Uniforms {
+      color_rgb: sentinel(COLOR_RGB),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "color_rgb", 0, COLOR_RGB);
+  }
+  #[test]
+  fn fullscreen_effects_immediates() {
+    const COLOR_MATRIX: &[u8] = &[
+      0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64, 0, 0, 160, 64, 0, 0, 192,
+      64, 0, 0, 224, 64, 0, 0, 0, 65, 0, 0, 16, 65, 0, 0, 32, 65, 0, 0, 48, 65, 0, 0, 64,
+      65, 0, 0, 80, 65, 0, 0, 96, 65, 0, 0, 112, 65, 0, 0, 128, 65,
+    ];
+    let value = fullscreen_effects::Immediates {
+      color_matrix: sentinel(COLOR_MATRIX),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "color_matrix", 0, COLOR_MATRIX);
+  }
+  #[test]
+  fn simple_array_demo_uniforms() {
+    const COLOR_RGB: &[u8] = &[0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64];
+    let value = simple_array_demo::Uniforms {
+      color_rgb: sentinel(COLOR_RGB),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "color_rgb", 0, COLOR_RGB);
+  }
+  #[test]
+  fn simple_array_demo_immediates() {
+    const COLOR_MATRIX: &[u8] = &[
+      0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64, 0, 0, 160, 64, 0, 0, 192,
+      64, 0, 0, 224, 64, 0, 0, 0, 65, 0, 0, 16, 65, 0, 0, 32, 65, 0, 0, 48, 65, 0, 0, 64,
+      65, 0, 0, 80, 65, 0, 0, 96, 65, 0, 0, 112, 65, 0, 0, 128, 65,
+    ];
+    let value = simple_array_demo::Immediates {
+      color_matrix: sentinel(COLOR_MATRIX),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "color_matrix", 0, COLOR_MATRIX);
+  }
+  #[test]
+  fn overlay_info_data() {
+    const DEMO_INDEX: &[u8] = &[0, 0, 128, 63];
+    const TOTAL_DEMOS: &[u8] = &[0, 0, 0, 64];
+    const TIME: &[u8] = &[0, 0, 64, 64];
+    const SCALE_FACTOR: &[u8] = &[0, 0, 128, 64];
+    const WINDOW_WIDTH: &[u8] = &[0, 0, 160, 64];
+    const WINDOW_HEIGHT: &[u8] = &[0, 0, 192, 64];
+    const PADDING1: &[u8] = &[0, 0, 224, 64];
+    const PADDING2: &[u8] = &[0, 0, 0, 65];
+    let value = overlay::InfoData {
+      demo_index: sentinel(DEMO_INDEX),
+      total_demos: sentinel(TOTAL_DEMOS),
+      time: sentinel(TIME),
+      scale_factor: sentinel(SCALE_FACTOR),
+      window_width: sentinel(WINDOW_WIDTH),
+      window_height: sentinel(WINDOW_HEIGHT),
+      padding1: sentinel(PADDING1),
+      padding2: sentinel(PADDING2),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "demo_index", 0, DEMO_INDEX);
+    assert_field_bytes(bytes, "total_demos", 4, TOTAL_DEMOS);
+    assert_field_bytes(bytes, "time", 8, TIME);
+    assert_field_bytes(bytes, "scale_factor", 12, SCALE_FACTOR);
+    assert_field_bytes(bytes, "window_width", 16, WINDOW_WIDTH);
+    assert_field_bytes(bytes, "window_height", 20, WINDOW_HEIGHT);
+    assert_field_bytes(bytes, "padding1", 24, PADDING1);
+    assert_field_bytes(bytes, "padding2", 28, PADDING2);
+  }
+  #[test]
+  fn compute_demo_particle_physics_job() {
+    const POSITION: &[u8] = &[0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64];
+    const DIRECTION: &[u8] = &[0, 0, 128, 64, 0, 0, 160, 64, 0, 0, 192, 64];
+    const ACCUM: &[u8] = &[0, 0, 224, 64, 0, 0, 0, 65, 0, 0, 16, 65];
+    const DEPTH: &[u8] = &[10, 0, 0, 0];
+    let value = compute_demo::particle_physics::Job {
+      position: sentinel(POSITION),
+      _pad_position: bytemuck::Zeroable::zeroed(),
+      direction: sentinel(DIRECTION),
+      _pad_direction: bytemuck::Zeroable::zeroed(),
+      accum: sentinel(ACCUM),
+      depth: sentinel(DEPTH),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "position", 0, POSITION);
+    assert_field_bytes(bytes, "direction", 16, DIRECTION);
+    assert_field_bytes(bytes, "accum", 32, ACCUM);
+    assert_field_bytes(bytes, "depth", 44, DEPTH);
+  }
+  #[test]
+  fn compute_demo_particle_physics_params() {
+    const SCALE: &[u8] = &[0, 0, 128, 63];
+    const DAMPING: &[u8] = &[0, 0, 0, 64];
+    let value = compute_demo::particle_physics::Params {
+      scale: sentinel(SCALE),
+      damping: sentinel(DAMPING),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "scale", 0, SCALE);
+    assert_field_bytes(bytes, "damping", 4, DAMPING);
+  }
+  #[test]
+  fn buffer_layouts_fixed_layout() {
+    const PRIMARY: &[u8] = &[0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64];
+    const ACCENTS: &[u8] = &[
+      0, 0, 128, 64, 0, 0, 160, 64, 0, 0, 192, 64, 0, 0, 0, 0, 0, 0, 224, 64, 0, 0, 0,
+      65, 0, 0, 16, 65, 0, 0, 0, 0,
+    ];
+    const TAG: &[u8] = &[10, 0, 0, 0];
+    let value = buffer_layouts::FixedLayout {
+      primary: sentinel(PRIMARY),
+      _pad_primary: bytemuck::Zeroable::zeroed(),
+      accents: sentinel(ACCENTS),
+      tag: sentinel(TAG),
+      _pad_tag: bytemuck::Zeroable::zeroed(),
+    };
+    let bytes: &[u8] = bytemuck::bytes_of(&value);
+    assert_field_bytes(bytes, "primary", 0, PRIMARY);
+    assert_field_bytes(bytes, "accents", 16, ACCENTS);
+    assert_field_bytes(bytes, "tag", 48, TAG);
+  }
+  #[test]
+  fn buffer_layouts_runtime_layout() {
+    const HEADER: &[u8] = &[
+      0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 0, 0, 0, 0, 128, 64, 0, 0, 160, 64,
+      0, 0, 192, 64, 0, 0, 0, 0, 0, 0, 224, 64, 0, 0, 0, 65, 0, 0, 16, 65, 0, 0, 0, 0,
+      10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    const COLORS: [&[u8]; 2] = [
+      &[0, 0, 48, 65, 0, 0, 64, 65, 0, 0, 80, 65, 0, 0, 96, 65],
+      &[0, 0, 112, 65, 0, 0, 128, 65, 0, 0, 136, 65, 0, 0, 144, 65],
+    ];
+    let value = buffer_layouts::RuntimeLayoutSized::<2> {
+      header: sentinel(HEADER),
+      colors: [sentinel(COLORS[0]), sentinel(COLORS[1])],
+    };
+    let bytes: &[u8] = value.as_bytes();
+    assert_field_bytes(bytes, "header", 0, HEADER);
+    assert_field_bytes(bytes, "colors[0]", 64, COLORS[0]);
+    assert_field_bytes(bytes, "colors[1]", 80, COLORS[1]);
+  }
+}
 #[allow(unused, non_snake_case, non_camel_case_types, non_upper_case_globals)]
 pub mod fullscreen_effects {
   use super::{_root, _root::*};
@@ -572,6 +930,76 @@ pub mod fullscreen_effects {
   pub const fn Uniforms(color_rgb: glam::Vec4) -> Uniforms {
     Uniforms { color_rgb }
   }
+  impl Uniforms {
+    pub const OFFSET_COLOR_RGB: u64 = 0;
+    pub const SIZE_COLOR_RGB: u64 = 16;
+    pub const SIZE: u64 = 16;
+    #[doc = "Writes `color_rgb` of the `Uniforms` stored at `base_offset` in `buffer`."]
+    pub fn write_field_color_rgb(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Vec4,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.color_rgb = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_COLOR_RGB as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_COLOR_RGB,
+        &bytes[..Self::SIZE_COLOR_RGB as usize],
+      );
+    }
+  }
+  impl Uniforms {
+    #[doc = "Reads a `Uniforms` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 16 })
+    }
+  }
+  impl Uniforms {
+    #[doc = "The usages of a buffer holding a `Uniforms`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::UNIFORM);
+    #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("fullscreen_effects::Uniforms"),
+          contents: bytemuck::bytes_of(value),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
+  }
+  #[doc = r" Why bytes could not be read as a struct with its WGSL layout."]
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum LayoutError {
+    #[doc = r" There are fewer bytes than the struct needs."]
+    TooShort { expected: usize, actual: usize },
+    #[doc = r" The bytes do not start at a multiple of the struct alignment."]
+    Misaligned { alignment: usize },
+  }
+  impl ::core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      match self {
+        Self::TooShort { expected, actual } => {
+          write!(f, "expected at least {expected} bytes, got {actual}")
+        }
+        Self::Misaligned { alignment } => {
+          write!(f, "bytes are not aligned to {alignment}")
+        }
+      }
+    }
+  }
+  impl std::error::Error for LayoutError {}
   #[repr(C)]
   #[derive(Debug, PartialEq, Clone, Copy)]
   pub struct VertexInput {
@@ -580,6 +1008,16 @@ pub mod fullscreen_effects {
   pub const fn VertexInput(position: glam::Vec3) -> VertexInput {
     VertexInput { position }
   }
+  impl VertexInput {
+    pub const OFFSET_POSITION: u64 = 0;
+    pub const SIZE_POSITION: u64 = 12;
+    pub const SIZE: u64 = 16;
+  }
+  impl VertexInput {
+    #[doc = "The usages of a buffer holding a `VertexInput`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::VERTEX);
+  }
   impl VertexInput {
     pub const VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 1] = [wgpu::VertexAttribute {
       format: wgpu::VertexFormat::Float32x3,
@@ -595,6 +1033,20 @@ pub mod fullscreen_effects {
         attributes: &Self::VERTEX_ATTRIBUTES,
       }
     }
+    #[doc = r" Creates a vertex buffer holding `vertices` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_vertex_buffer(
+      device: &wgpu::Device,
+      vertices: &[Self],
+    ) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("fullscreen_effects::VertexInput"),
+          contents: bytemuck::cast_slice(vertices),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
   }
   #[repr(C, align(16))]
   #[derive(Debug, PartialEq, Clone, Copy)]
@@ -605,6 +1057,39 @@ pub mod fullscreen_effects {
   pub const fn Immediates(color_matrix: glam::Mat4) -> Immediates {
     Immediates { color_matrix }
   }
+  impl Immediates {
+    pub const OFFSET_COLOR_MATRIX: u64 = 0;
+    pub const SIZE_COLOR_MATRIX: u64 = 64;
+    pub const SIZE: u64 = 64;
+    #[doc = "Writes `color_matrix` of the `Immediates` stored at `base_offset` in `buffer`."]
+    pub fn write_field_color_matrix(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Mat4,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.color_matrix = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_COLOR_MATRIX as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_COLOR_MATRIX,
+        &bytes[..Self::SIZE_COLOR_MATRIX as usize],
+      );
+    }
+  }
+  impl Immediates {
+    #[doc = "Reads a `Immediates` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 16 })
+    }
+  }
   pub const ENTRY_VS_MAIN: &str = "vs_main";
   pub const ENTRY_FS_MAIN: &str = "fs_main";
   #[derive(Debug)]
@@ -634,6 +1119,17 @@ pub mod fullscreen_effects {
       constants: Default::default(),
     }
   }
+  #[doc = "The vertex buffers of `vs_main`, one per buffer of [`vs_main_entry`] in the same order."]
+  #[derive(Debug, Clone, Copy)]
+  pub struct VsMainVertexBuffers<'a> {
+    pub vertex_input: wgpu::BufferSlice<'a>,
+  }
+  impl VsMainVertexBuffers<'_> {
+    #[doc = r" Sets every vertex buffer at its slot."]
+    pub fn set(&self, pass: &mut impl SetVertexBuffer) {
+      pass.set_vertex_buffer(0, self.vertex_input);
+    }
+  }
   #[derive(Debug)]
   pub struct FragmentEntry<const N: usize> {
     pub entry_point: &'static str,
@@ -661,6 +1157,15 @@ pub mod fullscreen_effects {
       constants: Default::default(),
     }
   }
+  #[doc = "The stages of the entry points that read `constants`."]
+  pub const IMMEDIATE_STAGES: wgpu::ShaderStages = wgpu::ShaderStages::FRAGMENT;
+  #[doc = "Sets `constants` for the entry points in [`IMMEDIATE_STAGES`]."]
+  pub fn set_immediates(
+    pass: &mut impl SetImmediates,
+    data: &_root::fullscreen_effects::Immediates,
+  ) {
+    pass.set_immediates(0, bytemuck::bytes_of(data));
+  }
   #[derive(Debug)]
   pub struct WgpuBindGroup1EntriesParams<'a> {
     pub main_texture: &'a wgpu::TextureView,
@@ -701,7 +1206,7 @@ pub mod fullscreen_effects {
           #[doc = " @binding(0): \"main_texture\""]
           wgpu::BindGroupLayoutEntry {
             binding: 0,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Texture {
               sample_type: wgpu::TextureSampleType::Float { filterable: true },
               view_dimension: wgpu::TextureViewDimension::D2,
@@ -712,7 +1217,7 @@ pub mod fullscreen_effects {
           #[doc = " @binding(1): \"main_sampler\""]
           wgpu::BindGroupLayoutEntry {
             binding: 1,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
             count: None,
           },
@@ -775,7 +1280,7 @@ pub mod fullscreen_effects {
           #[doc = " @binding(0): \"uniforms\""]
           wgpu::BindGroupLayoutEntry {
             binding: 0,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Buffer {
               ty: wgpu::BufferBindingType::Uniform,
               has_dynamic_offset: false,
@@ -962,6 +1467,76 @@ pub mod simple_array_demo {
   pub const fn Uniforms(color_rgb: glam::Vec4) -> Uniforms {
     Uniforms { color_rgb }
   }
+  impl Uniforms {
+    pub const OFFSET_COLOR_RGB: u64 = 0;
+    pub const SIZE_COLOR_RGB: u64 = 16;
+    pub const SIZE: u64 = 16;
+    #[doc = "Writes `color_rgb` of the `Uniforms` stored at `base_offset` in `buffer`."]
+    pub fn write_field_color_rgb(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Vec4,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.color_rgb = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_COLOR_RGB as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_COLOR_RGB,
+        &bytes[..Self::SIZE_COLOR_RGB as usize],
+      );
+    }
+  }
+  impl Uniforms {
+    #[doc = "Reads a `Uniforms` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 16 })
+    }
+  }
+  impl Uniforms {
+    #[doc = "The usages of a buffer holding a `Uniforms`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::UNIFORM);
+    #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("simple_array_demo::Uniforms"),
+          contents: bytemuck::bytes_of(value),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
+  }
+  #[doc = r" Why bytes could not be read as a struct with its WGSL layout."]
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum LayoutError {
+    #[doc = r" There are fewer bytes than the struct needs."]
+    TooShort { expected: usize, actual: usize },
+    #[doc = r" The bytes do not start at a multiple of the struct alignment."]
+    Misaligned { alignment: usize },
+  }
+  impl ::core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      match self {
+        Self::TooShort { expected, actual } => {
+          write!(f, "expected at least {expected} bytes, got {actual}")
+        }
+        Self::Misaligned { alignment } => {
+          write!(f, "bytes are not aligned to {alignment}")
+        }
+      }
+    }
+  }
+  impl std::error::Error for LayoutError {}
   #[repr(C, align(16))]
   #[derive(Debug, PartialEq, Clone, Copy)]
   pub struct Immediates {
@@ -971,6 +1546,39 @@ pub mod simple_array_demo {
   pub const fn Immediates(color_matrix: glam::Mat4) -> Immediates {
     Immediates { color_matrix }
   }
+  impl Immediates {
+    pub const OFFSET_COLOR_MATRIX: u64 = 0;
+    pub const SIZE_COLOR_MATRIX: u64 = 64;
+    pub const SIZE: u64 = 64;
+    #[doc = "Writes `color_matrix` of the `Immediates` stored at `base_offset` in `buffer`."]
+    pub fn write_field_color_matrix(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Mat4,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.color_matrix = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_COLOR_MATRIX as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_COLOR_MATRIX,
+        &bytes[..Self::SIZE_COLOR_MATRIX as usize],
+      );
+    }
+  }
+  impl Immediates {
+    #[doc = "Reads a `Immediates` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 16 })
+    }
+  }
   pub const ENTRY_VS_MAIN: &str = "vs_main";
   pub const ENTRY_FS_MAIN: &str = "fs_main";
   #[derive(Debug)]
@@ -1008,6 +1616,17 @@ pub mod simple_array_demo {
       constants: Default::default(),
     }
   }
+  #[doc = "The vertex buffers of `vs_main`, one per buffer of [`vs_main_entry`] in the same order."]
+  #[derive(Debug, Clone, Copy)]
+  pub struct VsMainVertexBuffers<'a> {
+    pub position: wgpu::BufferSlice<'a>,
+  }
+  impl VsMainVertexBuffers<'_> {
+    #[doc = r" Sets every vertex buffer at its slot."]
+    pub fn set(&self, pass: &mut impl SetVertexBuffer) {
+      pass.set_vertex_buffer(0, self.position);
+    }
+  }
   #[derive(Debug)]
   pub struct FragmentEntry<const N: usize> {
     pub entry_point: &'static str,
@@ -1035,6 +1654,15 @@ pub mod simple_array_demo {
       constants: Default::default(),
     }
   }
+  #[doc = "The stages of the entry points that read `push_constants`."]
+  pub const IMMEDIATE_STAGES: wgpu::ShaderStages = wgpu::ShaderStages::FRAGMENT;
+  #[doc = "Sets `push_constants` for the entry points in [`IMMEDIATE_STAGES`]."]
+  pub fn set_immediates(
+    pass: &mut impl SetImmediates,
+    data: &_root::simple_array_demo::Immediates,
+  ) {
+    pass.set_immediates(0, bytemuck::bytes_of(data));
+  }
   #[derive(Debug)]
   pub struct WgpuBindGroup1EntriesParams<'a> {
     pub texture_array: &'a [&'a wgpu::TextureView],
@@ -1085,7 +1713,7 @@ pub mod simple_array_demo {
           #[doc = " @binding(0): \"texture_array\""]
           wgpu::BindGroupLayoutEntry {
             binding: 0,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Texture {
               sample_type: wgpu::TextureSampleType::Float { filterable: true },
               view_dimension: wgpu::TextureViewDimension::D2,
@@ -1096,14 +1724,14 @@ pub mod simple_array_demo {
           #[doc = " @binding(1): \"sampler_array\""]
           wgpu::BindGroupLayoutEntry {
             binding: 1,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
             count: Some(std::num::NonZeroU32::new(2u32).unwrap()),
           },
           #[doc = " @binding(2): \"texture_array_no_bind\""]
           wgpu::BindGroupLayoutEntry {
             binding: 2,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Texture {
               sample_type: wgpu::TextureSampleType::Float { filterable: true },
               view_dimension: wgpu::TextureViewDimension::D2Array,
@@ -1170,7 +1798,7 @@ pub mod simple_array_demo {
           #[doc = " @binding(0): \"uniforms\""]
           wgpu::BindGroupLayoutEntry {
             binding: 0,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Buffer {
               ty: wgpu::BufferBindingType::Uniform,
               has_dynamic_offset: false,
@@ -1409,6 +2037,202 @@ pub mod overlay {
       }
     }
   }
+  impl InfoData {
+    pub const OFFSET_DEMO_INDEX: u64 = 0;
+    pub const SIZE_DEMO_INDEX: u64 = 4;
+    pub const OFFSET_TOTAL_DEMOS: u64 = 4;
+    pub const SIZE_TOTAL_DEMOS: u64 = 4;
+    pub const OFFSET_TIME: u64 = 8;
+    pub const SIZE_TIME: u64 = 4;
+    pub const OFFSET_SCALE_FACTOR: u64 = 12;
+    pub const SIZE_SCALE_FACTOR: u64 = 4;
+    pub const OFFSET_WINDOW_WIDTH: u64 = 16;
+    pub const SIZE_WINDOW_WIDTH: u64 = 4;
+    pub const OFFSET_WINDOW_HEIGHT: u64 = 20;
+    pub const SIZE_WINDOW_HEIGHT: u64 = 4;
+    pub const OFFSET_PADDING1: u64 = 24;
+    pub const SIZE_PADDING1: u64 = 4;
+    pub const OFFSET_PADDING2: u64 = 28;
+    pub const SIZE_PADDING2: u64 = 4;
+    pub const SIZE: u64 = 32;
+    #[doc = "Writes `demo_index` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_demo_index(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.demo_index = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_DEMO_INDEX as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_DEMO_INDEX,
+        &bytes[..Self::SIZE_DEMO_INDEX as usize],
+      );
+    }
+    #[doc = "Writes `total_demos` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_total_demos(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.total_demos = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_TOTAL_DEMOS as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_TOTAL_DEMOS,
+        &bytes[..Self::SIZE_TOTAL_DEMOS as usize],
+      );
+    }
+    #[doc = "Writes `time` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_time(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.time = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_TIME as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_TIME,
+        &bytes[..Self::SIZE_TIME as usize],
+      );
+    }
+    #[doc = "Writes `scale_factor` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_scale_factor(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.scale_factor = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_SCALE_FACTOR as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_SCALE_FACTOR,
+        &bytes[..Self::SIZE_SCALE_FACTOR as usize],
+      );
+    }
+    #[doc = "Writes `window_width` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_window_width(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.window_width = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_WINDOW_WIDTH as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_WINDOW_WIDTH,
+        &bytes[..Self::SIZE_WINDOW_WIDTH as usize],
+      );
+    }
+    #[doc = "Writes `window_height` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_window_height(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.window_height = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_WINDOW_HEIGHT as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_WINDOW_HEIGHT,
+        &bytes[..Self::SIZE_WINDOW_HEIGHT as usize],
+      );
+    }
+    #[doc = "Writes `padding1` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_padding1(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.padding1 = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_PADDING1 as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_PADDING1,
+        &bytes[..Self::SIZE_PADDING1 as usize],
+      );
+    }
+    #[doc = "Writes `padding2` of the `InfoData` stored at `base_offset` in `buffer`."]
+    pub fn write_field_padding2(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &f32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.padding2 = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_PADDING2 as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_PADDING2,
+        &bytes[..Self::SIZE_PADDING2 as usize],
+      );
+    }
+  }
+  impl InfoData {
+    #[doc = "Reads a `InfoData` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 4 })
+    }
+  }
+  impl InfoData {
+    #[doc = "The usages of a buffer holding a `InfoData`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::UNIFORM);
+    #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("overlay::InfoData"),
+          contents: bytemuck::bytes_of(value),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
+  }
+  #[doc = r" Why bytes could not be read as a struct with its WGSL layout."]
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum LayoutError {
+    #[doc = r" There are fewer bytes than the struct needs."]
+    TooShort { expected: usize, actual: usize },
+    #[doc = r" The bytes do not start at a multiple of the struct alignment."]
+    Misaligned { alignment: usize },
+  }
+  impl ::core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      match self {
+        Self::TooShort { expected, actual } => {
+          write!(f, "expected at least {expected} bytes, got {actual}")
+        }
+        Self::Misaligned { alignment } => {
+          write!(f, "bytes are not aligned to {alignment}")
+        }
+      }
+    }
+  }
+  impl std::error::Error for LayoutError {}
   pub const ENTRY_VS_MAIN: &str = "vs_main";
   pub const ENTRY_FS_MAIN: &str = "fs_main";
   #[derive(Debug)]
@@ -1524,7 +2348,7 @@ pub mod overlay {
           #[doc = " @binding(1): \"text_texture\""]
           wgpu::BindGroupLayoutEntry {
             binding: 1,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Texture {
               sample_type: wgpu::TextureSampleType::Float { filterable: true },
               view_dimension: wgpu::TextureViewDimension::D2,
@@ -1535,7 +2359,7 @@ pub mod overlay {
           #[doc = " @binding(2): \"text_sampler\""]
           wgpu::BindGroupLayoutEntry {
             binding: 2,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
             count: None,
           },
@@ -1701,6 +2525,18 @@ pub mod gradient_triangle {
       texture_id,
     }
   }
+  impl VertexInput {
+    pub const OFFSET_POSITION: u64 = 0;
+    pub const SIZE_POSITION: u64 = 12;
+    pub const OFFSET_TEXTURE_ID: u64 = 12;
+    pub const SIZE_TEXTURE_ID: u64 = 4;
+    pub const SIZE: u64 = 32;
+  }
+  impl VertexInput {
+    #[doc = "The usages of a buffer holding a `VertexInput`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::VERTEX);
+  }
   impl VertexInput {
     pub const VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 2] = [
       wgpu::VertexAttribute {
@@ -1723,6 +2559,20 @@ pub mod gradient_triangle {
         attributes: &Self::VERTEX_ATTRIBUTES,
       }
     }
+    #[doc = r" Creates a vertex buffer holding `vertices` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_vertex_buffer(
+      device: &wgpu::Device,
+      vertices: &[Self],
+    ) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("gradient_triangle::VertexInput"),
+          contents: bytemuck::cast_slice(vertices),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
   }
   pub const ENTRY_VS_MAIN: &str = "vs_main";
   pub const ENTRY_FS_MAIN: &str = "fs_main";
@@ -1753,6 +2603,17 @@ pub mod gradient_triangle {
       constants: Default::default(),
     }
   }
+  #[doc = "The vertex buffers of `vs_main`, one per buffer of [`vs_main_entry`] in the same order."]
+  #[derive(Debug, Clone, Copy)]
+  pub struct VsMainVertexBuffers<'a> {
+    pub vertex_input: wgpu::BufferSlice<'a>,
+  }
+  impl VsMainVertexBuffers<'_> {
+    #[doc = r" Sets every vertex buffer at its slot."]
+    pub fn set(&self, pass: &mut impl SetVertexBuffer) {
+      pass.set_vertex_buffer(0, self.vertex_input);
+    }
+  }
   #[derive(Debug)]
   pub struct FragmentEntry<const N: usize> {
     pub entry_point: &'static str,
@@ -1902,6 +2763,17 @@ pub mod multisampled_texture_demo {
       constants: Default::default(),
     }
   }
+  #[doc = "The vertex buffers of `vs_main`, one per buffer of [`vs_main_entry`] in the same order."]
+  #[derive(Debug, Clone, Copy)]
+  pub struct VsMainVertexBuffers<'a> {
+    pub position: wgpu::BufferSlice<'a>,
+  }
+  impl VsMainVertexBuffers<'_> {
+    #[doc = r" Sets every vertex buffer at its slot."]
+    pub fn set(&self, pass: &mut impl SetVertexBuffer) {
+      pass.set_vertex_buffer(0, self.position);
+    }
+  }
   pub fn vs_msaa_entry() -> VertexEntry<0> {
     VertexEntry {
       entry_point: ENTRY_VS_MSAA,
@@ -1977,7 +2849,7 @@ pub mod multisampled_texture_demo {
           #[doc = " @binding(0): \"ms_texture\""]
           wgpu::BindGroupLayoutEntry {
             binding: 0,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Texture {
               sample_type: wgpu::TextureSampleType::Float { filterable: false },
               view_dimension: wgpu::TextureViewDimension::D2,
@@ -2212,6 +3084,109 @@ pub mod compute_demo {
         }
       }
     }
+    impl Job {
+      pub const OFFSET_POSITION: u64 = 0;
+      pub const SIZE_POSITION: u64 = 12;
+      pub const OFFSET_DIRECTION: u64 = 16;
+      pub const SIZE_DIRECTION: u64 = 12;
+      pub const OFFSET_ACCUM: u64 = 32;
+      pub const SIZE_ACCUM: u64 = 12;
+      pub const OFFSET_DEPTH: u64 = 44;
+      pub const SIZE_DEPTH: u64 = 4;
+      pub const SIZE: u64 = 48;
+      #[doc = "Writes `position` of the `Job` stored at `base_offset` in `buffer`."]
+      pub fn write_field_position(
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        base_offset: wgpu::BufferAddress,
+        value: &glam::Vec3,
+      ) {
+        let mut this = <Self as bytemuck::Zeroable>::zeroed();
+        this.position = *value;
+        let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_POSITION as usize..];
+        queue.write_buffer(
+          buffer,
+          base_offset + Self::OFFSET_POSITION,
+          &bytes[..Self::SIZE_POSITION as usize],
+        );
+      }
+      #[doc = "Writes `direction` of the `Job` stored at `base_offset` in `buffer`."]
+      pub fn write_field_direction(
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        base_offset: wgpu::BufferAddress,
+        value: &glam::Vec3,
+      ) {
+        let mut this = <Self as bytemuck::Zeroable>::zeroed();
+        this.direction = *value;
+        let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_DIRECTION as usize..];
+        queue.write_buffer(
+          buffer,
+          base_offset + Self::OFFSET_DIRECTION,
+          &bytes[..Self::SIZE_DIRECTION as usize],
+        );
+      }
+      #[doc = "Writes `accum` of the `Job` stored at `base_offset` in `buffer`."]
+      pub fn write_field_accum(
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        base_offset: wgpu::BufferAddress,
+        value: &glam::Vec3,
+      ) {
+        let mut this = <Self as bytemuck::Zeroable>::zeroed();
+        this.accum = *value;
+        let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_ACCUM as usize..];
+        queue.write_buffer(
+          buffer,
+          base_offset + Self::OFFSET_ACCUM,
+          &bytes[..Self::SIZE_ACCUM as usize],
+        );
+      }
+      #[doc = "Writes `depth` of the `Job` stored at `base_offset` in `buffer`."]
+      pub fn write_field_depth(
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        base_offset: wgpu::BufferAddress,
+        value: &u32,
+      ) {
+        let mut this = <Self as bytemuck::Zeroable>::zeroed();
+        this.depth = *value;
+        let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_DEPTH as usize..];
+        queue.write_buffer(
+          buffer,
+          base_offset + Self::OFFSET_DEPTH,
+          &bytes[..Self::SIZE_DEPTH as usize],
+        );
+      }
+    }
+    impl Job {
+      #[doc = "Reads a `Job` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+      pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+        let size = ::core::mem::size_of::<Self>();
+        let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+          expected: size,
+          actual: bytes.len(),
+        })?;
+        bytemuck::try_from_bytes(bytes)
+          .map_err(|_| LayoutError::Misaligned { alignment: 16 })
+      }
+    }
+    impl Job {
+      #[doc = "The usages of a buffer holding a `Job`, from how the shaders bind it."]
+      pub const BUFFER_USAGES: wgpu::BufferUsages =
+        wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::STORAGE);
+      #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+      pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+        wgpu::util::DeviceExt::create_buffer_init(
+          device,
+          &wgpu::util::BufferInitDescriptor {
+            label: Some("compute_demo::particle_physics::Job"),
+            contents: bytemuck::bytes_of(value),
+            usage: Self::BUFFER_USAGES,
+          },
+        )
+      }
+    }
     #[repr(C)]
     #[derive(Debug, PartialEq, Clone, Copy)]
     pub struct JobInit {
@@ -2237,6 +3212,27 @@ pub mod compute_demo {
         data.build()
       }
     }
+    #[doc = r" Why bytes could not be read as a struct with its WGSL layout."]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LayoutError {
+      #[doc = r" There are fewer bytes than the struct needs."]
+      TooShort { expected: usize, actual: usize },
+      #[doc = r" The bytes do not start at a multiple of the struct alignment."]
+      Misaligned { alignment: usize },
+    }
+    impl ::core::fmt::Display for LayoutError {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+          Self::TooShort { expected, actual } => {
+            write!(f, "expected at least {expected} bytes, got {actual}")
+          }
+          Self::Misaligned { alignment } => {
+            write!(f, "bytes are not aligned to {alignment}")
+          }
+        }
+      }
+    }
+    impl std::error::Error for LayoutError {}
     #[repr(C, align(4))]
     #[derive(Debug, PartialEq, Clone, Copy)]
     pub struct Params {
@@ -2248,6 +3244,73 @@ pub mod compute_demo {
     pub const fn Params(scale: f32, damping: f32) -> Params {
       Params { scale, damping }
     }
+    impl Params {
+      pub const OFFSET_SCALE: u64 = 0;
+      pub const SIZE_SCALE: u64 = 4;
+      pub const OFFSET_DAMPING: u64 = 4;
+      pub const SIZE_DAMPING: u64 = 4;
+      pub const SIZE: u64 = 8;
+      #[doc = "Writes `scale` of the `Params` stored at `base_offset` in `buffer`."]
+      pub fn write_field_scale(
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        base_offset: wgpu::BufferAddress,
+        value: &f32,
+      ) {
+        let mut this = <Self as bytemuck::Zeroable>::zeroed();
+        this.scale = *value;
+        let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_SCALE as usize..];
+        queue.write_buffer(
+          buffer,
+          base_offset + Self::OFFSET_SCALE,
+          &bytes[..Self::SIZE_SCALE as usize],
+        );
+      }
+      #[doc = "Writes `damping` of the `Params` stored at `base_offset` in `buffer`."]
+      pub fn write_field_damping(
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        base_offset: wgpu::BufferAddress,
+        value: &f32,
+      ) {
+        let mut this = <Self as bytemuck::Zeroable>::zeroed();
+        this.damping = *value;
+        let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_DAMPING as usize..];
+        queue.write_buffer(
+          buffer,
+          base_offset + Self::OFFSET_DAMPING,
+          &bytes[..Self::SIZE_DAMPING as usize],
+        );
+      }
+    }
+    impl Params {
+      #[doc = "Reads a `Params` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+      pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+        let size = ::core::mem::size_of::<Self>();
+        let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+          expected: size,
+          actual: bytes.len(),
+        })?;
+        bytemuck::try_from_bytes(bytes)
+          .map_err(|_| LayoutError::Misaligned { alignment: 4 })
+      }
+    }
+    impl Params {
+      #[doc = "The usages of a buffer holding a `Params`, from how the shaders bind it."]
+      pub const BUFFER_USAGES: wgpu::BufferUsages =
+        wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::UNIFORM);
+      #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+      pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+        wgpu::util::DeviceExt::create_buffer_init(
+          device,
+          &wgpu::util::BufferInitDescriptor {
+            label: Some("compute_demo::particle_physics::Params"),
+            contents: bytemuck::bytes_of(value),
+            usage: Self::BUFFER_USAGES,
+          },
+        )
+      }
+    }
     pub const DT: f32 = 0.012f32;
     pub const BOUNDARY: f32 = 1f32;
     pub const NEIGHBOR_RADIUS: f32 = 0.12f32;
@@ -2881,32 +3944,76 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     use super::{_root, _root::*};
     #[repr(C)]
     #[derive(Debug, PartialEq, Clone, Copy)]
-    pub struct VertexInput {
+    pub struct QuadVertex {
       pub quad_pos: glam::Vec2,
-      pub position_and_size: glam::Vec4,
     }
-    pub const fn VertexInput(
-      quad_pos: glam::Vec2,
-      position_and_size: glam::Vec4,
-    ) -> VertexInput {
-      VertexInput {
-        quad_pos,
-        position_and_size,
+    pub const fn QuadVertex(quad_pos: glam::Vec2) -> QuadVertex {
+      QuadVertex { quad_pos }
+    }
+    impl QuadVertex {
+      pub const OFFSET_QUAD_POS: u64 = 0;
+      pub const SIZE_QUAD_POS: u64 = 8;
+      pub const SIZE: u64 = 8;
+    }
+    impl QuadVertex {
+      #[doc = "The usages of a buffer holding a `QuadVertex`, from how the shaders bind it."]
+      pub const BUFFER_USAGES: wgpu::BufferUsages =
+        wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::VERTEX);
+    }
+    impl QuadVertex {
+      pub const VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 1] = [wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: std::mem::offset_of!(Self, quad_pos) as u64,
+        shader_location: 0,
+      }];
+      pub const fn vertex_buffer_layout(
+        step_mode: wgpu::VertexStepMode,
+      ) -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+          array_stride: std::mem::size_of::<Self>() as u64,
+          step_mode,
+          attributes: &Self::VERTEX_ATTRIBUTES,
+        }
+      }
+      #[doc = r" Creates a vertex buffer holding `vertices` with [`Self::BUFFER_USAGES`]."]
+      pub fn create_vertex_buffer(
+        device: &wgpu::Device,
+        vertices: &[Self],
+      ) -> wgpu::Buffer {
+        wgpu::util::DeviceExt::create_buffer_init(
+          device,
+          &wgpu::util::BufferInitDescriptor {
+            label: Some("compute_demo::particle_renderer::QuadVertex"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: Self::BUFFER_USAGES,
+          },
+        )
       }
     }
-    impl VertexInput {
-      pub const VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 2] = [
-        wgpu::VertexAttribute {
-          format: wgpu::VertexFormat::Float32x2,
-          offset: std::mem::offset_of!(Self, quad_pos) as u64,
-          shader_location: 0,
-        },
-        wgpu::VertexAttribute {
-          format: wgpu::VertexFormat::Float32x4,
-          offset: std::mem::offset_of!(Self, position_and_size) as u64,
-          shader_location: 1,
-        },
-      ];
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ParticleInstance {
+      pub position_and_size: glam::Vec4,
+    }
+    pub const fn ParticleInstance(position_and_size: glam::Vec4) -> ParticleInstance {
+      ParticleInstance { position_and_size }
+    }
+    impl ParticleInstance {
+      pub const OFFSET_POSITION_AND_SIZE: u64 = 0;
+      pub const SIZE_POSITION_AND_SIZE: u64 = 16;
+      pub const SIZE: u64 = 16;
+    }
+    impl ParticleInstance {
+      #[doc = "The usages of a buffer holding a `ParticleInstance`, from how the shaders bind it."]
+      pub const BUFFER_USAGES: wgpu::BufferUsages =
+        wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::VERTEX);
+    }
+    impl ParticleInstance {
+      pub const VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 1] = [wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x4,
+        offset: std::mem::offset_of!(Self, position_and_size) as u64,
+        shader_location: 1,
+      }];
       pub const fn vertex_buffer_layout(
         step_mode: wgpu::VertexStepMode,
       ) -> wgpu::VertexBufferLayout<'static> {
@@ -2916,6 +4023,22 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
           attributes: &Self::VERTEX_ATTRIBUTES,
         }
       }
+      pub const VERTEX_BUFFER_LAYOUT: wgpu::VertexBufferLayout<'static> =
+        Self::vertex_buffer_layout(wgpu::VertexStepMode::Instance);
+      #[doc = r" Creates a vertex buffer holding `vertices` with [`Self::BUFFER_USAGES`]."]
+      pub fn create_vertex_buffer(
+        device: &wgpu::Device,
+        vertices: &[Self],
+      ) -> wgpu::Buffer {
+        wgpu::util::DeviceExt::create_buffer_init(
+          device,
+          &wgpu::util::BufferInitDescriptor {
+            label: Some("compute_demo::particle_renderer::ParticleInstance"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: Self::BUFFER_USAGES,
+          },
+        )
+      }
     }
     pub const ENTRY_VS_MAIN: &str = "vs_main";
     pub const ENTRY_FS_MAIN: &str = "fs_main";
@@ -2939,13 +4062,29 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         },
       }
     }
-    pub fn vs_main_entry(vertex_input: wgpu::VertexStepMode) -> VertexEntry<1> {
+    pub fn vs_main_entry(quad_vertex: wgpu::VertexStepMode) -> VertexEntry<2> {
       VertexEntry {
         entry_point: ENTRY_VS_MAIN,
-        buffers: [Some(VertexInput::vertex_buffer_layout(vertex_input))],
+        buffers: [
+          Some(QuadVertex::vertex_buffer_layout(quad_vertex)),
+          Some(ParticleInstance::VERTEX_BUFFER_LAYOUT),
+        ],
         constants: Default::default(),
       }
     }
+    #[doc = "The vertex buffers of `vs_main`, one per buffer of [`vs_main_entry`] in the same order."]
+    #[derive(Debug, Clone, Copy)]
+    pub struct VsMainVertexBuffers<'a> {
+      pub quad_vertex: wgpu::BufferSlice<'a>,
+      pub particle_instance: wgpu::BufferSlice<'a>,
+    }
+    impl VsMainVertexBuffers<'_> {
+      #[doc = r" Sets every vertex buffer at its slot."]
+      pub fn set(&self, pass: &mut impl SetVertexBuffer) {
+        pass.set_vertex_buffer(0, self.quad_vertex);
+        pass.set_vertex_buffer(1, self.particle_instance);
+      }
+    }
     #[derive(Debug)]
     pub struct FragmentEntry<const N: usize> {
       pub entry_point: &'static str,
@@ -3025,8 +4164,11 @@ struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNRPWE2LOMRUW4Z3TX {
     mouse_pos: vec2<f32>,
 }
 
-struct VertexInput {
+struct QuadVertex {
     @location(0) quad_pos: vec2<f32>,
+}
+
+struct ParticleInstance {
     @location(1) position_and_size: vec4<f32>,
 }
 
@@ -3052,14 +4194,14 @@ fn get_frame_sizeX_naga_oil_mod_XM5WG6YTBNRPWE2LOMRUW4Z3TX() -> vec2<f32> {
 }
 
 @vertex 
-fn vs_main(input: VertexInput) -> VertexOutput {
+fn vs_main(vertex: QuadVertex, instance: ParticleInstance) -> VertexOutput {
     var output: VertexOutput;
     var particle_pixels: f32 = 8f;
 
     let _e1 = get_frame_sizeX_naga_oil_mod_XM5WG6YTBNRPWE2LOMRUW4Z3TX();
     let aspect_ratio = (_e1.x / _e1.y);
-    let particle_world_pos = input.position_and_size.xyz;
-    let particle_data = input.position_and_size.w;
+    let particle_world_pos = instance.position_and_size.xyz;
+    let particle_data = instance.position_and_size.w;
     let particle_type = (floor(particle_data) % 4f);
     let energy = (fract(particle_data) * 10f);
     if (particle_type > 90f) {
@@ -3084,10 +4226,10 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     let _e36 = particle_pixels;
     let particle_size = ((_e36 * 2f) / _e1.y);
     let screen_center = vec2<f32>(particle_world_pos.x, particle_world_pos.y);
-    let quad_offset = vec2<f32>(((input.quad_pos.x * particle_size) / aspect_ratio), (input.quad_pos.y * particle_size));
+    let quad_offset = vec2<f32>(((vertex.quad_pos.x * particle_size) / aspect_ratio), (vertex.quad_pos.y * particle_size));
     let billboard_pos = (screen_center + quad_offset);
     output.position = vec4<f32>(billboard_pos, 0f, 1f);
-    output.world_position = input.quad_pos;
+    output.world_position = vertex.quad_pos;
     output.particle_type = particle_type;
     output.energy = energy;
     if (particle_type > 90f) {
@@ -3107,26 +4249,26 @@ fn vs_main(input: VertexInput) -> VertexOutput {
             }
         }
     }
-    let _e128 = output;
-    return _e128;
+    let _e129 = output;
+    return _e129;
 }
 
 @fragment 
-fn fs_main(input_1: VertexOutput) -> @location(0) vec4<f32> {
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     var final_color: vec3<f32>;
 
     let _e0 = get_frame_sizeX_naga_oil_mod_XM5WG6YTBNRPWE2LOMRUW4Z3TX();
     let _e1 = get_timeX_naga_oil_mod_XM5WG6YTBNRPWE2LOMRUW4Z3TX();
-    let dist_from_center = length(input_1.world_position);
+    let dist_from_center = length(input.world_position);
     if (dist_from_center > 0.8f) {
         discard;
     }
     let alpha = (1f - smoothstep((0.8f - 0.08f), 0.8f, dist_from_center));
-    let particle_id = ((input_1.particle_type * 1000f) + (input_1.energy * 100f));
+    let particle_id = ((input.particle_type * 1000f) + (input.energy * 100f));
     let stable_seed = fract((sin((particle_id * 12.9898f)) * 43758.547f));
     let slow_time = (_e1 * 0.6f);
-    let pulse = (0.85f + (0.15f * sin(((slow_time * (1f + (input_1.particle_type * 0.3f))) + (stable_seed * 6.28f)))));
-    final_color = ((input_1.color * alpha) * pulse);
+    let pulse = (0.85f + (0.15f * sin(((slow_time * (1f + (input.particle_type * 0.3f))) + (stable_seed * 6.28f)))));
+    final_color = ((input.color * alpha) * pulse);
     let glow_radius = (0.8f * 0.6f);
     let core_glow = (1f - smoothstep(0f, glow_radius, dist_from_center));
     let edge_glow = (0.3f * (1f - smoothstep(glow_radius, 0.8f, dist_from_center)));
@@ -3190,6 +4332,112 @@ pub mod buffer_layouts {
       }
     }
   }
+  impl FixedLayout {
+    pub const OFFSET_PRIMARY: u64 = 0;
+    pub const SIZE_PRIMARY: u64 = 12;
+    pub const OFFSET_ACCENTS: u64 = 16;
+    pub const SIZE_ACCENTS: u64 = 32;
+    pub const OFFSET_TAG: u64 = 48;
+    pub const SIZE_TAG: u64 = 4;
+    pub const SIZE: u64 = 64;
+    #[doc = "Writes `primary` of the `FixedLayout` stored at `base_offset` in `buffer`."]
+    pub fn write_field_primary(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &glam::Vec3,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.primary = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_PRIMARY as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_PRIMARY,
+        &bytes[..Self::SIZE_PRIMARY as usize],
+      );
+    }
+    #[doc = "Writes `accents` of the `FixedLayout` stored at `base_offset` in `buffer`."]
+    pub fn write_field_accents(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &[glam::Vec3; 2],
+    ) {
+      let value = *value;
+      let value: &[Padded<4usize, glam::Vec3>; 2] = &pad_array(value);
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.accents = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_ACCENTS as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_ACCENTS,
+        &bytes[..Self::SIZE_ACCENTS as usize],
+      );
+    }
+    #[doc = "Writes `tag` of the `FixedLayout` stored at `base_offset` in `buffer`."]
+    pub fn write_field_tag(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &u32,
+    ) {
+      let mut this = <Self as bytemuck::Zeroable>::zeroed();
+      this.tag = *value;
+      let bytes = &bytemuck::bytes_of(&this)[Self::OFFSET_TAG as usize..];
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_TAG,
+        &bytes[..Self::SIZE_TAG as usize],
+      );
+    }
+  }
+  impl FixedLayout {
+    #[doc = "Reads a `FixedLayout` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+      let size = ::core::mem::size_of::<Self>();
+      let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+        expected: size,
+        actual: bytes.len(),
+      })?;
+      bytemuck::try_from_bytes(bytes)
+        .map_err(|_| LayoutError::Misaligned { alignment: 16 })
+    }
+  }
+  impl FixedLayout {
+    #[doc = "The usages of a buffer holding a `FixedLayout`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages = wgpu::BufferUsages::COPY_DST
+      .union(wgpu::BufferUsages::UNIFORM)
+      .union(wgpu::BufferUsages::STORAGE);
+    #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("buffer_layouts::FixedLayout"),
+          contents: bytemuck::bytes_of(value),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
+  }
+  impl FixedLayout {
+    #[doc = "Returns `accents` without its padding."]
+    pub fn accents(&self) -> [glam::Vec3; 2] {
+      ::core::array::from_fn(|index_0| self.accents[index_0].field)
+    }
+    #[doc = "Sets `accents`, padding it to its WGSL layout."]
+    pub fn set_accents(&mut self, value: [glam::Vec3; 2]) {
+      self.accents = pad_array(value);
+    }
+    #[doc = "Returns element `index` of `accents` without its padding."]
+    pub fn accents_at(&self, index: usize) -> glam::Vec3 {
+      self.accents[index].field
+    }
+    #[doc = "Sets element `index` of `accents`, padding it to its WGSL layout."]
+    pub fn set_accents_at(&mut self, index: usize, value: glam::Vec3) {
+      self.accents[index] = Padded::new(value);
+    }
+  }
   #[repr(C)]
   #[derive(Debug, PartialEq, Clone, Copy)]
   pub struct FixedLayoutInit {
@@ -3284,6 +4532,27 @@ pub mod buffer_layouts {
     );
   };
   unsafe impl __wgsl_bindgen_padded_layout::Valid for Padded<4usize, glam::Vec3> {}
+  #[doc = r" Why bytes could not be read as a struct with its WGSL layout."]
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum LayoutError {
+    #[doc = r" There are fewer bytes than the struct needs."]
+    TooShort { expected: usize, actual: usize },
+    #[doc = r" The bytes do not start at a multiple of the struct alignment."]
+    Misaligned { alignment: usize },
+  }
+  impl ::core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+      match self {
+        Self::TooShort { expected, actual } => {
+          write!(f, "expected at least {expected} bytes, got {actual}")
+        }
+        Self::Misaligned { alignment } => {
+          write!(f, "bytes are not aligned to {alignment}")
+        }
+      }
+    }
+  }
+  impl std::error::Error for LayoutError {}
   #[repr(C, align(16))]
   #[derive(Debug, PartialEq, Clone, Copy)]
   pub struct RuntimeLayout<Tail = [glam::Vec4]>
@@ -3371,6 +4640,102 @@ pub mod buffer_layouts {
       }
     }
   }
+  impl RuntimeLayout {
+    pub const OFFSET_HEADER: u64 = 0;
+    pub const SIZE_HEADER: u64 = 64;
+    pub const OFFSET_COLORS: u64 = 64;
+    #[doc = "Writes `header` of the `RuntimeLayout` stored at `base_offset` in `buffer`."]
+    pub fn write_field_header(
+      queue: &wgpu::Queue,
+      buffer: &wgpu::Buffer,
+      base_offset: wgpu::BufferAddress,
+      value: &FixedLayout,
+    ) {
+      let bytes = bytemuck::bytes_of(value);
+      queue.write_buffer(
+        buffer,
+        base_offset + Self::OFFSET_HEADER,
+        &bytes[..Self::SIZE_HEADER as usize],
+      );
+    }
+  }
+  impl RuntimeLayout {
+    #[doc = "Reads a `RuntimeLayout` from bytes with its WGSL layout. Bytes past the end of the struct are ignored."]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError>
+    where
+      FixedLayout: bytemuck::Pod,
+      glam::Vec4: bytemuck::Pod,
+    {
+      use core::{mem, ptr};
+      let tail_offset = mem::offset_of!(RuntimeLayoutSized<0>, colors);
+      let alignment = mem::align_of::<RuntimeLayoutSized<0>>();
+      let aligned_len = bytes.len() / alignment * alignment;
+      let len = aligned_len.saturating_sub(tail_offset) / mem::size_of::<glam::Vec4>();
+      let size =
+        (tail_offset + len * mem::size_of::<glam::Vec4>()).next_multiple_of(alignment);
+      if bytes.len() < size {
+        return Err(LayoutError::TooShort {
+          expected: size,
+          actual: bytes.len(),
+        });
+      }
+      if bytes.as_ptr().align_offset(alignment) != 0 {
+        return Err(LayoutError::Misaligned { alignment });
+      }
+      let this = ptr::slice_from_raw_parts(bytes.as_ptr().cast::<glam::Vec4>(), len)
+        as *const Self;
+      Ok(unsafe { &*this })
+    }
+    #[doc = "Reads a `RuntimeLayout` from bytes with its WGSL layout, split into its fixed-size fields and its runtime-sized `colors`. Unlike [`Self::from_bytes`], `colors` gets every element that fits, like `arrayLength` in WGSL, even if the struct padding after them does not."]
+    pub fn tail_from_bytes(
+      bytes: &[u8],
+    ) -> Result<(&RuntimeLayoutSized<0>, &[glam::Vec4]), LayoutError>
+    where
+      FixedLayout: bytemuck::Pod,
+      glam::Vec4: bytemuck::Pod,
+    {
+      use core::mem;
+      let this = Self::from_bytes(bytes)?;
+      let header = this as *const Self as *const RuntimeLayoutSized<0>;
+      let tail_offset = mem::offset_of!(RuntimeLayoutSized<0>, colors);
+      let len = (bytes.len() - tail_offset) / mem::size_of::<glam::Vec4>();
+      let tail = &bytes[tail_offset..][..len * mem::size_of::<glam::Vec4>()];
+      Ok((unsafe { &*header }, bytemuck::cast_slice(tail)))
+    }
+  }
+  impl RuntimeLayout {
+    #[doc = "The usages of a buffer holding a `RuntimeLayout`, from how the shaders bind it."]
+    pub const BUFFER_USAGES: wgpu::BufferUsages =
+      wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::STORAGE);
+    #[doc = r" Creates a buffer holding `value` with [`Self::BUFFER_USAGES`]."]
+    pub fn create_buffer(device: &wgpu::Device, value: &Self) -> wgpu::Buffer
+    where
+      FixedLayout: bytemuck::Pod,
+      glam::Vec4: bytemuck::Pod,
+    {
+      wgpu::util::DeviceExt::create_buffer_init(
+        device,
+        &wgpu::util::BufferInitDescriptor {
+          label: Some("buffer_layouts::RuntimeLayout"),
+          contents: value.as_bytes(),
+          usage: Self::BUFFER_USAGES,
+        },
+      )
+    }
+    #[doc = "Creates a zeroed storage buffer for a `RuntimeLayout` with `len` elements in `colors`."]
+    pub fn create_storage_buffer_with_len(
+      device: &wgpu::Device,
+      len: usize,
+    ) -> wgpu::Buffer {
+      let size = Self::OFFSET_COLORS + len as wgpu::BufferAddress * 16;
+      device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("buffer_layouts::RuntimeLayout"),
+        size: size.next_multiple_of(16),
+        usage: Self::BUFFER_USAGES,
+        mapped_at_creation: false,
+      })
+    }
+  }
   #[doc(hidden)]
   mod __wgsl_bindgen_runtime_array_sealed {
     pub trait Sealed {}
@@ -3497,7 +4862,7 @@ pub mod buffer_layouts {
           #[doc = " @binding(0): \"uniform_layout\""]
           wgpu::BindGroupLayoutEntry {
             binding: 0,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Buffer {
               ty: wgpu::BufferBindingType::Uniform,
               has_dynamic_offset: false,
@@ -3510,7 +4875,7 @@ pub mod buffer_layouts {
           #[doc = " @binding(1): \"direct_layout\""]
           wgpu::BindGroupLayoutEntry {
             binding: 1,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Buffer {
               ty: wgpu::BufferBindingType::Storage { read_only: true },
               has_dynamic_offset: false,
@@ -3523,7 +4888,7 @@ pub mod buffer_layouts {
           #[doc = " @binding(2): \"layout_array\""]
           wgpu::BindGroupLayoutEntry {
             binding: 2,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Buffer {
               ty: wgpu::BufferBindingType::Storage { read_only: true },
               has_dynamic_offset: false,
@@ -3534,7 +4899,7 @@ pub mod buffer_layouts {
           #[doc = " @binding(3): \"runtime_layout\""]
           wgpu::BindGroupLayoutEntry {
             binding: 3,
-            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            visibility: wgpu::ShaderStages::FRAGMENT,
             ty: wgpu::BindingType::Buffer {
               ty: wgpu::BufferBindingType::Storage { read_only: true },
               has_dynamic_offset: false,
@@ -83,11 +83,26 @@ pub fn demangle_str(string: &str) -> Cow<'_, str> {
   })
 }
 
+/// Splits a name mangled by naga_oil into the module it was imported from, as
+/// given to the composer, and the name it was declared with.
+///
+///   * "UniformsX_naga_oil_mod_XOR4XAZLTX" => (Some("types"), "Uniforms")
+///   * "Uniforms" => (None, "Uniforms")
+pub fn split_mangled_name(string: &str) -> (Option<String>, &str) {
+  match undecorate_regex().captures(string) {
+    Some(caps) if caps.get(0).unwrap().len() == string.len() => {
+      let name = caps.get(2).unwrap().as_str();
+      (Some(decode(caps.get(3).unwrap().as_str())), name)
+    }
+    _ => (None, string),
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use pretty_assertions::assert_eq;
 
-  use crate::bevy_util::make_valid_rust_import;
+  use crate::bevy_util::{make_valid_rust_import, split_mangled_name};
   use crate::quote_gen::RustSourceItemPath;
 
   #[test]
@@ -114,4 +129,13 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn test_split_mangled_name() {
+    assert_eq!(
+      split_mangled_name("UniformsX_naga_oil_mod_XOR4XAZLTX"),
+      (Some("types".to_string()), "Uniforms")
+    );
+    assert_eq!(split_mangled_name("Uniforms"), (None, "Uniforms"));
+  }
 }
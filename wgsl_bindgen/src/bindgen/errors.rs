@@ -25,7 +25,13 @@ pub enum WgslBindgenError {
     inner: naga_oil::compose::ComposerErrorInner,
   },
 
+  #[error(
+    "Entry `{entry}` declares override-sized arrays, which are only supported in shaders without imports"
+  )]
+  OverrideSizedArrayWithImports { entry: String },
+
   #[error(transparent)]
+  #[diagnostic(transparent)]
   ModuleCreationError(#[from] CreateModuleError),
 
   #[error(transparent)]
@@ -14,7 +14,8 @@ use regex::Regex;
 pub use types::*;
 
 use crate::{
-  FastIndexMap, WGSLBindgen, WgslBindgenError, WgslType, WgslTypeSerializeStrategy,
+  FastIndexMap, WGSLBindgen, WgslBindgenError, WgslLayoutReport, WgslType,
+  WgslTypeSerializeStrategy,
 };
 
 /// An enum representing the source type that will be generated for the output.
@@ -130,6 +131,89 @@ impl From<(&str, &str, TokenStream)> for OverrideStructFieldType {
   }
 }
 
+/// Struct for overriding the vertex buffer format of vertex attributes in
+/// specific structs, eg: a `vec4<f32>` color stored as `Unorm8x4`.
+#[derive(Clone, Debug)]
+pub struct OverrideVertexFormat {
+  pub struct_regex: Regex,
+  pub field_regex: Regex,
+  pub format: wgpu::VertexFormat,
+}
+impl From<(Regex, Regex, wgpu::VertexFormat)> for OverrideVertexFormat {
+  fn from(
+    (struct_regex, field_regex, format): (Regex, Regex, wgpu::VertexFormat),
+  ) -> Self {
+    Self {
+      struct_regex,
+      field_regex,
+      format,
+    }
+  }
+}
+impl From<(&str, &str, wgpu::VertexFormat)> for OverrideVertexFormat {
+  fn from((struct_regex, field_regex, format): (&str, &str, wgpu::VertexFormat)) -> Self {
+    Self {
+      struct_regex: Regex::new(struct_regex).expect("Failed to create struct regex"),
+      field_regex: Regex::new(field_regex).expect("Failed to create field regex"),
+      format,
+    }
+  }
+}
+
+/// Struct for fixing the step mode of matching vertex inputs at generation time,
+/// eg: every struct named `.*Instance` stepping per instance.
+#[derive(Clone, Debug)]
+pub struct OverrideVertexStepMode {
+  /// Regex to match the fully qualified name of a vertex input struct, or the
+  /// name of a vertex input argument that is not in a struct.
+  pub input_regex: Regex,
+  pub step_mode: wgpu::VertexStepMode,
+}
+impl From<(Regex, wgpu::VertexStepMode)> for OverrideVertexStepMode {
+  fn from((input_regex, step_mode): (Regex, wgpu::VertexStepMode)) -> Self {
+    Self {
+      input_regex,
+      step_mode,
+    }
+  }
+}
+impl From<(&str, wgpu::VertexStepMode)> for OverrideVertexStepMode {
+  fn from((input_regex, step_mode): (&str, wgpu::VertexStepMode)) -> Self {
+    Self {
+      input_regex: Regex::new(input_regex).expect("Failed to create input regex"),
+      step_mode,
+    }
+  }
+}
+
+/// Struct for generating the vertex pulling variant of matching vertex input
+/// structs, read by shaders from a storage buffer at `@group(group) @binding(binding)`.
+#[derive(Clone, Debug)]
+pub struct VertexPulling {
+  /// Regex to match the fully qualified name of a vertex input struct.
+  pub struct_regex: Regex,
+  pub group: u32,
+  pub binding: u32,
+}
+impl From<(Regex, u32, u32)> for VertexPulling {
+  fn from((struct_regex, group, binding): (Regex, u32, u32)) -> Self {
+    Self {
+      struct_regex,
+      group,
+      binding,
+    }
+  }
+}
+impl From<(&str, u32, u32)> for VertexPulling {
+  fn from((struct_regex, group, binding): (&str, u32, u32)) -> Self {
+    Self {
+      struct_regex: Regex::new(struct_regex).expect("Failed to create struct regex"),
+      group,
+      binding,
+    }
+  }
+}
+
 /// Struct for overriding alignment of specific structs.
 #[derive(Clone, Debug)]
 pub struct OverrideStructAlignment {
@@ -153,6 +237,54 @@ impl From<(&str, u16)> for OverrideStructAlignment {
   }
 }
 
+/// Struct for adding extra attributes to matching generated structs and their
+/// `*Init` counterparts, eg: `#[non_exhaustive]`.
+#[derive(Clone, Debug)]
+pub struct AddStructAttributes {
+  pub struct_regex: Regex,
+  pub attributes: TokenStream,
+}
+impl From<(Regex, TokenStream)> for AddStructAttributes {
+  fn from((struct_regex, attributes): (Regex, TokenStream)) -> Self {
+    Self {
+      struct_regex,
+      attributes,
+    }
+  }
+}
+impl From<(&str, TokenStream)> for AddStructAttributes {
+  fn from((struct_regex, attributes): (&str, TokenStream)) -> Self {
+    Self {
+      struct_regex: Regex::new(struct_regex).expect("Failed to create struct regex"),
+      attributes,
+    }
+  }
+}
+
+/// Struct for adding extra derives to matching generated structs and their
+/// `*Init` counterparts, eg: `["Eq", "Hash"]`.
+#[derive(Clone, Debug)]
+pub struct AddStructDerives {
+  pub struct_regex: Regex,
+  pub derives: Vec<String>,
+}
+impl<D: Into<String>, const N: usize> From<(Regex, [D; N])> for AddStructDerives {
+  fn from((struct_regex, derives): (Regex, [D; N])) -> Self {
+    Self {
+      struct_regex,
+      derives: derives.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+impl<D: Into<String>, const N: usize> From<(&str, [D; N])> for AddStructDerives {
+  fn from((struct_regex, derives): (&str, [D; N])) -> Self {
+    Self::from((
+      Regex::new(struct_regex).expect("Failed to create struct regex"),
+      derives,
+    ))
+  }
+}
+
 /// Struct for overriding binding module path of bindgroup entry
 #[derive(Clone, Debug)]
 pub struct OverrideBindGroupEntryModulePath {
@@ -202,6 +334,26 @@ impl From<(&str, bool)> for OverrideTextureFilterability {
   }
 }
 
+/// Struct for marking buffer bindings as having a dynamic offset, set along
+/// with their bind group.
+#[derive(Clone, Debug)]
+pub struct OverrideDynamicOffset {
+  /// Regex to match binding path (e.g., ".*::per_draw")
+  pub binding_regex: Regex,
+}
+impl From<Regex> for OverrideDynamicOffset {
+  fn from(binding_regex: Regex) -> Self {
+    Self { binding_regex }
+  }
+}
+impl From<&str> for OverrideDynamicOffset {
+  fn from(binding_regex: &str) -> Self {
+    Self {
+      binding_regex: Regex::new(binding_regex).expect("Failed to create binding regex"),
+    }
+  }
+}
+
 /// Enum for sampler binding types
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SamplerType {
@@ -282,8 +434,8 @@ pub struct WgslBindgenOption {
   pub skip_hash_check: bool,
 
   /// Derive [encase::ShaderType](https://docs.rs/encase/latest/encase/trait.ShaderType.html#)
-  /// for user defined WGSL structs when `WgslTypeSerializeStrategy::Encase`.
-  /// else derive bytemuck
+  /// for user defined WGSL structs when `WgslTypeSerializeStrategy::Encase`,
+  /// zerocopy traits when `WgslTypeSerializeStrategy::Zerocopy`, else derive bytemuck
   #[builder(default)]
   pub serialization_strategy: WgslTypeSerializeStrategy,
 
@@ -331,12 +483,38 @@ pub struct WgslBindgenOption {
   #[builder(default, setter(into))]
   pub override_struct_field_type: Vec<OverrideStructFieldType>,
 
+  /// A vector of `OverrideVertexFormat` to store matching vertex attributes in a different buffer format
+  /// than their WGSL type, eg: normalized or packed integers. The generated field type and
+  /// `VertexAttribute::format` both follow the override.
+  #[builder(default, setter(into))]
+  pub override_vertex_format: Vec<OverrideVertexFormat>,
+
+  /// A vector of `OverrideVertexStepMode` fixing the step mode of matching vertex inputs. Vertex entry
+  /// functions take no step mode parameter for them, and their buffer layouts become consts.
+  #[builder(default, setter(into))]
+  pub override_vertex_step_mode: Vec<OverrideVertexStepMode>,
+
+  /// A vector of `VertexPulling` generating a WGSL library and a host-shareable `*Pulled` struct for
+  /// matching vertex input structs, so shaders can fetch them from a storage buffer instead of
+  /// vertex attributes.
+  #[builder(default, setter(into))]
+  pub vertex_pulling: Vec<VertexPulling>,
+
   /// A vector of regular expressions and alignments that override the generated alignment for matching structs.
   /// This can be used in scenarios where a specific minimum alignment is required for a uniform buffer.
   /// Refer to the [WebGPU specs](https://www.w3.org/TR/webgpu/#dom-supported-limits-minuniformbufferoffsetalignment) for more information.
   #[builder(default, setter(into))]
   pub override_struct_alignment: Vec<OverrideStructAlignment>,
 
+  /// A vector of regular expressions and attributes added to matching generated structs and their `*Init` counterparts.
+  #[builder(default, setter(into))]
+  pub struct_attributes: Vec<AddStructAttributes>,
+
+  /// A vector of regular expressions and derives added to matching generated structs and their `*Init` counterparts.
+  /// Derives already generated for a struct are not repeated.
+  #[builder(default, setter(into))]
+  pub struct_derives: Vec<AddStructDerives>,
+
   /// A vector of regular expressions and target module path that that override the module path for bind group entries.
   /// This can be used to customize where bind group entries are generated in the output code.
   #[builder(default, setter(into))]
@@ -347,7 +525,17 @@ pub struct WgslBindgenOption {
   #[builder(default, setter(each(name = "add_custom_padding_field_regexp", into)))]
   pub custom_padding_field_regexps: Vec<Regex>,
 
-  /// Whether to always have the init struct generated in the out. This is only applicable when using bytemuck mode.
+  /// Where to report the WGSL layout of host-shareable structs, as doc tables on the
+  /// generated structs and/or a JSON file next to the output. Defaults to no report.
+  #[builder(default)]
+  pub layout_report: BitFlags<WgslLayoutReport>,
+
+  /// Whether to emit a `#[cfg(test)] mod layout_tests` that serializes each host-shareable
+  /// struct filled with sentinel values and checks every field's bytes at its WGSL offset.
+  #[builder(default = "false")]
+  pub layout_tests: bool,
+
+  /// Whether to always have the init struct generated in the out. This is only applicable when using bytemuck or zerocopy mode.
   #[builder(default = "false")]
   pub always_generate_init_struct: bool,
 
@@ -369,6 +557,23 @@ pub struct WgslBindgenOption {
   #[builder(default, setter(into))]
   pub override_sampler_type: Vec<OverrideSamplerType>,
 
+  /// A vector of uniform and storage buffer bindings with dynamic offsets.
+  /// Their bind groups take a typed `*DynamicOffsets` struct in `set_with_offsets` instead of `set`.
+  #[builder(default, setter(into))]
+  pub override_dynamic_offset: Vec<OverrideDynamicOffset>,
+
+  /// Whether bind group entries take uniform and storage buffers as a `typed_buffer::TypedBuffer<'a, T>`
+  /// of the type bound in the shader instead of a `wgpu::BufferBinding<'a>`. The generated buffer
+  /// creation helpers then return a `typed_buffer::Buffer<T>` to take them from.
+  #[builder(default = "false")]
+  pub typed_buffer_bindings: bool,
+
+  /// Whether bind group entries take sampled and storage textures as a `typed_texture` view matching
+  /// the binding's dimension and sample type or format, eg: `TextureView2d<Float>` or
+  /// `StorageTextureView<Rgba8Unorm, WriteOnly>`, instead of a `wgpu::TextureView`.
+  #[builder(default = "false")]
+  pub typed_texture_views: bool,
+
   /// Shader definitions to be passed to naga-oil for conditional compilation.
   /// These are preprocessor definitions that can be used in WGSL shaders with #ifdef, #ifndef, etc.
   #[builder(default, setter(into))]
@@ -398,6 +603,34 @@ impl WgslBindgenOptionBuilder {
     self
   }
 
+  /// Adds attributes, eg: `quote!(#[non_exhaustive])`, to the generated structs
+  /// whose fully qualified name matches `struct_regex`, and to their `*Init` structs.
+  pub fn add_struct_attributes(
+    &mut self,
+    struct_regex: &str,
+    attributes: TokenStream,
+  ) -> &mut Self {
+    self
+      .struct_attributes
+      .get_or_insert_with(Vec::new)
+      .push((struct_regex, attributes).into());
+    self
+  }
+
+  /// Adds derives, eg: `["Eq", "Hash"]`, to the generated structs whose fully
+  /// qualified name matches `struct_regex`, and to their `*Init` structs.
+  pub fn add_struct_derives<D: Into<String>, const N: usize>(
+    &mut self,
+    struct_regex: &str,
+    derives: [D; N],
+  ) -> &mut Self {
+    self
+      .struct_derives
+      .get_or_insert_with(Vec::new)
+      .push((struct_regex, derives).into());
+    self
+  }
+
   /// Add a shader definition value
   pub fn add_shader_def(
     &mut self,
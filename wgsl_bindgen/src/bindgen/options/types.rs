@@ -54,7 +54,7 @@ impl WgslTypeMapBuild for GlamWgslTypeMap {
     #[cfg(feature = "glam")]
     {
       let is_encase = serialize_strategy.is_encase();
-      let types = vec![
+      let mut types = vec![
         (Vector(Vec2i), type_info_from_rust::<glam::IVec2>(quote!(glam::IVec2))),
         (Vector(Vec3i), type_info_from_rust::<glam::IVec3>(quote!(glam::IVec3))),
         (Vector(Vec4i), type_info_from_rust::<glam::IVec4>(quote!(glam::IVec4))),
@@ -68,6 +68,35 @@ impl WgslTypeMapBuild for GlamWgslTypeMap {
         (Matrix(Mat3x3f), type_info_from_rust::<glam::Mat3A>(quote!(glam::Mat3A))),
         (Matrix(Mat4x4f), type_info_from_rust::<glam::Mat4>(quote!(glam::Mat4))),
       ];
+
+      // encase cannot serialize f16, f64 or 64-bit integers.
+      if !is_encase {
+        // glam has no f16 vectors, so these stay plain `half::f16` arrays
+        // sized to the vector itself. The struct layout pads them out to the
+        // WGSL alignment. `half::f16` is a transparent `u16`.
+        types.extend([
+          (Vector(Vec2h), type_info_from_rust::<[u16; 2]>(quote!([half::f16; 2]))),
+          (Vector(Vec3h), type_info_from_rust::<[u16; 3]>(quote!([half::f16; 3]))),
+          (Vector(Vec4h), type_info_from_rust::<[u16; 4]>(quote!([half::f16; 4]))),
+        ]);
+
+        // `DMat3` is three unpadded `DVec3` columns, which does not match the
+        // WGSL column stride, so `mat3x3<f64>` falls back to arrays.
+        types.extend([
+          (Vector(Vec2d), type_info_from_rust::<glam::DVec2>(quote!(glam::DVec2))),
+          (Vector(Vec3d), type_info_from_rust::<glam::DVec3>(quote!(glam::DVec3))),
+          (Vector(Vec4d), type_info_from_rust::<glam::DVec4>(quote!(glam::DVec4))),
+          (Vector(Vec2i64), type_info_from_rust::<glam::I64Vec2>(quote!(glam::I64Vec2))),
+          (Vector(Vec3i64), type_info_from_rust::<glam::I64Vec3>(quote!(glam::I64Vec3))),
+          (Vector(Vec4i64), type_info_from_rust::<glam::I64Vec4>(quote!(glam::I64Vec4))),
+          (Vector(Vec2u64), type_info_from_rust::<glam::U64Vec2>(quote!(glam::U64Vec2))),
+          (Vector(Vec3u64), type_info_from_rust::<glam::U64Vec3>(quote!(glam::U64Vec3))),
+          (Vector(Vec4u64), type_info_from_rust::<glam::U64Vec4>(quote!(glam::U64Vec4))),
+          (Matrix(Mat2x2d), type_info_from_rust::<glam::DMat2>(quote!(glam::DMat2))),
+          (Matrix(Mat4x4d), type_info_from_rust::<glam::DMat4>(quote!(glam::DMat4))),
+        ]);
+      }
+
       types.into_iter().collect()
     }
 
@@ -212,3 +241,266 @@ impl WgslTypeMapBuild for NalgebraWgslTypeMap {
     }
   }
 }
+
+/// `mint` types like `mint::Vector4<f32>` or `mint::ColumnMatrix4<f32>`.
+/// Matrices whose columns would need padding, like `mat3x3<f32>`, will use the output from [RustWgslTypeMap].
+#[derive(Clone)]
+pub struct MintWgslTypeMap;
+
+impl WgslTypeMapBuild for MintWgslTypeMap {
+  fn build(&self, serialize_strategy: WgslTypeSerializeStrategy) -> WgslTypeMap {
+    use crate::WgslMatType::*;
+    use crate::WgslType::*;
+    use crate::WgslVecType::*;
+
+    // Only bytemuck structs can hold `mint` types, through their own `Pod`
+    // impl. zerocopy and encase need trait impls on every field type that
+    // `mint` does not provide, so they use the output from [RustWgslTypeMap].
+    if !serialize_strategy.is_bytemuck() {
+      return WgslTypeMap::default();
+    }
+
+    #[cfg(feature = "mint")]
+    {
+      vec![
+        (
+          Vector(Vec2i),
+          type_info_from_rust::<mint::Vector2<i32>>(quote!(mint::Vector2<i32>)),
+        ),
+        (
+          Vector(Vec3i),
+          type_info_from_rust::<mint::Vector3<i32>>(quote!(mint::Vector3<i32>)),
+        ),
+        (
+          Vector(Vec4i),
+          type_info_from_rust::<mint::Vector4<i32>>(quote!(mint::Vector4<i32>)),
+        ),
+        (
+          Vector(Vec2u),
+          type_info_from_rust::<mint::Vector2<u32>>(quote!(mint::Vector2<u32>)),
+        ),
+        (
+          Vector(Vec3u),
+          type_info_from_rust::<mint::Vector3<u32>>(quote!(mint::Vector3<u32>)),
+        ),
+        (
+          Vector(Vec4u),
+          type_info_from_rust::<mint::Vector4<u32>>(quote!(mint::Vector4<u32>)),
+        ),
+        (
+          Vector(Vec2f),
+          type_info_from_rust::<mint::Vector2<f32>>(quote!(mint::Vector2<f32>)),
+        ),
+        (
+          Vector(Vec3f),
+          type_info_from_rust::<mint::Vector3<f32>>(quote!(mint::Vector3<f32>)),
+        ),
+        (
+          Vector(Vec4f),
+          type_info_from_rust::<mint::Vector4<f32>>(quote!(mint::Vector4<f32>)),
+        ),
+        // mint names matrices by rows x columns, WGSL by columns x rows.
+        (
+          Matrix(Mat2x2f),
+          type_info_from_rust::<mint::ColumnMatrix2<f32>>(quote!(
+            mint::ColumnMatrix2<f32>
+          )),
+        ),
+        (
+          Matrix(Mat3x2f),
+          type_info_from_rust::<mint::ColumnMatrix2x3<f32>>(quote!(
+            mint::ColumnMatrix2x3<f32>
+          )),
+        ),
+        (
+          Matrix(Mat4x2f),
+          type_info_from_rust::<mint::ColumnMatrix2x4<f32>>(quote!(
+            mint::ColumnMatrix2x4<f32>
+          )),
+        ),
+        (
+          Matrix(Mat2x4f),
+          type_info_from_rust::<mint::ColumnMatrix4x2<f32>>(quote!(
+            mint::ColumnMatrix4x2<f32>
+          )),
+        ),
+        (
+          Matrix(Mat3x4f),
+          type_info_from_rust::<mint::ColumnMatrix4x3<f32>>(quote!(
+            mint::ColumnMatrix4x3<f32>
+          )),
+        ),
+        (
+          Matrix(Mat4x4f),
+          type_info_from_rust::<mint::ColumnMatrix4<f32>>(quote!(
+            mint::ColumnMatrix4<f32>
+          )),
+        ),
+      ]
+      .into_iter()
+      .collect()
+    }
+
+    #[cfg(not(feature = "mint"))]
+    {
+      // No fallback when mint feature is not enabled
+      WgslTypeMap::default()
+    }
+  }
+}
+
+/// `cgmath` types like `cgmath::Vector4<f32>` or `cgmath::Matrix4<f32>`.
+/// Types not representable by `cgmath` like `mat2x3<f32>` will use the output from [RustWgslTypeMap].
+#[derive(Clone)]
+pub struct CgmathWgslTypeMap;
+
+impl WgslTypeMapBuild for CgmathWgslTypeMap {
+  fn build(&self, serialize_strategy: WgslTypeSerializeStrategy) -> WgslTypeMap {
+    use crate::WgslMatType::*;
+    use crate::WgslType::*;
+    use crate::WgslVecType::*;
+
+    // Only bytemuck structs can hold `cgmath` types, through their own `Pod`
+    // impl. zerocopy and encase need trait impls on every field type that
+    // `cgmath` does not provide, so they use the output from [RustWgslTypeMap].
+    if !serialize_strategy.is_bytemuck() {
+      return WgslTypeMap::default();
+    }
+
+    #[cfg(feature = "cgmath")]
+    {
+      vec![
+        (
+          Vector(Vec2i),
+          type_info_from_rust::<cgmath::Vector2<i32>>(quote!(cgmath::Vector2<i32>)),
+        ),
+        (
+          Vector(Vec3i),
+          type_info_from_rust::<cgmath::Vector3<i32>>(quote!(cgmath::Vector3<i32>)),
+        ),
+        (
+          Vector(Vec4i),
+          type_info_from_rust::<cgmath::Vector4<i32>>(quote!(cgmath::Vector4<i32>)),
+        ),
+        (
+          Vector(Vec2u),
+          type_info_from_rust::<cgmath::Vector2<u32>>(quote!(cgmath::Vector2<u32>)),
+        ),
+        (
+          Vector(Vec3u),
+          type_info_from_rust::<cgmath::Vector3<u32>>(quote!(cgmath::Vector3<u32>)),
+        ),
+        (
+          Vector(Vec4u),
+          type_info_from_rust::<cgmath::Vector4<u32>>(quote!(cgmath::Vector4<u32>)),
+        ),
+        (
+          Vector(Vec2f),
+          type_info_from_rust::<cgmath::Vector2<f32>>(quote!(cgmath::Vector2<f32>)),
+        ),
+        (
+          Vector(Vec3f),
+          type_info_from_rust::<cgmath::Vector3<f32>>(quote!(cgmath::Vector3<f32>)),
+        ),
+        (
+          Vector(Vec4f),
+          type_info_from_rust::<cgmath::Vector4<f32>>(quote!(cgmath::Vector4<f32>)),
+        ),
+        (
+          Matrix(Mat2x2f),
+          type_info_from_rust::<cgmath::Matrix2<f32>>(quote!(cgmath::Matrix2<f32>)),
+        ),
+        (
+          Matrix(Mat4x4f),
+          type_info_from_rust::<cgmath::Matrix4<f32>>(quote!(cgmath::Matrix4<f32>)),
+        ),
+      ]
+      .into_iter()
+      .collect()
+    }
+
+    #[cfg(not(feature = "cgmath"))]
+    {
+      // No fallback when cgmath feature is not enabled
+      WgslTypeMap::default()
+    }
+  }
+}
+
+/// `ultraviolet` types like `ultraviolet::Vec4` or `ultraviolet::Mat4`.
+/// Types not representable by `ultraviolet` like `mat2x3<f32>` will use the output from [RustWgslTypeMap].
+#[derive(Clone)]
+pub struct UltravioletWgslTypeMap;
+
+impl WgslTypeMapBuild for UltravioletWgslTypeMap {
+  fn build(&self, serialize_strategy: WgslTypeSerializeStrategy) -> WgslTypeMap {
+    use crate::WgslMatType::*;
+    use crate::WgslType::*;
+    use crate::WgslVecType::*;
+
+    // Only bytemuck structs can hold `ultraviolet` types, through their own `Pod`
+    // impl. zerocopy and encase need trait impls on every field type that
+    // `ultraviolet` does not provide, so they use the output from [RustWgslTypeMap].
+    if !serialize_strategy.is_bytemuck() {
+      return WgslTypeMap::default();
+    }
+
+    #[cfg(feature = "ultraviolet")]
+    {
+      vec![
+        (
+          Vector(Vec2i),
+          type_info_from_rust::<ultraviolet::IVec2>(quote!(ultraviolet::IVec2)),
+        ),
+        (
+          Vector(Vec3i),
+          type_info_from_rust::<ultraviolet::IVec3>(quote!(ultraviolet::IVec3)),
+        ),
+        (
+          Vector(Vec4i),
+          type_info_from_rust::<ultraviolet::IVec4>(quote!(ultraviolet::IVec4)),
+        ),
+        (
+          Vector(Vec2u),
+          type_info_from_rust::<ultraviolet::UVec2>(quote!(ultraviolet::UVec2)),
+        ),
+        (
+          Vector(Vec3u),
+          type_info_from_rust::<ultraviolet::UVec3>(quote!(ultraviolet::UVec3)),
+        ),
+        (
+          Vector(Vec4u),
+          type_info_from_rust::<ultraviolet::UVec4>(quote!(ultraviolet::UVec4)),
+        ),
+        (
+          Vector(Vec2f),
+          type_info_from_rust::<ultraviolet::Vec2>(quote!(ultraviolet::Vec2)),
+        ),
+        (
+          Vector(Vec3f),
+          type_info_from_rust::<ultraviolet::Vec3>(quote!(ultraviolet::Vec3)),
+        ),
+        (
+          Vector(Vec4f),
+          type_info_from_rust::<ultraviolet::Vec4>(quote!(ultraviolet::Vec4)),
+        ),
+        (
+          Matrix(Mat2x2f),
+          type_info_from_rust::<ultraviolet::Mat2>(quote!(ultraviolet::Mat2)),
+        ),
+        (
+          Matrix(Mat4x4f),
+          type_info_from_rust::<ultraviolet::Mat4>(quote!(ultraviolet::Mat4)),
+        ),
+      ]
+      .into_iter()
+      .collect()
+    }
+
+    #[cfg(not(feature = "ultraviolet"))]
+    {
+      // No fallback when ultraviolet feature is not enabled
+      WgslTypeMap::default()
+    }
+  }
+}
@@ -1,20 +1,44 @@
 use std::io::Write;
 
+use naga_oil::compose::preprocess::{
+  PreprocessOutput, Preprocessor, PreprocessorMetaData,
+};
 use naga_oil::compose::{
-  ComposableModuleDescriptor, Composer, ComposerError, NagaModuleDescriptor,
-  ShaderLanguage,
+  ComposableModuleDescriptor, Composer, ComposerError, ComposerErrorInner,
+  NagaModuleDescriptor, ShaderLanguage,
 };
 
 use crate::bevy_util::source_file::SourceFile;
 use crate::bevy_util::DependencyTree;
+use crate::generate::bind_group;
+use crate::layout_report::layout_report_json;
 use crate::{
-  create_rust_bindings, SourceFilePath, SourceWithFullDependenciesResult,
-  WgslBindgenError, WgslBindgenOption, WgslEntryResult, WgslShaderIrCapabilities,
+  create_rust_bindings, naga_util, wgsl, SourceFilePath,
+  SourceWithFullDependenciesResult, WgslBindgenError, WgslBindgenOption, WgslEntryResult,
+  WgslLayoutReport, WgslShaderIrCapabilities,
 };
 
 const PKG_VER: &str = env!("CARGO_PKG_VERSION");
 const PKG_NAME: &str = env!("CARGO_PKG_NAME");
 
+/// Whether naga rejected a `var<uniform>` for breaking the layout rules of the
+/// uniform address space.
+fn is_uniform_layout_error(err: &ComposerError) -> bool {
+  use naga::valid::{GlobalVariableError, ValidationError};
+
+  let ComposerErrorInner::ShaderValidationError(err) = &err.inner else {
+    return false;
+  };
+  matches!(
+    err.as_inner(),
+    ValidationError::GlobalVariable {
+      source: GlobalVariableError::Alignment(naga::AddressSpace::Uniform, ..)
+        | GlobalVariableError::InvalidType(naga::AddressSpace::Uniform),
+      ..
+    }
+  )
+}
+
 pub struct WGSLBindgen {
   dependency_tree: DependencyTree,
   options: WgslBindgenOption,
@@ -114,14 +138,61 @@ impl WGSLBindgen {
         .map_err(|err| map_err(&composer, err))?;
     }
 
-    let mut module = composer
-      .make_naga_module(NagaModuleDescriptor {
-        source: &source.content,
-        file_path: &source.file_path.to_string(),
-        shader_defs: shader_defs_map,
-        ..Default::default()
+    let file_path = source.file_path.to_string();
+    let descriptor = || NagaModuleDescriptor {
+      source: &source.content,
+      file_path: &file_path,
+      shader_defs: shader_defs_map.clone(),
+      ..Default::default()
+    };
+
+    // naga_oil copies the length of override-sized arrays without remapping the
+    // override handle, and drops overrides no expression reads, so there is no
+    // override left to remap it to. Such modules either fail validation or end
+    // up sized by the wrong override. Entries without imports that declare them
+    // are parsed by naga directly instead, and entries with imports are rejected.
+    let override_sized_entry = entry
+      .full_dependencies
+      .is_empty()
+      .then(|| {
+        Self::parse_entry_without_imports(ir_capabilities, &entry, &shader_defs_map)
       })
-      .map_err(|err| map_err(&composer, err))?;
+      .and_then(Result::ok)
+      .filter(|(module, _)| !wgsl::array_length_overrides(module).is_empty());
+    let override_sized_array_error = || WgslBindgenError::OverrideSizedArrayWithImports {
+      entry: file_path.clone(),
+    };
+
+    let mut module = match override_sized_entry {
+      Some(module) => Self::validate_entry(ir_capabilities, &entry, module)?,
+      None => match composer.make_naga_module(descriptor()) {
+        Ok(module) if !wgsl::array_length_overrides(&module).is_empty() => {
+          return Err(override_sized_array_error());
+        }
+        Ok(module) => module,
+        Err(err) => {
+          composer.validate = false;
+          match composer.make_naga_module(descriptor()) {
+            // naga_oil's error would be about the override it wrongly copied.
+            Ok(module) if !wgsl::array_length_overrides(&module).is_empty() => {
+              return Err(override_sized_array_error());
+            }
+            // Uniform layout violations are reported per field when the bind
+            // groups are generated, which is clearer than naga's error.
+            Ok(module)
+              if is_uniform_layout_error(&err)
+                && bind_group::check_uniform_layouts(&module).is_err() =>
+            {
+              module
+            }
+            _ => return Err(map_err(&composer, err)),
+          }
+        }
+      },
+    };
+
+    let override_doc_comments =
+      naga_util::attach_doc_comments(&mut module, &entry, &shader_defs_map);
 
     if add_override_ids {
       // When using `EmbedSource`, wgsl_bindgen uses naga to serialize the AST back into a WGSL string.
@@ -150,10 +221,88 @@ impl WGSLBindgen {
     Ok(WgslEntryResult {
       mod_name: source.file_path.module_path(workspace_root),
       naga_module: module,
+      override_doc_comments,
       source_including_deps: entry,
     })
   }
 
+  fn compose_error(
+    entry: &SourceWithFullDependenciesResult<'_>,
+    source: &str,
+    inner: ComposerErrorInner,
+  ) -> WgslBindgenError {
+    let msg = match &inner {
+      ComposerErrorInner::WgslParseError(err) => err.emit_to_string(source),
+      ComposerErrorInner::ShaderValidationError(err) => err.emit_to_string(source),
+      _ => inner.to_string(),
+    };
+    WgslBindgenError::NagaModuleComposeError {
+      entry: entry.source_file.file_path.to_string(),
+      msg,
+      inner,
+    }
+  }
+
+  /// Preprocesses and parses an entry without imports with naga alone,
+  /// returning the module unvalidated along with the parsed source.
+  fn parse_entry_without_imports(
+    ir_capabilities: Option<WgslShaderIrCapabilities>,
+    entry: &SourceWithFullDependenciesResult<'_>,
+    shader_defs: &std::collections::HashMap<String, naga_oil::compose::ShaderDefValue>,
+  ) -> Result<(naga::Module, String), WgslBindgenError> {
+    let source = entry.source_file;
+    let to_error = |source: &str, inner| Self::compose_error(entry, source, inner);
+
+    let preprocessor = Preprocessor::default();
+    let PreprocessorMetaData {
+      defines,
+      wgsl_directives,
+      cleaned_source,
+      ..
+    } = preprocessor
+      .get_preprocessor_metadata(&source.content, true)
+      .map_err(|inner| to_error(&source.content, inner))?;
+
+    let mut shader_defs = shader_defs.clone();
+    shader_defs.extend(defines);
+    let PreprocessOutput {
+      preprocessed_source,
+      ..
+    } = preprocessor
+      .preprocess(&cleaned_source, &shader_defs)
+      .map_err(|inner| to_error(&cleaned_source, inner))?;
+    let preprocessed_source =
+      format!("{}{preprocessed_source}", wgsl_directives.to_wgsl_string());
+
+    let module =
+      naga::front::wgsl::Frontend::new_with_options(naga::front::wgsl::Options {
+        capabilities: ir_capabilities.unwrap_or_default(),
+        ..naga::front::wgsl::Options::new()
+      })
+      .parse(&preprocessed_source)
+      .map_err(|err| {
+        to_error(&preprocessed_source, ComposerErrorInner::WgslParseError(err))
+      })?;
+
+    Ok((module, preprocessed_source))
+  }
+
+  fn validate_entry(
+    ir_capabilities: Option<WgslShaderIrCapabilities>,
+    entry: &SourceWithFullDependenciesResult<'_>,
+    (module, source): (naga::Module, String),
+  ) -> Result<naga::Module, WgslBindgenError> {
+    naga::valid::Validator::new(
+      naga::valid::ValidationFlags::all(),
+      ir_capabilities.unwrap_or_default(),
+    )
+    .validate(&module)
+    .map_err(|err| {
+      Self::compose_error(entry, &source, ComposerErrorInner::ShaderValidationError(err))
+    })?;
+    Ok(module)
+  }
+
   pub fn header_texts(&self) -> String {
     use std::fmt::Write;
     let mut text = String::new();
@@ -168,9 +317,9 @@ impl WGSLBindgen {
     text
   }
 
-  fn generate_output(&self) -> Result<String, WgslBindgenError> {
+  fn generate_entry_results(&self) -> Result<Vec<WgslEntryResult<'_>>, WgslBindgenError> {
     let ir_capabilities = self.options.ir_capabilities;
-    let entry_results = self
+    self
       .dependency_tree
       .get_source_files_with_full_dependencies()
       .into_iter()
@@ -186,8 +335,11 @@ impl WGSLBindgen {
             .contains(crate::WgslShaderSourceType::EmbedSource),
         )
       })
-      .collect::<Result<Vec<_>, _>>()?;
+      .collect()
+  }
 
+  fn generate_output(&self) -> Result<String, WgslBindgenError> {
+    let entry_results = self.generate_entry_results()?;
     Ok(create_rust_bindings(entry_results, &self.options)?)
   }
 
@@ -215,14 +367,27 @@ impl WGSLBindgen {
       || old_hashstr_comment != format!("// SourceHash: {}", self.content_hash);
 
     if self.options.skip_hash_check || is_hash_changed() {
-      let content = self.generate_string()?;
+      let entry_results = self.generate_entry_results()?;
+      let layout_report = self
+        .options
+        .layout_report
+        .contains(WgslLayoutReport::Json)
+        .then(|| layout_report_json(&entry_results, &self.options));
+
+      let mut content = self.header_texts();
+      content += &create_rust_bindings(entry_results, &self.options)?;
 
       // Create parent directories if they don't exist
       if let Some(parent) = out.parent() {
         std::fs::create_dir_all(parent)?;
       }
 
-      std::fs::File::create(out)?.write_all(content.as_bytes())?
+      std::fs::File::create(out)?.write_all(content.as_bytes())?;
+
+      if let Some(layout_report) = layout_report {
+        let layout_report_out = out.with_extension("layout.json");
+        std::fs::File::create(layout_report_out)?.write_all(layout_report.as_bytes())?;
+      }
     }
 
     Ok(())
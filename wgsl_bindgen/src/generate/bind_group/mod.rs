@@ -1,5 +1,7 @@
 mod raw_shader_bind_group;
 mod single_bind_group;
+mod typed_texture;
+mod uniform_layout;
 
 use std::collections::BTreeMap;
 
@@ -12,10 +14,12 @@ pub use raw_shader_bind_group::{
 use single_bind_group::SingleBindGroupBuilder;
 pub use single_bind_group::SingleBindGroupData;
 use smol_str::{SmolStr, ToSmolStr};
+pub(crate) use uniform_layout::check_uniform_layouts;
 
 use crate::quote_gen::{
-  padded_helper_item, padded_layout_impl_item, PaddedTypeInfo, RustSourceItem,
-  RustSourceItemCategory, RustSourceItemPath, MOD_REFERENCE_ROOT,
+  encase_bytes_item, padded_helper_item, padded_layout_impl_item, typed_buffer_item,
+  PaddedTypeInfo, RustSourceItem, RustSourceItemCategory, RustSourceItemPath,
+  MOD_REFERENCE_ROOT,
 };
 use crate::wgsl::buffer_binding_type;
 use crate::*;
@@ -69,6 +73,13 @@ impl<'a> ReusableShaderBindGroups<'a> {
           options,
         };
         items.push(builder.build());
+        if group_data.has_typed_buffers() {
+          items.push(typed_buffer_item(options));
+          if options.serialization_strategy.is_encase() {
+            items.push(encase_bytes_item());
+          }
+        }
+        items.extend(typed_texture_items(group_data));
         let padded_types = bind_group_padded_types(
           group_data,
           &common_bind_groups.containing_module,
@@ -77,7 +88,11 @@ impl<'a> ReusableShaderBindGroups<'a> {
         if !padded_types.is_empty() {
           items.push(padded_helper_item(&common_bind_groups.containing_module, options));
           items.extend(padded_types.iter().map(|padded_type| {
-            padded_layout_impl_item(&common_bind_groups.containing_module, padded_type)
+            padded_layout_impl_item(
+              &common_bind_groups.containing_module,
+              padded_type,
+              options,
+            )
           }));
         }
       }
@@ -98,12 +113,19 @@ impl<'a> ReusableShaderBindGroups<'a> {
           options,
         };
         items.push(builder.build());
+        if group_ref.data.has_typed_buffers() {
+          items.push(typed_buffer_item(options));
+          if options.serialization_strategy.is_encase() {
+            items.push(encase_bytes_item());
+          }
+        }
+        items.extend(typed_texture_items(&group_ref.data));
         let padded_types =
           bind_group_padded_types(&group_ref.data, &shader.containing_module, options);
         if !padded_types.is_empty() {
           items.push(padded_helper_item(&shader.containing_module, options));
           items.extend(padded_types.iter().map(|padded_type| {
-            padded_layout_impl_item(&shader.containing_module, padded_type)
+            padded_layout_impl_item(&shader.containing_module, padded_type, options)
           }));
         }
       }
@@ -123,6 +145,27 @@ impl<'a> ReusableShaderBindGroups<'a> {
   }
 }
 
+/// The `typed_texture` module and the marker types of the storage formats,
+/// when the group binds texture views as typed views.
+fn typed_texture_items(group_data: &SingleBindGroupData<'_>) -> Vec<RustSourceItem> {
+  if !group_data
+    .bindings
+    .iter()
+    .any(|binding| binding.typed_texture_view_type.is_some())
+  {
+    return Vec::new();
+  }
+
+  let mut items = vec![typed_texture::typed_texture_item()];
+  items.extend(
+    group_data
+      .typed_storage_formats()
+      .into_iter()
+      .map(typed_texture::storage_format_item),
+  );
+  items
+}
+
 fn bind_group_padded_types(
   group_data: &SingleBindGroupData<'_>,
   invoking_entry_module: &str,
@@ -196,12 +239,38 @@ fn generate_bind_groups_module_extras(
     || shader_stages.contains(wgpu::ShaderStages::FRAGMENT)
     || shader_stages.contains(wgpu::ShaderStages::VERTEX);
 
-  // The set function for each bind group already sets the index.
+  // The set function for each bind group already sets the index. Groups with
+  // dynamic offsets take theirs as a parameter.
+  let mut offsets_params = Vec::new();
   let set_groups: Vec<_> = bind_group_data
-    .keys()
-    .map(|group_no| {
+    .iter()
+    .map(|(group_no, group_ref)| {
       let group = indexed_name_ident("bind_group", *group_no);
-      quote!(#group.set(pass);)
+      if group_ref.data.dynamic_offset_bindings().is_empty() {
+        return quote!(#group.set(pass););
+      }
+
+      let offsets_name = format!(
+        "{}DynamicOffsets",
+        options
+          .wgpu_binding_generator
+          .bind_group_layout
+          .bind_group_name_ident(*group_no)
+      );
+      let offsets_type = match group_ref.kind {
+        ShaderBindGroupRefKind::Common => {
+          let path =
+            RustSourceItemPath::new(group_ref.data.first_module(), offsets_name.into());
+          quote!(#path)
+        }
+        ShaderBindGroupRefKind::Entrypoint => {
+          let name = format_ident!("{offsets_name}");
+          quote!(#name)
+        }
+      };
+      let offsets = format_ident!("{group}_offsets");
+      offsets_params.push(quote!(#offsets: #offsets_type));
+      quote!(#group.set_with_offsets(pass, #offsets);)
     })
     .collect();
 
@@ -306,7 +375,7 @@ fn generate_bind_groups_module_extras(
         }
 
         impl<'a> WgpuBindGroups<'a> {
-            pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pub fn set(&self, pass: &mut impl SetBindGroup #(, #offsets_params)*) {
                 #(self.#set_groups)*
             }
         }
@@ -440,7 +509,12 @@ mod tests {
 
             @compute
             @workgroup_size(64)
-            fn main() {}
+            fn main() {
+                _ = arrayLength(&src);
+                _ = vertex_weights;
+                _ = dst;
+                _ = transforms;
+            }
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
@@ -499,10 +573,26 @@ mod tests {
             @group(1) @binding(1) var<uniform> one: f32;
 
             @vertex
-            fn vs_main() {}
+            fn vs_main() {
+                _ = transforms;
+                _ = one;
+            }
 
             @fragment
-            fn fs_main() {}
+            fn fs_main() {
+                _ = color_texture;
+                _ = color_texture_i32;
+                _ = color_texture_u32;
+                _ = color_sampler;
+                _ = depth_texture;
+                _ = comparison_sampler;
+                _ = storage_tex_read;
+                _ = storage_tex_write;
+                _ = storage_tex_read_write;
+                _ = color_texture_msaa;
+                _ = depth_texture_msaa;
+                _ = one;
+            }
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
@@ -536,7 +626,9 @@ mod tests {
             @group(0) @binding(0) var<uniform> transforms: Transforms;
 
             @vertex
-            fn vs_main() {}
+            fn vs_main() {
+                _ = transforms;
+            }
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
@@ -569,7 +661,9 @@ mod tests {
             @group(0) @binding(0) var<uniform> transforms: Transforms;
 
             @fragment
-            fn fs_main() {}
+            fn fs_main() {
+                _ = transforms;
+            }
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
@@ -604,10 +698,14 @@ mod tests {
             @group(0) @binding(1) var acc_struct: acceleration_structure;
 
             @vertex
-            fn vs_main() {}
+            fn vs_main() {
+                _ = transforms;
+            }
 
             @fragment
-            fn fs_main() {}
+            fn fs_main() {
+                _ = acc_struct;
+            }
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
@@ -643,10 +741,15 @@ mod tests {
             @group(0) @binding(2) var sampler_array: binding_array<sampler, 3>;
 
             @vertex
-            fn vs_main() {}
+            fn vs_main() {
+                _ = transforms;
+            }
 
             @fragment
-            fn fs_main() {}
+            fn fs_main() {
+                _ = texture_array[0];
+                _ = sampler_array[0];
+            }
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
@@ -5,6 +5,7 @@ use naga::FastIndexMap;
 use smol_str::SmolStr;
 
 use super::single_bind_group::SingleBindGroupEntry;
+use super::uniform_layout::check_uniform_layouts;
 use crate::bind_group::{
   CommonShaderBindGroups, ReusableShaderBindGroups, ShaderBindGroupRef,
   ShaderBindGroupRefKind, ShaderEntryBindGroups, SingleBindGroupData,
@@ -68,10 +69,15 @@ impl<'a> RawShadersBindGroups<'a> {
         merged_bindings.push(binding.clone());
       }
       merged_bindings.sort_by_key(|a| a.binding_index);
+      // A binding shared by several entry points is visible to all their stages.
       merged_bindings.dedup_by(|a, b| {
-        a.binding_index == b.binding_index
+        let is_same = a.binding_index == b.binding_index
           && a.item_path == b.item_path
-          && a.name == b.name
+          && a.name == b.name;
+        if is_same {
+          b.shader_stages |= a.shader_stages;
+        }
+        is_same
       });
       SingleBindGroupData {
         bindings: merged_bindings,
@@ -93,36 +99,30 @@ impl<'a> RawShadersBindGroups<'a> {
 
         match common_bind_groups.entry(group_no) {
           Entry::Vacant(vacant_entry) => {
-            vacant_entry.insert((shader.shader_stages, group.clone()));
+            vacant_entry.insert(group.clone());
           }
           Entry::Occupied(mut occupied_entry) => {
-            let merged_group = merge_bind_groups(&occupied_entry.get().1, group);
-            let merged_stages = occupied_entry.get().0 | shader.shader_stages;
-            occupied_entry.insert((merged_stages, merged_group));
+            let merged_group = merge_bind_groups(occupied_entry.get(), group);
+            occupied_entry.insert(merged_group);
           }
         };
       }
     }
 
     // Remove all the bind groups that are not reusable.
-    common_bind_groups.retain(|&_group_no, (_, group)| group.are_all_same_module());
+    common_bind_groups.retain(|&_group_no, group| group.are_all_same_module());
 
     // Update common bind groups with combined shader stages for correct visibility
     let mut updated_common_bind_groups = BTreeMap::new();
-    for (&group_no, (merged_stages, group)) in &common_bind_groups {
+    for (&group_no, group) in &common_bind_groups {
       let common_module = group.first_module();
-      let updated_group = group.with_updated_shader_stages(
-        &common_module,
-        self.options,
-        *merged_stages,
-        group_no,
-      );
-      updated_common_bind_groups.insert(group_no, (merged_stages, updated_group));
+      let updated_group = group.with_updated_shader_stages(&common_module, self.options);
+      updated_common_bind_groups.insert(group_no, updated_group);
     }
 
     // Create the reusable shader bind groups
     let mut reusable_shader_bind_groups = ReusableShaderBindGroups::new();
-    for (&group_no, (_, group)) in &updated_common_bind_groups {
+    for (&group_no, group) in &updated_common_bind_groups {
       let common_module = group.first_module();
 
       reusable_shader_bind_groups
@@ -151,9 +151,7 @@ impl<'a> RawShadersBindGroups<'a> {
         });
 
       for (group_no, group) in &shader.bind_group_data {
-        let common_bindgroup = updated_common_bind_groups
-          .get(group_no)
-          .map(|(_, group)| group);
+        let common_bindgroup = updated_common_bind_groups.get(group_no);
         let is_common = Some(group.first_module())
           == common_bindgroup.map(|group| group.first_module());
         let reusable_bindgroup = is_common.then_some(common_bindgroup).flatten();
@@ -221,6 +219,8 @@ pub fn get_bind_group_data_for_entry<'a>(
 fn get_bind_group_data_for_entry_with_context<'a>(
   context: &BindGroupGenerationContext<'a>,
 ) -> Result<RawShaderEntryBindGroups<'a>, CreateModuleError> {
+  check_uniform_layouts(context.module)?;
+
   // Use a BTree to sort type and field names by group index.
   // This isn't strictly necessary but makes the generated code cleaner.
   let mut bind_group_data = BTreeMap::new();
@@ -241,7 +241,7 @@ fn get_bind_group_data_for_entry_with_context<'a>(
         context.module_name,
         context.options,
         context.module,
-        context.shader_stages,
+        crate::wgsl::global_shader_stages(context.module, global_handle.0),
         binding.binding,
         binding_type,
         global.space,
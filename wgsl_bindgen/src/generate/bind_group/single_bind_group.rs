@@ -2,6 +2,7 @@ use derive_more::Constructor;
 use smol_str::ToSmolStr;
 
 use self::quote_gen::RustSourceItemPath;
+use super::typed_texture::typed_texture_view_type;
 use super::*;
 use crate::SamplerType;
 
@@ -11,6 +12,9 @@ struct BindGroupEntriesStructBuilder<'a> {
   group_no: u32,
   data: &'a SingleBindGroupData<'a>,
   generator: &'a BindGroupLayoutGenerator,
+  /// Whether buffers and texture views are taken as their typed wrappers, when
+  /// the options generate them.
+  typed_resources: bool,
 }
 
 impl<'a> BindGroupEntriesStructBuilder<'a> {
@@ -50,6 +54,28 @@ impl<'a> BindGroupEntriesStructBuilder<'a> {
       unknown => panic!("Unsupported type for binding fields: {unknown:#?}"),
     }
   }
+  /// The type held by a buffer binding taken as a `typed_buffer::TypedBuffer`.
+  fn typed_buffer_type<'b>(
+    &self,
+    binding: &'b SingleBindGroupEntry,
+  ) -> Option<&'b TokenStream> {
+    binding
+      .typed_buffer_type
+      .as_ref()
+      .filter(|_| self.typed_resources)
+  }
+
+  /// The typed view taken by a texture binding.
+  fn typed_texture_view_type<'b>(
+    &self,
+    binding: &'b SingleBindGroupEntry,
+  ) -> Option<&'b TokenStream> {
+    binding
+      .typed_texture_view_type
+      .as_ref()
+      .filter(|_| self.typed_resources)
+  }
+
   /// Generates a binding entry from a parameter variable and a group binding.
   fn create_entry_from_parameter(
     &self,
@@ -63,7 +89,13 @@ impl<'a> BindGroupEntriesStructBuilder<'a> {
       self.containing_module,
     );
     let binding_name = Ident::new(&demangled_name.name, Span::call_site());
-    let binding_var = quote!(#binding_var_name.#binding_name);
+    let binding_var = if self.typed_buffer_type(binding).is_some() {
+      quote!(#binding_var_name.#binding_name.into_binding())
+    } else if self.typed_texture_view_type(binding).is_some() {
+      quote!(#binding_var_name.#binding_name.inner())
+    } else {
+      quote!(#binding_var_name.#binding_name)
+    };
 
     let resource_type = Self::get_resource_type_from_binding(binding);
     entry_cons(binding_index, binding_var, resource_type)
@@ -103,7 +135,13 @@ impl<'a> BindGroupEntriesStructBuilder<'a> {
 
     let resource_type = Self::get_resource_type_from_binding(binding);
 
-    let param_field_type = self.generator.binding_type_map[&resource_type].clone();
+    let param_field_type = if let Some(buffer_type) = self.typed_buffer_type(binding) {
+      quote!(typed_buffer::TypedBuffer<'a, #buffer_type>)
+    } else if let Some(view_type) = self.typed_texture_view_type(binding) {
+      quote!(&'a #view_type)
+    } else {
+      self.generator.binding_type_map[&resource_type].clone()
+    };
     let field_type = self.generator.entry_struct_type.clone();
 
     let param_field = quote!(pub #field_name: #param_field_type);
@@ -228,6 +266,50 @@ impl<'a> BindGroupStructBuilder<'a> {
       .bind_group_name_ident(self.group_no)
   }
 
+  fn dynamic_offsets_struct_name(&self) -> syn::Ident {
+    format_ident!("{}DynamicOffsets", self.struct_name())
+  }
+
+  /// The struct holding one offset per binding with a dynamic offset, ordered
+  /// by binding index.
+  fn dynamic_offsets_struct(&self) -> TokenStream {
+    let bindings = self.data.dynamic_offset_bindings();
+    if bindings.is_empty() {
+      return quote!();
+    }
+
+    let offsets_name = self.dynamic_offsets_struct_name();
+    let fields: Vec<_> = bindings
+      .iter()
+      .map(|binding| format_ident!("{}", binding.item_path.name.as_str()))
+      .collect();
+    let field_docs = bindings.iter().map(|binding| {
+      format!(" Offset in bytes of @binding({}) into its buffer.", binding.binding_index)
+    });
+    let count = Index::from(fields.len());
+    let doc = format!(
+      " The dynamic offsets of [`{}`], passed to `set_bind_group` in binding order.",
+      self.struct_name()
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct #offsets_name {
+            #(
+                #[doc = #field_docs]
+                pub #fields: u32
+            ),*
+        }
+
+        impl #offsets_name {
+            pub fn into_array(self) -> [wgpu::DynamicOffset; #count] {
+                [#(self.#fields),*]
+            }
+        }
+    }
+  }
+
   fn bind_group_struct_impl(&self) -> TokenStream {
     // TODO: Support compute shader with vertex/fragment in the same module?
     let bind_group_name = self.struct_name();
@@ -243,6 +325,21 @@ impl<'a> BindGroupStructBuilder<'a> {
     let bind_group_label =
       format!("{}::BindGroup{}", self.sanitized_entry_name, self.group_no);
 
+    let set_fn = if self.data.dynamic_offset_bindings().is_empty() {
+      quote! {
+          pub fn set(&self, pass: &mut impl SetBindGroup) {
+              pass.set_bind_group(#group_no, &self.0, &[]);
+          }
+      }
+    } else {
+      let offsets_name = self.dynamic_offsets_struct_name();
+      quote! {
+          pub fn set_with_offsets(&self, pass: &mut impl SetBindGroup, offsets: #offsets_name) {
+              pass.set_bind_group(#group_no, &self.0, &offsets.into_array());
+          }
+      }
+    };
+
     quote! {
         impl #bind_group_name {
             pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = #bind_group_layout_descriptor;
@@ -262,9 +359,7 @@ impl<'a> BindGroupStructBuilder<'a> {
                 Self(bind_group)
             }
 
-            pub fn set(&self, pass: &mut impl SetBindGroup) {
-                pass.set_bind_group(#group_no, &self.0, &[]);
-            }
+            #set_fn
 
             pub fn inner(&self) -> &wgpu::BindGroup {
                 &self.0
@@ -286,10 +381,12 @@ impl<'a> BindGroupStructBuilder<'a> {
     };
 
     let group_impl = self.bind_group_struct_impl();
+    let dynamic_offsets_struct = self.dynamic_offsets_struct();
 
     quote! {
         #group_struct
         #group_impl
+        #dynamic_offsets_struct
     }
   }
 }
@@ -311,6 +408,7 @@ impl<'a> SingleBindGroupBuilder<'a> {
       self.group_no,
       self.group_data,
       &wgpu_generator.bind_group_layout,
+      true,
     )
     .build();
 
@@ -321,6 +419,7 @@ impl<'a> SingleBindGroupBuilder<'a> {
           self.group_no,
           self.group_data,
           &additional_generator.bind_group_layout,
+          false,
         )
         .build()
       } else {
@@ -353,7 +452,7 @@ impl<'a> SingleBindGroupBuilder<'a> {
 }
 
 /// Helper function to check if a binding should have non-filterable texture
-fn check_texture_filterability(
+pub(super) fn check_texture_filterability(
   binding_name: &Option<String>,
   invoking_entry_module: &str,
   options: &WgslBindgenOption,
@@ -375,6 +474,40 @@ fn check_texture_filterability(
   true
 }
 
+/// The type a buffer binding holds, with runtime-sized arrays as slices of
+/// their elements.
+fn typed_buffer_type(
+  invoking_entry_module: &str,
+  naga_module: &naga::Module,
+  binding_type: &naga::Type,
+  options: &WgslBindgenOption,
+) -> TokenStream {
+  let buffer_type =
+    rust_type(Some(invoking_entry_module), naga_module, binding_type, options);
+  match binding_type.inner {
+    naga::TypeInner::Array {
+      base,
+      size: naga::ArraySize::Dynamic,
+      ..
+    } => {
+      // encase holds runtime-sized arrays in a `Vec`, the others as their
+      // element type.
+      let element_type = if options.serialization_strategy.is_encase() {
+        rust_type(
+          Some(invoking_entry_module),
+          naga_module,
+          &naga_module.types[base],
+          options,
+        )
+      } else {
+        buffer_type
+      };
+      quote!([#element_type])
+    }
+    _ => quote!(#buffer_type),
+  }
+}
+
 /// Helper function to check for sampler type overrides
 fn check_sampler_type_override(
   binding_name: &Option<String>,
@@ -405,6 +538,7 @@ fn generate_binding_type_for_type(
   options: &WgslBindgenOption,
   address_space: naga::AddressSpace,
   binding_name: &Option<String>,
+  has_dynamic_offset: bool,
 ) -> TokenStream {
   // TODO: Support more types.
   match &binding_type.inner {
@@ -422,7 +556,7 @@ fn generate_binding_type_for_type(
 
       quote!(wgpu::BindingType::Buffer {
           ty: #buffer_binding_type,
-          has_dynamic_offset: false,
+          has_dynamic_offset: #has_dynamic_offset,
           min_binding_size: #min_binding_size,
       })
     }
@@ -525,6 +659,7 @@ fn generate_binding_type_for_type(
         options,
         address_space,
         binding_name,
+        has_dynamic_offset,
       )
     }
     // TODO: Better error handling.
@@ -541,10 +676,8 @@ fn bind_group_layout_entry(
   binding_type: &naga::Type,
   name: Option<String>,
   address_space: naga::AddressSpace,
+  has_dynamic_offset: bool,
 ) -> TokenStream {
-  // TODO: Assume storage is only used for compute?
-  // TODO: Support just vertex or fragment?
-  // TODO: Visible from all stages?
   let stages = quote_shader_stages(shader_stages);
 
   let wgpu_binding_type = generate_binding_type_for_type(
@@ -554,6 +687,7 @@ fn bind_group_layout_entry(
     options,
     address_space,
     &name,
+    has_dynamic_offset,
   );
 
   let doc = format!(
@@ -609,6 +743,42 @@ impl<'a> SingleBindGroupData<'a> {
     self.bindings.first().unwrap().item_path.module.clone()
   }
 
+  /// The bindings with a dynamic offset, in the order `set_bind_group` expects
+  /// their offsets.
+  pub fn dynamic_offset_bindings(&self) -> Vec<&SingleBindGroupEntry<'a>> {
+    let mut bindings: Vec<_> = self
+      .bindings
+      .iter()
+      .filter(|binding| binding.has_dynamic_offset)
+      .collect();
+    bindings.sort_by_key(|binding| binding.binding_index);
+    bindings
+  }
+
+  /// Whether any binding is a buffer taken as a `typed_buffer::TypedBuffer`.
+  pub fn has_typed_buffers(&self) -> bool {
+    self
+      .bindings
+      .iter()
+      .any(|binding| binding.typed_buffer_type.is_some())
+  }
+
+  /// The storage texture formats of the bindings taken as typed views.
+  pub fn typed_storage_formats(&self) -> Vec<naga::StorageFormat> {
+    self
+      .bindings
+      .iter()
+      .filter(|binding| binding.typed_texture_view_type.is_some())
+      .filter_map(|binding| match binding.binding_type.inner {
+        naga::TypeInner::Image {
+          class: naga::ImageClass::Storage { format, .. },
+          ..
+        } => Some(format),
+        _ => None,
+      })
+      .collect()
+  }
+
   pub fn are_all_same_module(&self) -> bool {
     let first_module = self.first_module();
     self
@@ -617,13 +787,12 @@ impl<'a> SingleBindGroupData<'a> {
       .all(|b| b.item_path.module == first_module)
   }
 
-  /// Update all binding entries with new shader stages
+  /// Regenerates the layout entries of all bindings from their shader stages,
+  /// after the stages of shared bindings were merged across entry points.
   pub fn with_updated_shader_stages(
     &self,
     invoking_entry_module: &str,
     options: &WgslBindgenOption,
-    shader_stages: wgpu::ShaderStages,
-    group_index: u32,
   ) -> Self {
     let updated_bindings = self
       .bindings
@@ -634,7 +803,7 @@ impl<'a> SingleBindGroupData<'a> {
         binding.with_updated_shader_stages(
           invoking_entry_module,
           options,
-          shader_stages,
+          binding.shader_stages,
           binding.address_space,
         )
       })
@@ -660,6 +829,14 @@ pub struct SingleBindGroupEntry<'a> {
   pub naga_module: &'a naga::Module,
   pub layout_entry_token_stream: TokenStream,
   pub address_space: naga::AddressSpace,
+  /// The stages of the entry points that use the binding.
+  pub shader_stages: wgpu::ShaderStages,
+  /// Whether the binding is a buffer matched by `override_dynamic_offset`.
+  pub has_dynamic_offset: bool,
+  /// The type held by the buffer, when buffers are bound with `typed_buffer_bindings`.
+  pub typed_buffer_type: Option<TokenStream>,
+  /// The typed view of the texture, when textures are bound with `typed_texture_views`.
+  pub typed_texture_view_type: Option<TokenStream>,
 }
 
 impl<'a> SingleBindGroupEntry<'a> {
@@ -676,6 +853,25 @@ impl<'a> SingleBindGroupEntry<'a> {
     let item_path =
       RustSourceItemPath::from_mangled(name.as_ref().unwrap(), invoking_entry_module);
 
+    // Binding arrays of buffers cannot have dynamic offsets.
+    let has_dynamic_offset = crate::wgsl::buffer_usages(address_space).is_some()
+      && !matches!(binding_type.inner, naga::TypeInner::BindingArray { .. })
+      && crate::wgsl::has_dynamic_offset(options, &item_path.get_fully_qualified_name());
+
+    let typed_buffer_type = (options.typed_buffer_bindings
+      && crate::wgsl::buffer_usages(address_space).is_some()
+      && !matches!(binding_type.inner, naga::TypeInner::BindingArray { .. }))
+    .then(|| {
+      typed_buffer_type(invoking_entry_module, naga_module, binding_type, options)
+    });
+
+    let typed_texture_view_type = options
+      .typed_texture_views
+      .then(|| {
+        typed_texture_view_type(binding_type, &name, invoking_entry_module, options)
+      })
+      .flatten();
+
     let layout_entry_token_stream = bind_group_layout_entry(
       invoking_entry_module,
       naga_module,
@@ -685,6 +881,7 @@ impl<'a> SingleBindGroupEntry<'a> {
       binding_type,
       name.clone(),
       address_space,
+      has_dynamic_offset,
     );
 
     Self {
@@ -695,6 +892,10 @@ impl<'a> SingleBindGroupEntry<'a> {
       naga_module,
       layout_entry_token_stream,
       address_space,
+      shader_stages,
+      has_dynamic_offset,
+      typed_buffer_type,
+      typed_texture_view_type,
     }
   }
 
@@ -715,6 +916,7 @@ impl<'a> SingleBindGroupEntry<'a> {
       self.binding_type,
       self.name.clone(),
       address_space,
+      self.has_dynamic_offset,
     );
 
     Self {
@@ -725,6 +927,10 @@ impl<'a> SingleBindGroupEntry<'a> {
       naga_module: self.naga_module,
       layout_entry_token_stream,
       address_space: self.address_space,
+      shader_stages,
+      has_dynamic_offset: self.has_dynamic_offset,
+      typed_buffer_type: self.typed_buffer_type.clone(),
+      typed_texture_view_type: self.typed_texture_view_type.clone(),
     }
   }
 }
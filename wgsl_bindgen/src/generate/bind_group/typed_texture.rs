@@ -0,0 +1,484 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::single_bind_group::check_texture_filterability;
+use crate::quote_gen::{
+  RustSourceItem, RustSourceItemCategory, RustSourceItemPath, MOD_TYPED_TEXTURE,
+};
+use crate::WgslBindgenOption;
+
+/// The view dimensions, as their marker type, named after their
+/// `wgpu::TextureViewDimension` variant, and the alias of their `TextureView`.
+const VIEW_DIMENSIONS: [(&str, &str); 6] = [
+  ("D1", "TextureView1d"),
+  ("D2", "TextureView2d"),
+  ("D2Array", "TextureView2dArray"),
+  ("Cube", "TextureViewCube"),
+  ("CubeArray", "TextureViewCubeArray"),
+  ("D3", "TextureView3d"),
+];
+
+/// The type of the typed texture view bound to a texture binding, eg:
+/// `typed_texture::TextureView2d<typed_texture::Float>`.
+pub(super) fn typed_texture_view_type(
+  binding_type: &naga::Type,
+  binding_name: &Option<String>,
+  invoking_entry_module: &str,
+  options: &WgslBindgenOption,
+) -> Option<TokenStream> {
+  let naga::TypeInner::Image {
+    dim,
+    class,
+    arrayed,
+  } = binding_type.inner
+  else {
+    return None;
+  };
+
+  let dimension = match (dim, arrayed) {
+    (naga::ImageDimension::D1, _) => "D1",
+    (naga::ImageDimension::D2, false) => "D2",
+    (naga::ImageDimension::D2, true) => "D2Array",
+    (naga::ImageDimension::Cube, false) => "Cube",
+    (naga::ImageDimension::Cube, true) => "CubeArray",
+    (naga::ImageDimension::D3, _) => "D3",
+  };
+
+  match class {
+    naga::ImageClass::Sampled { kind, multi } => {
+      let sample_type = match kind {
+        naga::ScalarKind::Sint => quote!(typed_texture::Sint),
+        naga::ScalarKind::Uint => quote!(typed_texture::Uint),
+        naga::ScalarKind::Float
+          if !multi
+            && check_texture_filterability(
+              binding_name,
+              invoking_entry_module,
+              options,
+            ) =>
+        {
+          quote!(typed_texture::Float)
+        }
+        naga::ScalarKind::Float => quote!(typed_texture::UnfilterableFloat),
+        _ => return None,
+      };
+      Some(sampled_view_type(dimension, sample_type, multi))
+    }
+    naga::ImageClass::Depth { multi } => {
+      Some(sampled_view_type(dimension, quote!(typed_texture::Depth), multi))
+    }
+    naga::ImageClass::Storage { format, access } => {
+      let format = format_ident!("{format:?}");
+      let is_read = access.contains(naga::StorageAccess::LOAD);
+      let is_write = access.contains(naga::StorageAccess::STORE);
+      let access = match (is_read, is_write) {
+        (true, true) => quote!(typed_texture::ReadWrite),
+        (true, false) => quote!(typed_texture::ReadOnly),
+        _ => quote!(typed_texture::WriteOnly),
+      };
+      // 2D storage textures are the common case, so it is the default dimension.
+      let dimension = (dimension != "D2").then(|| {
+        let dimension = format_ident!("{dimension}");
+        quote!(, typed_texture::#dimension)
+      });
+      Some(quote!(
+        typed_texture::StorageTextureView<typed_texture::#format, #access #dimension>
+      ))
+    }
+    naga::ImageClass::External => None,
+  }
+}
+
+fn sampled_view_type(
+  dimension: &str,
+  sample_type: TokenStream,
+  multi: bool,
+) -> TokenStream {
+  let (_, alias) = VIEW_DIMENSIONS
+    .iter()
+    .find(|(marker, _)| *marker == dimension)
+    .unwrap();
+  let alias = format_ident!("{alias}");
+  if multi {
+    quote!(typed_texture::#alias<typed_texture::Multisampled<#sample_type>>)
+  } else {
+    quote!(typed_texture::#alias<#sample_type>)
+  }
+}
+
+/// The marker type of a storage texture format, eg: `typed_texture::Rgba8Unorm`.
+/// Only formats bound by the shaders get one.
+pub(super) fn storage_format_item(format: naga::StorageFormat) -> RustSourceItem {
+  let format = format_ident!("{format:?}");
+  let doc = format!(" The `{format}` format of a [`StorageTextureView`].");
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs | RustSourceItemCategory::TraitImpls,
+    RustSourceItemPath::new(MOD_TYPED_TEXTURE.into(), format.to_string().into()),
+    quote! {
+      #[doc = #doc]
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct #format;
+
+      impl StorageFormat for #format {
+        const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::#format;
+      }
+    },
+  )
+}
+
+/// The `typed_texture` module's texture views that carry the dimension and
+/// sample type or format they are bound with, so bind group entries only take
+/// views matching the shader.
+pub(super) fn typed_texture_item() -> RustSourceItem {
+  let (dimensions, aliases): (Vec<_>, Vec<_>) = VIEW_DIMENSIONS
+    .iter()
+    .map(|(marker, alias)| (format_ident!("{marker}"), format_ident!("{alias}")))
+    .unzip();
+  let dimension_docs = dimensions
+    .iter()
+    .map(|dimension| format!(" The `{dimension}` view dimension."));
+  let alias_docs = dimensions.iter().map(|dimension| {
+    format!(" A [`TextureView`] with the `{dimension}` view dimension.")
+  });
+
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs
+      | RustSourceItemCategory::TypeImpls
+      | RustSourceItemCategory::TraitImpls,
+    RustSourceItemPath::new(MOD_TYPED_TEXTURE.into(), "TextureView".into()),
+    quote! {
+      /// The view dimension a texture is bound with.
+      pub trait ViewDimension {
+        const DIMENSION: wgpu::TextureViewDimension;
+      }
+
+      /// How the shader samples a texture.
+      pub trait SampleType {
+        const MULTISAMPLED: bool;
+        /// Whether a view sampled as `sample_type` can be bound.
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool;
+      }
+
+      /// The format a storage texture is bound with.
+      pub trait StorageFormat {
+        const FORMAT: wgpu::TextureFormat;
+      }
+
+      /// The access the shader has to a storage texture.
+      pub trait StorageAccess {
+        const ACCESS: wgpu::StorageTextureAccess;
+      }
+
+      #(
+        #[doc = #dimension_docs]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct #dimensions;
+
+        impl ViewDimension for #dimensions {
+          const DIMENSION: wgpu::TextureViewDimension = wgpu::TextureViewDimension::#dimensions;
+        }
+      )*
+
+      /// Sampled as filterable floats.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct Float;
+
+      /// Sampled as floats without filtering, which also takes depth formats.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct UnfilterableFloat;
+
+      /// Sampled as depth.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct Depth;
+
+      /// Sampled as signed integers.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct Sint;
+
+      /// Sampled as unsigned integers.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct Uint;
+
+      /// A multisampled texture sampled as `S`.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct Multisampled<S>(::core::marker::PhantomData<S>);
+
+      impl SampleType for Float {
+        const MULTISAMPLED: bool = false;
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool {
+          sample_type == wgpu::TextureSampleType::Float { filterable: true }
+        }
+      }
+
+      impl SampleType for UnfilterableFloat {
+        const MULTISAMPLED: bool = false;
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool {
+          matches!(
+            sample_type,
+            wgpu::TextureSampleType::Float { .. } | wgpu::TextureSampleType::Depth
+          )
+        }
+      }
+
+      impl SampleType for Depth {
+        const MULTISAMPLED: bool = false;
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool {
+          sample_type == wgpu::TextureSampleType::Depth
+        }
+      }
+
+      impl SampleType for Sint {
+        const MULTISAMPLED: bool = false;
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool {
+          sample_type == wgpu::TextureSampleType::Sint
+        }
+      }
+
+      impl SampleType for Uint {
+        const MULTISAMPLED: bool = false;
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool {
+          sample_type == wgpu::TextureSampleType::Uint
+        }
+      }
+
+      impl<S: SampleType> SampleType for Multisampled<S> {
+        const MULTISAMPLED: bool = true;
+        fn accepts(sample_type: wgpu::TextureSampleType) -> bool {
+          S::accepts(sample_type)
+        }
+      }
+
+      /// Read only storage access.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct ReadOnly;
+
+      /// Write only storage access.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct WriteOnly;
+
+      /// Read and write storage access.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct ReadWrite;
+
+      impl StorageAccess for ReadOnly {
+        const ACCESS: wgpu::StorageTextureAccess = wgpu::StorageTextureAccess::ReadOnly;
+      }
+
+      impl StorageAccess for WriteOnly {
+        const ACCESS: wgpu::StorageTextureAccess = wgpu::StorageTextureAccess::WriteOnly;
+      }
+
+      impl StorageAccess for ReadWrite {
+        const ACCESS: wgpu::StorageTextureAccess = wgpu::StorageTextureAccess::ReadWrite;
+      }
+
+      /// Why a view of a texture cannot be bound where the shader expects it.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+      pub enum TextureViewError {
+        /// The texture was not created with the usage the binding needs.
+        MissingUsage { usage: wgpu::TextureUsages },
+        /// The view has another dimension than the binding.
+        Dimension {
+          expected: wgpu::TextureViewDimension,
+          actual: wgpu::TextureViewDimension,
+        },
+        /// The view format is not sampled as the binding expects. `None` for a
+        /// depth-stencil format viewed without selecting an aspect.
+        SampleType { actual: Option<wgpu::TextureSampleType> },
+        /// The texture is multisampled and the binding is not, or the other way around.
+        Multisampled { expected: bool },
+        /// The view has another format than the storage binding.
+        Format {
+          expected: wgpu::TextureFormat,
+          actual: wgpu::TextureFormat,
+        },
+        /// The view starts past the last array layer of the texture.
+        BaseArrayLayer { base: u32, layers: u32 },
+      }
+
+      impl ::core::fmt::Display for TextureViewError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            Self::MissingUsage { usage } => {
+              write!(f, "the texture is missing the {usage:?} usage")
+            }
+            Self::Dimension { expected, actual } => {
+              write!(f, "expected a {expected:?} view, got {actual:?}")
+            }
+            Self::SampleType { actual } => {
+              write!(f, "the view is sampled as {actual:?}, which the binding does not take")
+            }
+            Self::Multisampled { expected: true } => {
+              write!(f, "expected a multisampled texture")
+            }
+            Self::Multisampled { expected: false } => {
+              write!(f, "expected a texture that is not multisampled")
+            }
+            Self::Format { expected, actual } => {
+              write!(f, "expected a {expected:?} view, got {actual:?}")
+            }
+            Self::BaseArrayLayer { base, layers } => {
+              write!(f, "the view starts at array layer {base} of a texture with {layers}")
+            }
+          }
+        }
+      }
+
+      impl std::error::Error for TextureViewError {}
+
+      /// Checks the usage and dimension shared by sampled and storage views.
+      fn check_view<D: ViewDimension>(
+        texture: &wgpu::Texture,
+        desc: &wgpu::TextureViewDescriptor<'_>,
+        usage: wgpu::TextureUsages,
+      ) -> Result<(), TextureViewError> {
+        if !texture.usage().contains(usage) {
+          return Err(TextureViewError::MissingUsage { usage });
+        }
+
+        // The dimension wgpu picks for a view without one.
+        let dimension = match (desc.dimension, texture.dimension()) {
+          (Some(dimension), _) => dimension,
+          (None, wgpu::TextureDimension::D1) => wgpu::TextureViewDimension::D1,
+          (None, wgpu::TextureDimension::D2) => {
+            let layers = match desc.array_layer_count {
+              Some(count) => count,
+              None => texture
+                .depth_or_array_layers()
+                .checked_sub(desc.base_array_layer)
+                .ok_or(TextureViewError::BaseArrayLayer {
+                  base: desc.base_array_layer,
+                  layers: texture.depth_or_array_layers(),
+                })?,
+            };
+            if layers == 1 {
+              wgpu::TextureViewDimension::D2
+            } else {
+              wgpu::TextureViewDimension::D2Array
+            }
+          }
+          (None, wgpu::TextureDimension::D3) => wgpu::TextureViewDimension::D3,
+        };
+        if dimension != D::DIMENSION {
+          return Err(TextureViewError::Dimension {
+            expected: D::DIMENSION,
+            actual: dimension,
+          });
+        }
+        Ok(())
+      }
+
+      /// A view of a texture with the `D` dimension, sampled as `S`.
+      pub struct TextureView<D, S> {
+        view: wgpu::TextureView,
+        _kind: ::core::marker::PhantomData<fn() -> (D, S)>,
+      }
+
+      #(
+        #[doc = #alias_docs]
+        pub type #aliases<S> = TextureView<#dimensions, S>;
+      )*
+
+      impl<D: ViewDimension, S: SampleType> TextureView<D, S> {
+        /// Creates a view of `texture` with `desc`, checking that it can be bound
+        /// with the `D` dimension and sampled as `S`. Float32 formats count as
+        /// unfilterable, as filtering them depends on a device feature.
+        pub fn new(
+          texture: &wgpu::Texture,
+          desc: &wgpu::TextureViewDescriptor<'_>,
+        ) -> Result<Self, TextureViewError> {
+          check_view::<D>(texture, desc, wgpu::TextureUsages::TEXTURE_BINDING)?;
+
+          let format = desc.format.unwrap_or(texture.format());
+          let sample_type = format.sample_type(Some(desc.aspect), None);
+          if !sample_type.is_some_and(S::accepts) {
+            return Err(TextureViewError::SampleType { actual: sample_type });
+          }
+          if (texture.sample_count() > 1) != S::MULTISAMPLED {
+            return Err(TextureViewError::Multisampled {
+              expected: S::MULTISAMPLED,
+            });
+          }
+          Ok(Self::from_raw_unchecked(texture.create_view(desc)))
+        }
+      }
+
+      impl<D, S> TextureView<D, S> {
+        /// Wraps a view the caller knows has the `D` dimension and is sampled
+        /// as `S`. Nothing checks that it does.
+        pub fn from_raw_unchecked(view: wgpu::TextureView) -> Self {
+          Self {
+            view,
+            _kind: ::core::marker::PhantomData,
+          }
+        }
+
+        pub fn inner(&self) -> &wgpu::TextureView {
+          &self.view
+        }
+
+        pub fn into_inner(self) -> wgpu::TextureView {
+          self.view
+        }
+      }
+
+      impl<D, S> ::core::fmt::Debug for TextureView<D, S> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_tuple("TextureView").field(&self.view).finish()
+        }
+      }
+
+      /// A view of a storage texture with the `F` format and `D` dimension,
+      /// accessed as `A`.
+      pub struct StorageTextureView<F, A, D = D2> {
+        view: wgpu::TextureView,
+        _kind: ::core::marker::PhantomData<fn() -> (F, A, D)>,
+      }
+
+      impl<F: StorageFormat, A: StorageAccess, D: ViewDimension> StorageTextureView<F, A, D> {
+        /// Creates a view of `texture` with `desc`, checking that it can be bound
+        /// with the `F` format and `D` dimension.
+        pub fn new(
+          texture: &wgpu::Texture,
+          desc: &wgpu::TextureViewDescriptor<'_>,
+        ) -> Result<Self, TextureViewError> {
+          check_view::<D>(texture, desc, wgpu::TextureUsages::STORAGE_BINDING)?;
+
+          let format = desc.format.unwrap_or(texture.format());
+          if format != F::FORMAT {
+            return Err(TextureViewError::Format {
+              expected: F::FORMAT,
+              actual: format,
+            });
+          }
+          Ok(Self::from_raw_unchecked(texture.create_view(desc)))
+        }
+      }
+
+      impl<F, A, D> StorageTextureView<F, A, D> {
+        /// Wraps a view the caller knows has the `F` format and `D` dimension.
+        /// Nothing checks that it does.
+        pub fn from_raw_unchecked(view: wgpu::TextureView) -> Self {
+          Self {
+            view,
+            _kind: ::core::marker::PhantomData,
+          }
+        }
+
+        pub fn inner(&self) -> &wgpu::TextureView {
+          &self.view
+        }
+
+        pub fn into_inner(self) -> wgpu::TextureView {
+          self.view
+        }
+      }
+
+      impl<F, A, D> ::core::fmt::Debug for StorageTextureView<F, A, D> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_tuple("StorageTextureView").field(&self.view).finish()
+        }
+      }
+    },
+  )
+}
@@ -0,0 +1,279 @@
+//! Checks `var<uniform>` bindings against the stricter layout rules of the
+//! uniform address space, so violations can be reported per field instead of
+//! as naga's validation error on a type handle.
+
+use naga::common::wgsl::TypeContext;
+use naga::proc::{Alignment, Layouter};
+
+use crate::bevy_util::demangle_str;
+use crate::CreateModuleError;
+
+/// A value in the uniform buffer being checked, named by its access path from
+/// the binding, e.g. `frame.lights[_].color`.
+struct UniformValue<'a> {
+  path: String,
+  ty: naga::Handle<naga::Type>,
+  module: &'a naga::Module,
+  layouter: &'a Layouter,
+  binding: &'a str,
+}
+
+impl UniformValue<'_> {
+  fn type_name(&self, ty: naga::Handle<naga::Type>) -> String {
+    demangle_str(&self.module.to_ctx().type_to_string(ty)).to_string()
+  }
+
+  fn child(&self, path: String, ty: naga::Handle<naga::Type>) -> Self {
+    Self { path, ty, ..*self }
+  }
+
+  fn error(&self, reason: String, help: String) -> CreateModuleError {
+    CreateModuleError::UniformLayout {
+      binding: self.binding.to_string(),
+      field: self.path.clone(),
+      reason,
+      help,
+    }
+  }
+
+  /// Returns the alignment this value needs in the uniform address space, or
+  /// the first rule it breaks. Mirrors naga's uniform layout validation.
+  fn uniform_alignment(&self) -> Result<Alignment, CreateModuleError> {
+    let general_alignment = self.layouter[self.ty].alignment;
+    match &self.module.types[self.ty].inner {
+      naga::TypeInner::Array { base, size, stride } => {
+        if let naga::ArraySize::Dynamic = size {
+          return Err(self.error(
+            "runtime-sized arrays are not allowed in uniform buffers".to_string(),
+            "Bind the buffer as `var<storage, read>` instead, or give the array a fixed length."
+              .to_string(),
+          ));
+        }
+        let element = self.child(format!("{}[_]", self.path), *base);
+        let alignment = element
+          .uniform_alignment()?
+          .max(general_alignment)
+          .max(Alignment::MIN_UNIFORM);
+        if !alignment.is_aligned(*stride) {
+          let element_ty = self.type_name(*base);
+          return Err(self.error(
+            format!(
+              "its array stride {stride} is not a multiple of {}",
+              alignment * 1u32
+            ),
+            format!(
+              "Array elements in uniform buffers must be {0}-byte aligned. Use a {0}-byte \
+               element type instead of `{element_ty}` (e.g. `vec4<f32>`, or a struct with \
+               `@size({0})`), and mirror it on the Rust side with a padded type, or bind the \
+               buffer as `var<storage, read>`.",
+              alignment * 1u32
+            ),
+          ));
+        }
+        Ok(alignment)
+      }
+      naga::TypeInner::Struct { members, .. } => {
+        let mut alignment = Alignment::MIN_UNIFORM;
+        let mut prev_struct: Option<(&naga::StructMember, u32)> = None;
+        for member in members {
+          let name = member.name.as_deref().unwrap_or_default();
+          let member_value = self.child(format!("{}.{name}", self.path), member.ty);
+          let member_alignment = member_value.uniform_alignment()?;
+          if !member_alignment.is_aligned(member.offset) {
+            let required = member_alignment * 1u32;
+            return Err(member_value.error(
+              format!(
+                "it is at offset {}, which is not a multiple of {required}",
+                member.offset
+              ),
+              format!(
+                "Structs and arrays in uniform buffers must start at a multiple of 16 bytes. \
+                 Add `@align({required})` to `{name}` in WGSL, and align the Rust type to \
+                 match with `override_struct_alignment` or a padded type.",
+              ),
+            ));
+          }
+
+          // A member following a struct must start at least `roundUp(16, SizeOf(S))`
+          // bytes after it.
+          if let Some((prev, span)) = prev_struct {
+            let min = Alignment::MIN_UNIFORM.round_up(span);
+            if member.offset - prev.offset < min {
+              let prev_name = prev.name.as_deref().unwrap_or_default();
+              return Err(member_value.error(
+                format!(
+                  "it is at offset {}, but must start at least {min} bytes after the \
+                   struct member `{prev_name}` at offset {}",
+                  member.offset, prev.offset
+                ),
+                format!(
+                  "Add `@align(16)` to `{name}` or `@size({min})` to `{prev_name}` in WGSL, \
+                   and mirror the padding on the Rust side with `override_struct_alignment` \
+                   or a padded type.",
+                ),
+              ));
+            }
+          }
+          prev_struct = match &self.module.types[member.ty].inner {
+            naga::TypeInner::Struct { span, .. } => Some((member, *span)),
+            _ => None,
+          };
+
+          alignment = alignment.max(member_alignment);
+        }
+        Ok(alignment)
+      }
+      _ => Ok(general_alignment),
+    }
+  }
+}
+
+/// Checks a global variable bound as `var<uniform>` against the uniform
+/// address space layout rules. Other globals are always accepted.
+fn check_uniform_layout(
+  module: &naga::Module,
+  layouter: &Layouter,
+  global: &naga::GlobalVariable,
+) -> Result<(), CreateModuleError> {
+  if global.space != naga::AddressSpace::Uniform {
+    return Ok(());
+  }
+
+  let binding = global.name.as_deref().unwrap_or_default();
+  let value = UniformValue {
+    path: binding.to_string(),
+    ty: global.ty,
+    module,
+    layouter,
+    binding,
+  };
+  value.uniform_alignment().map(|_| ())
+}
+
+/// Checks every `var<uniform>` binding of the module against the uniform
+/// address space layout rules.
+pub(crate) fn check_uniform_layouts(
+  module: &naga::Module,
+) -> Result<(), CreateModuleError> {
+  let mut layouter = Layouter::default();
+  layouter.update(module.to_ctx()).unwrap();
+  module
+    .global_variables
+    .iter()
+    .try_for_each(|(_, global)| check_uniform_layout(module, &layouter, global))
+}
+
+#[cfg(test)]
+mod tests {
+  use indoc::indoc;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn uniform_layout_error(source: &str) -> Option<(String, String)> {
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    match check_uniform_layouts(&module) {
+      Ok(()) => None,
+      Err(CreateModuleError::UniformLayout { field, reason, .. }) => {
+        Some((field, reason))
+      }
+      Err(err) => panic!("unexpected error: {err}"),
+    }
+  }
+
+  #[test]
+  fn uniform_layout_accepts_valid_layouts() {
+    let source = indoc! {r#"
+      struct Light {
+        color: vec3<f32>,
+        intensity: f32,
+      };
+
+      struct Frame {
+        time: f32,
+        @align(16) light: Light,
+        lights: array<Light, 4>,
+        weights: array<vec4<f32>, 2>,
+      };
+
+      @group(0) @binding(0) var<uniform> frame: Frame;
+      // Storage buffers have no uniform restrictions.
+      @group(0) @binding(1) var<storage> values: array<f32>;
+    "#};
+
+    assert_eq!(uniform_layout_error(source), None);
+  }
+
+  #[test]
+  fn uniform_layout_rejects_small_array_stride() {
+    let source = indoc! {r#"
+      struct Frame {
+        time: f32,
+        @align(16) weights: array<f32, 4>,
+      };
+
+      @group(0) @binding(0) var<uniform> frame: Frame;
+    "#};
+
+    assert_eq!(
+      uniform_layout_error(source),
+      Some((
+        "frame.weights".to_string(),
+        "its array stride 4 is not a multiple of 16".to_string()
+      ))
+    );
+  }
+
+  #[test]
+  fn uniform_layout_rejects_misaligned_struct_member() {
+    let source = indoc! {r#"
+      struct Inner {
+        value: f32,
+      };
+
+      struct Outer {
+        time: f32,
+        inner: Inner,
+      };
+
+      struct Frame {
+        outer: Outer,
+      };
+
+      @group(0) @binding(0) var<uniform> frame: Frame;
+    "#};
+
+    assert_eq!(
+      uniform_layout_error(source),
+      Some((
+        "frame.outer.inner".to_string(),
+        "it is at offset 4, which is not a multiple of 16".to_string()
+      ))
+    );
+  }
+
+  #[test]
+  fn uniform_layout_rejects_member_too_close_after_struct() {
+    let source = indoc! {r#"
+      struct Inner {
+        value: f32,
+      };
+
+      struct Frame {
+        inner: Inner,
+        time: f32,
+      };
+
+      @group(0) @binding(0) var<uniform> frame: Frame;
+    "#};
+
+    assert_eq!(
+      uniform_layout_error(source),
+      Some((
+        "frame.time".to_string(),
+        "it is at offset 4, but must start at least 16 bytes after the struct member `inner` at offset 0"
+          .to_string()
+      ))
+    );
+  }
+}
@@ -1,11 +1,16 @@
+use naga::common::wgsl::TypeContext;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::Ident;
 
+use crate::naga_util::{constant_doc_comments, quote_doc_comments, OverrideDocComments};
 use crate::quote_gen::{
-  rust_type, RustSourceItem, RustSourceItemCategory, RustSourceItemPath,
+  array_length_param, rust_type, RustSourceItem, RustSourceItemCategory,
+  RustSourceItemPath,
+};
+use crate::{
+  sanitized_upper_snake_case, wgsl, CreateModuleError, FastIndexMap, WgslBindgenOption,
 };
-use crate::WgslBindgenOption;
 
 pub fn consts_items(
   invoking_entry_module: &str,
@@ -15,7 +20,7 @@ pub fn consts_items(
   module
     .constants
     .iter()
-    .filter_map(|(_, t)| -> Option<RustSourceItem> {
+    .filter_map(|(handle, t)| -> Option<RustSourceItem> {
       let name_str = t.name.as_ref()?;
 
       // we don't need full qualification here
@@ -45,10 +50,15 @@ pub fn consts_items(
         _ => None,
       }?;
 
+      let docs = constant_doc_comments(module, handle);
+
       Some(RustSourceItem::new(
         RustSourceItemCategory::ConstVarDecls.into(),
         rust_item_path,
-        quote! { pub const #name: #type_and_value;},
+        quote! {
+          #docs
+          pub const #name: #type_and_value;
+        },
       ))
     })
     .collect()
@@ -56,100 +66,269 @@ pub fn consts_items(
 
 pub fn pipeline_overridable_constants(
   module: &naga::Module,
+  doc_comments: &OverrideDocComments,
   options: &WgslBindgenOption,
-) -> TokenStream {
-  let overrides: Vec<_> = module.overrides.iter().map(|(_, o)| o).collect();
+) -> Result<TokenStream, CreateModuleError> {
+  if module.overrides.is_empty() {
+    return Ok(quote!());
+  }
 
-  let fields: Vec<_> = overrides
-    .iter()
-    .map(|o| {
-      let name = Ident::new(o.name.as_ref().unwrap(), Span::call_site());
-      // TODO: Do we only need to handle scalar types here?
-      let ty = rust_type(None, module, &module.types[o.ty], options);
-
-      if o.init.is_some() {
-        quote!(pub #name: Option<#ty>)
-      } else {
-        quote!(pub #name: #ty)
+  let defaults = override_defaults(module);
+
+  let mut fields = Vec::new();
+  let mut default_consts = Vec::new();
+  let mut required_entries = Vec::new();
+  let mut optional_entries = Vec::new();
+  for (handle, o) in module.overrides.iter() {
+    let wgsl_name = o.name.as_ref().unwrap();
+    let name = Ident::new(wgsl_name, Span::call_site());
+    let ty = &module.types[o.ty];
+
+    // Pipeline constants are passed to wgpu as `f64`, which only round-trips
+    // the scalar types WGSL allows for overrides.
+    let to_f64 = match ty.inner {
+      naga::TypeInner::Scalar(naga::Scalar::BOOL) => {
+        |value: TokenStream| quote!(if #value { 1.0 } else { 0.0 })
       }
-    })
-    .collect();
-
-  let required_entries: Vec<_> = overrides
-      .iter()
-      .filter_map(|o| {
-          if o.init.is_some() {
-              None
-          } else {
-              let key = override_key(o);
-
-              let name = Ident::new(o.name.as_ref().unwrap(), Span::call_site());
-
-              // TODO: Do we only need to handle scalar types here?
-              let ty = &module.types[o.ty];
-              let value = if matches!(ty.inner, naga::TypeInner::Scalar(s) if s.kind == naga::ScalarKind::Bool) {
-                  quote!(if self.#name { 1.0 } else { 0.0})
-              } else {
-                  quote!(self.#name as f64)
-              };
-
-              Some(quote!((#key, #value)))
-          }
-      })
-      .collect();
-
-  // Add code for optionally inserting the constants with defaults.
-  // Omitted constants will be initialized using the values defined in WGSL.
-  let insert_optional_entries: Vec<_> = overrides
-      .iter()
-      .filter_map(|o| {
-          if o.init.is_some() {
-              let key = override_key(o);
-
-              // TODO: Do we only need to handle scalar types here?
-              let ty = &module.types[o.ty];
-              let value = if matches!(ty.inner, naga::TypeInner::Scalar(s) if s.kind == naga::ScalarKind::Bool) {
-                  quote!(if value { 1.0 } else { 0.0})
-              } else {
-                  quote!(value as f64)
-              };
-
-              let name = Ident::new(o.name.as_ref().unwrap(), Span::call_site());
-
-              Some(quote! {
-                  if let Some(value) = self.#name {
-                      entries.push((#key, #value));
-                  }
-              })
-          } else {
-              None
-          }
-      })
-      .collect();
+      naga::TypeInner::Scalar(naga::Scalar::F16) => {
+        |value: TokenStream| quote!(#value.to_f64())
+      }
+      naga::TypeInner::Scalar(
+        naga::Scalar::I32 | naga::Scalar::U32 | naga::Scalar::F32 | naga::Scalar::F64,
+      ) => |value: TokenStream| quote!(#value as f64),
+      _ => {
+        return Err(CreateModuleError::UnsupportedOverrideType {
+          name: wgsl_name.clone(),
+          ty: module.to_ctx().type_to_string(o.ty),
+        })
+      }
+    };
+    let rust_type = rust_type(None, module, ty, options);
+    let docs = quote_doc_comments(doc_comments.get(&handle));
+    let key = override_key(o);
+
+    if o.init.is_some() {
+      fields.push(quote!(#docs pub #name: Option<#rust_type>));
+
+      let value = to_f64(quote!(value));
+      optional_entries.push(quote! {
+        if let Some(value) = self.#name {
+          entries.push((#key, #value));
+        }
+      });
+    } else {
+      fields.push(quote!(#docs pub #name: #rust_type));
 
-  let init_entries = if insert_optional_entries.is_empty() {
+      let value = to_f64(quote!(self.#name));
+      required_entries.push(quote!((#key, #value)));
+    }
+
+    if let Some(default_value) = defaults.get(&handle) {
+      let const_name = default_const_name(module, handle);
+      let doc = format!("The value of `{wgsl_name}` when it is left unset.");
+      default_consts.push(quote! {
+        #[doc = #doc]
+        pub const #const_name: #rust_type = #default_value;
+      });
+    }
+  }
+
+  let init_entries = if optional_entries.is_empty() {
     quote!(let entries = vec![#(#required_entries),*];)
   } else {
     quote!(let mut entries = vec![#(#required_entries),*];)
   };
 
-  if !fields.is_empty() {
-    // Create a Rust struct that can initialize the constants dictionary.
-    quote! {
-        pub struct OverrideConstants {
-            #(#fields),*
-        }
+  // Overrides without an initializer have no sensible default.
+  let derive_default = if required_entries.is_empty() {
+    quote!(Default,)
+  } else {
+    quote!()
+  };
 
-        impl OverrideConstants {
-            pub fn constants(&self) -> Vec<(&'static str, f64)> {
-                #init_entries
-                #(#insert_optional_entries);*
-                entries
-            }
+  let assert_array_lengths = assert_array_lengths_fn(module, &defaults);
+
+  // Create a Rust struct that can initialize the constants dictionary.
+  Ok(quote! {
+      #[derive(Debug, Clone, Copy, PartialEq, #derive_default)]
+      pub struct OverrideConstants {
+          #(#fields),*
+      }
+
+      impl OverrideConstants {
+          #(#default_consts)*
+
+          pub fn constants(&self) -> Vec<(&'static str, f64)> {
+              #init_entries
+              #(#optional_entries)*
+              entries
+          }
+
+          #assert_array_lengths
+      }
+  })
+}
+
+fn default_const_name(
+  module: &naga::Module,
+  handle: naga::Handle<naga::Override>,
+) -> Ident {
+  let name = module.overrides[handle].name.as_deref().unwrap_or_default();
+  Ident::new(&format!("DEFAULT_{}", sanitized_upper_snake_case(name)), Span::call_site())
+}
+
+/// The Rust const expressions for the WGSL initializers of overrides.
+///
+/// Initializers that depend on other overrides are expressed in terms of the
+/// defaults of those overrides, and are left out when any of them has no
+/// default or uses an operation that has no Rust const equivalent.
+fn override_defaults(
+  module: &naga::Module,
+) -> FastIndexMap<naga::Handle<naga::Override>, TokenStream> {
+  let mut defaults = FastIndexMap::default();
+  // naga orders overrides after the overrides their initializers depend on.
+  for (handle, o) in module.overrides.iter() {
+    let default_value = o
+      .init
+      .and_then(|init| override_const_expr(module, &defaults, init));
+    if let Some(default_value) = default_value {
+      defaults.insert(handle, default_value);
+    }
+  }
+  defaults
+}
+
+fn override_const_expr(
+  module: &naga::Module,
+  defaults: &FastIndexMap<naga::Handle<naga::Override>, TokenStream>,
+  expr: naga::Handle<naga::Expression>,
+) -> Option<TokenStream> {
+  use naga::{BinaryOperator as Bo, Expression, UnaryOperator as Uo};
+
+  let sub_expr = |expr| override_const_expr(module, defaults, expr);
+  let tokens = match &module.global_expressions[expr] {
+    Expression::Literal(literal) => literal_value(literal)?,
+    Expression::Constant(constant) => sub_expr(module.constants[*constant].init)?,
+    Expression::Override(o) => {
+      defaults.get(o)?;
+      let const_name = default_const_name(module, *o);
+      quote!(Self::#const_name)
+    }
+    Expression::Unary { op, expr } => {
+      let expr = sub_expr(*expr)?;
+      match op {
+        Uo::Negate => quote!((-#expr)),
+        Uo::LogicalNot | Uo::BitwiseNot => quote!((!#expr)),
+      }
+    }
+    Expression::Binary { op, left, right } => {
+      let left = sub_expr(*left)?;
+      let right = sub_expr(*right)?;
+      let op = match op {
+        Bo::Add => quote!(+),
+        Bo::Subtract => quote!(-),
+        Bo::Multiply => quote!(*),
+        Bo::Divide => quote!(/),
+        Bo::Modulo => quote!(%),
+        Bo::Equal => quote!(==),
+        Bo::NotEqual => quote!(!=),
+        Bo::Less => quote!(<),
+        Bo::LessEqual => quote!(<=),
+        Bo::Greater => quote!(>),
+        Bo::GreaterEqual => quote!(>=),
+        Bo::And => quote!(&),
+        Bo::ExclusiveOr => quote!(^),
+        Bo::InclusiveOr => quote!(|),
+        Bo::LogicalAnd => quote!(&&),
+        Bo::LogicalOr => quote!(||),
+        Bo::ShiftLeft => quote!(<<),
+        Bo::ShiftRight => quote!(>>),
+      };
+      quote!((#left #op #right))
+    }
+    Expression::As {
+      expr,
+      kind,
+      convert: Some(width),
+    } => {
+      let expr = sub_expr(*expr)?;
+      let ty = match (kind, width) {
+        (naga::ScalarKind::Sint, 4) => quote!(i32),
+        (naga::ScalarKind::Uint, 4) => quote!(u32),
+        (naga::ScalarKind::Float, 4) => quote!(f32),
+        (naga::ScalarKind::Float, 8) => quote!(f64),
+        _ => return None,
+      };
+      quote!((#expr as #ty))
+    }
+    _ => return None,
+  };
+  Some(tokens)
+}
+
+fn literal_value(literal: &naga::Literal) -> Option<TokenStream> {
+  let value = match *literal {
+    naga::Literal::F64(v) => quote!(#v),
+    naga::Literal::F32(v) => quote!(#v),
+    naga::Literal::U32(v) => quote!(#v),
+    naga::Literal::I32(v) => quote!(#v),
+    naga::Literal::Bool(v) => quote!(#v),
+    naga::Literal::F16(v) => {
+      let v = v.to_f32();
+      quote!(half::f16::from_f32_const(#v))
+    }
+    _ => return None,
+  };
+  Some(value)
+}
+
+/// Checks the overrides that size override-sized arrays against the const
+/// generic lengths used for those arrays on the host.
+fn assert_array_lengths_fn(
+  module: &naga::Module,
+  defaults: &FastIndexMap<naga::Handle<naga::Override>, TokenStream>,
+) -> TokenStream {
+  let length_overrides = wgsl::array_length_overrides(module);
+  if length_overrides.is_empty() {
+    return quote!();
+  }
+
+  let params = length_overrides
+    .iter()
+    .map(|handle| array_length_param(module, *handle))
+    .collect::<Vec<_>>();
+  let checks = length_overrides.iter().zip(&params).map(|(handle, param)| {
+    let o = &module.overrides[*handle];
+    let wgsl_name = o.name.as_ref().unwrap();
+    let name = Ident::new(wgsl_name, Span::call_site());
+    let message = format!("override `{wgsl_name}` is {{}}, but the array length is {{}}");
+    let default_const = default_const_name(module, *handle);
+
+    let check = quote!(assert!(value as usize == #param, #message, value, #param););
+    match (o.init, defaults.contains_key(handle)) {
+      (None, _) => quote! {
+        let value = self.#name;
+        #check
+      },
+      (Some(_), true) => quote! {
+        let value = self.#name.unwrap_or(Self::#default_const);
+        #check
+      },
+      // The default can't be evaluated on the host; it can only be checked once set.
+      (Some(_), false) => quote! {
+        if let Some(value) = self.#name {
+          #check
         }
+      },
+    }
+  });
+
+  quote! {
+    /// Panics unless the overrides sizing override-sized arrays resolve to the
+    /// lengths given as const generic parameters.
+    pub fn assert_array_lengths<#(const #params: usize),*>(&self) {
+      #(#checks)*
     }
-  } else {
-    quote!()
   }
 }
 
@@ -166,7 +345,9 @@ mod tests {
   use proc_macro2::TokenStream;
 
   use super::*;
-  use crate::assert_tokens_snapshot;
+  use crate::bevy_util::source_file::SourceFile;
+  use crate::naga_util::attach_doc_comments;
+  use crate::{assert_tokens_snapshot, SourceFilePath, SourceWithFullDependenciesResult};
 
   fn consts(module: &naga::Module) -> Vec<TokenStream> {
     consts_items("", module)
@@ -213,8 +394,8 @@ mod tests {
           override b3: bool;
           override f1: f32 = 0.5;
           override f2: f32;
-          // override f3: f64 = 0.6;
-          // override f4: f64;
+          override f3: f64 = 0.6lf;
+          override f4: f64;
           override i1: i32 = 0;
           override i2: i32;
           override i3: i32 = i1 * i2;
@@ -226,7 +407,89 @@ mod tests {
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
 
-    let actual = pipeline_overridable_constants(&module, &WgslBindgenOption::default());
+    let actual = pipeline_overridable_constants(
+      &module,
+      &Default::default(),
+      &WgslBindgenOption::default(),
+    )
+    .unwrap();
+    assert_tokens_snapshot!(actual);
+  }
+
+  #[test]
+  fn write_pipeline_overrideable_constants_dependent_defaults() {
+    let source = indoc! {r#"
+          const SCALE: f32 = 2.0;
+          override width: u32 = 64u;
+          override height: u32 = width / 2u;
+          override area: u32 = width * height;
+          override required: u32;
+          override depends_on_required: u32 = required + 1u;
+          override ratio: f32 = f32(width) * SCALE;
+          override enabled: bool = !(width > 128u);
+          @compute @workgroup_size(1)
+          fn main() {
+              let x = f32(area + depends_on_required) * ratio;
+              if enabled {
+                  let y = x + f32(height);
+              }
+          }
+      "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    let actual = pipeline_overridable_constants(
+      &module,
+      &Default::default(),
+      &WgslBindgenOption::default(),
+    )
+    .unwrap();
+    assert_tokens_snapshot!(actual);
+  }
+
+  #[test]
+  fn unsupported_override_type_is_an_error() {
+    let source = indoc! {r#"
+          enable f16;
+          override supported: f16 = 1.0h;
+          override unsupported: i64 = 1li;
+          @compute @workgroup_size(1)
+          fn main() {}
+      "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    let actual = pipeline_overridable_constants(
+      &module,
+      &Default::default(),
+      &WgslBindgenOption::default(),
+    );
+    assert!(matches!(
+      actual,
+      Err(CreateModuleError::UnsupportedOverrideType { ref name, ref ty })
+        if name == "unsupported" && ty == "i64"
+    ));
+  }
+
+  #[test]
+  fn write_pipeline_overrideable_constants_array_lengths() {
+    let source = indoc! {r#"
+          override block_size: u32 = 64u;
+          override tile_count: u32;
+          override scale: f32 = 1.0;
+          var<workgroup> scratch: array<f32, block_size>;
+          var<workgroup> tiles: array<u32, tile_count>;
+          @compute @workgroup_size(1)
+          fn main() {
+              scratch[0] = f32(tiles[0]) * scale;
+          }
+      "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    let actual = pipeline_overridable_constants(
+      &module,
+      &Default::default(),
+      &WgslBindgenOption::default(),
+    )
+    .unwrap();
     assert_tokens_snapshot!(actual);
   }
 
@@ -238,7 +501,53 @@ mod tests {
       "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = pipeline_overridable_constants(&module, &WgslBindgenOption::default());
+    let actual = pipeline_overridable_constants(
+      &module,
+      &Default::default(),
+      &WgslBindgenOption::default(),
+    )
+    .unwrap();
+    assert_tokens_snapshot!(actual);
+  }
+
+  #[test]
+  fn write_documented_constants() {
+    let source = indoc! {r#"
+          /// Number of lights in the scene.
+          ///
+          /// Must match the size of the lights buffer.
+          const LIGHT_COUNT: u32 = 4u;
+          const UNDOCUMENTED: f32 = 1.0;
+          /// Strength of the bloom pass.
+          @id(0) override bloom_intensity: f32 = 0.5;
+          /// Enables shadows.
+          override shadows: bool;
+          @fragment
+          fn main() -> @location(0) vec4<f32> {
+              return vec4<f32>(f32(LIGHT_COUNT), UNDOCUMENTED, bloom_intensity, f32(shadows));
+          }
+      "#};
+
+    let mut module = naga::front::wgsl::parse_str(source).unwrap();
+    let source_file =
+      SourceFile::create(SourceFilePath::new("test.wgsl"), None, source.into());
+    let override_doc_comments = attach_doc_comments(
+      &mut module,
+      &SourceWithFullDependenciesResult {
+        source_file: &source_file,
+        full_dependencies: Default::default(),
+      },
+      &Default::default(),
+    );
+
+    let consts = consts(&module);
+    let overrides = pipeline_overridable_constants(
+      &module,
+      &override_doc_comments,
+      &WgslBindgenOption::default(),
+    )
+    .unwrap();
+    let actual = quote!(#(#consts)* #overrides);
     assert_tokens_snapshot!(actual);
   }
 }
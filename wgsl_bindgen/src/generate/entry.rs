@@ -1,11 +1,15 @@
 use case::CaseExt;
+use heck::ToPascalCase;
 use naga::ShaderStage;
 use proc_macro2::{Literal, Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Ident, Index};
 
-use crate::quote_gen::{RustSourceItem, RustSourceItemCategory};
-use crate::wgsl;
+use crate::naga_util::entry_point_doc_comments;
+use crate::quote_gen::{
+  RustSourceItem, RustSourceItemCategory, RustSourceItemPath, MOD_REFERENCE_ROOT,
+};
+use crate::{wgsl, CreateModuleError, WgslBindgenOption, WgslTypeSerializeStrategy};
 
 fn fragment_target_count(module: &naga::Module, f: &naga::Function) -> usize {
   match &f.result {
@@ -37,13 +41,16 @@ pub fn entry_point_constants(module: &naga::Module) -> TokenStream {
   let entry_points: Vec<TokenStream> = module
     .entry_points
     .iter()
-    .map(|entry_point| {
+    .enumerate()
+    .map(|(index, entry_point)| {
       let entry_name = Literal::string(&entry_point.name);
       let const_name = Ident::new(
         &format!("ENTRY_{}", entry_point.name.to_uppercase()),
         Span::call_site(),
       );
+      let doc_comment = entry_point_doc_comments(module, index);
       quote! {
+          #doc_comment
           pub const #const_name: &str = #entry_name;
       }
     })
@@ -54,11 +61,16 @@ pub fn entry_point_constants(module: &naga::Module) -> TokenStream {
   }
 }
 
-pub fn vertex_states(invoking_entry_module: &str, module: &naga::Module) -> TokenStream {
+pub fn vertex_states(
+  invoking_entry_module: &str,
+  module: &naga::Module,
+  options: &WgslBindgenOption,
+) -> TokenStream {
   let vertex_entries: Vec<TokenStream> = module
     .entry_points
     .iter()
-    .filter_map(|entry_point| match &entry_point.stage {
+    .enumerate()
+    .filter_map(|(index, entry_point)| match &entry_point.stage {
       ShaderStage::Vertex => {
         // Get vertex input structs specific to this entry point
         let vertex_input_structs = wgsl::get_vertex_input_structs_for_entry_point(
@@ -71,20 +83,38 @@ pub fn vertex_states(invoking_entry_module: &str, module: &naga::Module) -> Toke
           wgsl::get_vertex_input_primitives_for_entry_point(module, entry_point);
 
         let mut step_mode_params = vec![];
+        // Each buffer slot is named after its step mode parameter.
+        let mut buffer_names = vec![];
         let mut layout_expressions: Vec<TokenStream> = vertex_input_structs
           .iter()
           .map(|input| {
             let struct_ref = input.item_path.short_token_stream(invoking_entry_module);
             let step_mode =
               Ident::new(&input.item_path.name.to_snake(), Span::call_site());
-            step_mode_params.push(quote!(#step_mode: wgpu::VertexStepMode));
-            quote!(#struct_ref::vertex_buffer_layout(#step_mode))
+            buffer_names.push(step_mode.clone());
+            let fully_qualified_name = input.item_path.get_fully_qualified_name();
+            if wgsl::vertex_step_mode_override(options, &fully_qualified_name).is_some() {
+              quote!(#struct_ref::VERTEX_BUFFER_LAYOUT)
+            } else {
+              step_mode_params.push(quote!(#step_mode: wgpu::VertexStepMode));
+              quote!(#struct_ref::vertex_buffer_layout(#step_mode))
+            }
           })
           .collect();
 
         for prim in &vertex_input_primitives {
-          let step_mode = Ident::new(&prim.name.to_snake(), Span::call_site());
-          step_mode_params.push(quote!(#step_mode: wgpu::VertexStepMode));
+          let step_mode_name = Ident::new(&prim.name.to_snake(), Span::call_site());
+          buffer_names.push(step_mode_name.clone());
+          let step_mode = match wgsl::vertex_step_mode_override(options, &prim.name) {
+            Some(step_mode) => {
+              let step_mode = format_ident!("{step_mode:?}");
+              quote!(wgpu::VertexStepMode::#step_mode)
+            }
+            None => {
+              step_mode_params.push(quote!(#step_mode_name: wgpu::VertexStepMode));
+              quote!(#step_mode_name)
+            }
+          };
 
           let format = wgsl::vertex_format(prim.ty);
           let format_ident = Ident::new(&format!("{format:?}"), Span::call_site());
@@ -133,7 +163,36 @@ pub fn vertex_states(invoking_entry_module: &str, module: &naga::Module) -> Toke
           quote!(#(#step_mode_params),*, #overrides)
         };
 
+        let doc_comment = entry_point_doc_comments(module, index);
+
+        let vertex_buffers = (!buffer_names.is_empty()).then(|| {
+          let struct_name = format_ident!(
+            "{}VertexBuffers",
+            entry_point.name.to_pascal_case()
+          );
+          let doc = format!(
+            "The vertex buffers of `{}`, one per buffer of [`{fn_name}`] in the same order.",
+            entry_point.name
+          );
+          let slots = (0..buffer_names.len()).map(Literal::usize_unsuffixed);
+          quote! {
+              #[doc = #doc]
+              #[derive(Debug, Clone, Copy)]
+              pub struct #struct_name<'a> {
+                  #(pub #buffer_names: wgpu::BufferSlice<'a>),*
+              }
+
+              impl #struct_name<'_> {
+                  /// Sets every vertex buffer at its slot.
+                  pub fn set(&self, pass: &mut impl SetVertexBuffer) {
+                      #(pass.set_vertex_buffer(#slots, self.#buffer_names);)*
+                  }
+              }
+          }
+        });
+
         Some(quote! {
+            #doc_comment
             pub fn #fn_name(#params) -> VertexEntry<#n> {
                 VertexEntry {
                     entry_point: #const_name,
@@ -143,6 +202,8 @@ pub fn vertex_states(invoking_entry_module: &str, module: &naga::Module) -> Toke
                     constants: #constants
                 }
             }
+
+            #vertex_buffers
         })
       }
       _ => None,
@@ -181,49 +242,99 @@ pub fn vertex_states(invoking_entry_module: &str, module: &naga::Module) -> Toke
   }
 }
 
+/// The `SetVertexBuffer` trait used by the generated vertex buffer structs, if
+/// the module has a vertex entry point.
+pub fn set_vertex_buffer_items(module: &naga::Module) -> Vec<RustSourceItem> {
+  if !module
+    .entry_points
+    .iter()
+    .any(|entry_point| entry_point.stage == ShaderStage::Vertex)
+  {
+    return Vec::new();
+  }
+
+  let set_vertex_buffer_trait = RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs.into(),
+    RustSourceItemPath::new(MOD_REFERENCE_ROOT.into(), "SetVertexBuffer".into()),
+    quote! {
+      pub trait SetVertexBuffer {
+        fn set_vertex_buffer(&mut self, slot: u32, buffer_slice: wgpu::BufferSlice<'_>);
+      }
+    },
+  );
+  let set_vertex_buffer_impls = ["RenderPass", "RenderBundleEncoder"].map(|encoder| {
+    let encoder = format_ident!("{encoder}");
+    RustSourceItem::new(
+      RustSourceItemCategory::TraitImpls.into(),
+      RustSourceItemPath::new(
+        MOD_REFERENCE_ROOT.into(),
+        format!("impl SetVertexBuffer for wgpu::{encoder}<'_>").into(),
+      ),
+      quote! {
+        impl SetVertexBuffer for wgpu::#encoder<'_> {
+          fn set_vertex_buffer(&mut self, slot: u32, buffer_slice: wgpu::BufferSlice<'_>) {
+              self.set_vertex_buffer(slot, buffer_slice);
+          }
+        }
+      },
+    )
+  });
+
+  let mut items = vec![set_vertex_buffer_trait];
+  items.extend(set_vertex_buffer_impls);
+  items
+}
+
 pub fn vertex_struct_impls(
   invoking_entry_module: &str,
   module: &naga::Module,
-) -> Vec<RustSourceItem> {
-  vertex_input_structs_impls(invoking_entry_module, module)
+  options: &WgslBindgenOption,
+) -> Result<Vec<RustSourceItem>, CreateModuleError> {
+  vertex_input_structs_impls(invoking_entry_module, module, options)
 }
 
 fn vertex_input_structs_impls(
   invoking_entry_module: &str,
   module: &naga::Module,
-) -> Vec<RustSourceItem> {
-  let vertex_inputs = wgsl::get_all_vertex_input_structs(invoking_entry_module, module);
+  options: &WgslBindgenOption,
+) -> Result<Vec<RustSourceItem>, CreateModuleError> {
+  let vertex_inputs = wgsl::get_vertex_input_structs(invoking_entry_module, module);
   vertex_inputs
     .iter()
-    .map(|input| generate_vertex_input_impl(input))
+    .map(|input| generate_vertex_input_impl(input, options))
     .collect()
 }
 
 /// Generate vertex input implementation for a single VertexInput struct
-pub fn generate_vertex_input_impl(input: &wgsl::VertexInput) -> RustSourceItem {
+pub fn generate_vertex_input_impl(
+  input: &wgsl::VertexInput,
+  options: &WgslBindgenOption,
+) -> Result<RustSourceItem, CreateModuleError> {
   let name = Ident::new(&input.item_path.name, Span::call_site());
+  let struct_name = input.item_path.get_fully_qualified_name();
 
   // Use index to avoid adding prefix to literals.
   let count = Index::from(input.fields.len());
-  let attributes: Vec<_> = input
+  let attributes = input
     .fields
     .iter()
     .map(|(location, m)| {
       let field_name: TokenStream = m.name.as_ref().unwrap().parse().unwrap();
       let location = Index::from(*location as usize);
-      let format = wgsl::vertex_format(&input.naga_module.types[m.ty]);
+      let format =
+        wgsl::vertex_attribute_format(options, &struct_name, m, input.naga_module)?;
       // TODO: Will the debug implementation always work with the macro?
       let format = Ident::new(&format!("{format:?}"), Span::call_site());
 
-      quote! {
+      Ok(quote! {
           wgpu::VertexAttribute {
               format: wgpu::VertexFormat::#format,
               offset: std::mem::offset_of!(Self, #field_name) as u64,
               shader_location: #location,
           }
-      }
+      })
     })
-    .collect();
+    .collect::<Result<Vec<_>, CreateModuleError>>()?;
 
   // The vertex_attr_array! macro doesn't account for field alignment.
   // Structs with glam::Vec4 and glam::Vec3 fields will not be tightly packed.
@@ -233,6 +344,40 @@ pub fn generate_vertex_input_impl(input: &wgsl::VertexInput) -> RustSourceItem {
   // TODO: Should this enforce WebGPU alignment requirements for compatibility?
   // https://gpuweb.github.io/gpuweb/#abstract-opdef-validating-gpuvertexbufferlayout
 
+  // The attribute offsets follow the Rust layout, which encase does not write.
+  let contents = match options.serialization_strategy {
+    WgslTypeSerializeStrategy::Bytemuck => Some(quote!(bytemuck::cast_slice(vertices))),
+    WgslTypeSerializeStrategy::Zerocopy => {
+      Some(quote!(zerocopy::IntoBytes::as_bytes(vertices)))
+    }
+    WgslTypeSerializeStrategy::Encase => None,
+  };
+  let create_vertex_buffer = contents.map(|contents| {
+    let label = input.item_path.get_fully_qualified_name().to_string();
+    quote! {
+        /// Creates a vertex buffer holding `vertices` with [`Self::BUFFER_USAGES`].
+        pub fn create_vertex_buffer(device: &wgpu::Device, vertices: &[Self]) -> wgpu::Buffer {
+            wgpu::util::DeviceExt::create_buffer_init(
+                device,
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some(#label),
+                    contents: #contents,
+                    usage: Self::BUFFER_USAGES,
+                },
+            )
+        }
+    }
+  });
+
+  let vertex_buffer_layout =
+    wgsl::vertex_step_mode_override(options, &struct_name).map(|step_mode| {
+      let step_mode = format_ident!("{step_mode:?}");
+      quote! {
+          pub const VERTEX_BUFFER_LAYOUT: wgpu::VertexBufferLayout<'static> =
+              Self::vertex_buffer_layout(wgpu::VertexStepMode::#step_mode);
+      }
+    });
+
   // TODO: Support vertex inputs that aren't in a struct.
   let ts = quote! {
       impl #name {
@@ -245,21 +390,26 @@ pub fn generate_vertex_input_impl(input: &wgsl::VertexInput) -> RustSourceItem {
                   attributes: &Self::VERTEX_ATTRIBUTES
               }
           }
+
+          #vertex_buffer_layout
+
+          #create_vertex_buffer
       }
   };
 
-  RustSourceItem {
+  Ok(RustSourceItem {
     catagories: RustSourceItemCategory::TypeImpls.into(),
     path: input.item_path.clone(),
     tokenstream: ts,
-  }
+  })
 }
 
 pub fn fragment_states(module: &naga::Module) -> TokenStream {
   let entries: Vec<TokenStream> = module
     .entry_points
     .iter()
-    .filter_map(|entry_point| match &entry_point.stage {
+    .enumerate()
+    .filter_map(|(index, entry_point)| match &entry_point.stage {
       ShaderStage::Fragment => {
         let fn_name =
           Ident::new(&format!("{}_entry", entry_point.name), Span::call_site());
@@ -285,7 +435,10 @@ pub fn fragment_states(module: &naga::Module) -> TokenStream {
           quote!(Default::default())
         };
 
+        let doc_comment = entry_point_doc_comments(module, index);
+
         Some(quote! {
+            #doc_comment
             pub fn #fn_name(
                 targets: [Option<wgpu::ColorTargetState>; #target_count],
                 #overrides
@@ -349,7 +502,8 @@ mod test {
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_struct_impls("test", &module)
+    let actual = vertex_struct_impls("test", &module, &WgslBindgenOption::default())
+      .unwrap()
       .into_iter()
       .map(|it| it.tokenstream)
       .collect::<TokenStream>();
@@ -372,7 +526,8 @@ mod test {
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_struct_impls("test", &module)
+    let actual = vertex_struct_impls("test", &module, &WgslBindgenOption::default())
+      .unwrap()
       .into_iter()
       .map(|it| it.tokenstream)
       .collect::<TokenStream>();
@@ -395,7 +550,8 @@ mod test {
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_struct_impls("test", &module)
+    let actual = vertex_struct_impls("test", &module, &WgslBindgenOption::default())
+      .unwrap()
       .into_iter()
       .map(|it| it.tokenstream)
       .collect::<TokenStream>();
@@ -418,7 +574,8 @@ mod test {
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_struct_impls("test", &module)
+    let actual = vertex_struct_impls("test", &module, &WgslBindgenOption::default())
+      .unwrap()
       .into_iter()
       .map(|it| it.tokenstream)
       .collect::<TokenStream>();
@@ -458,7 +615,7 @@ mod test {
     };
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_states("test", &module);
+    let actual = vertex_states("test", &module, &WgslBindgenOption::default());
 
     assert_tokens_snapshot!(actual);
   }
@@ -478,7 +635,7 @@ mod test {
     };
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_states("test", &module);
+    let actual = vertex_states("test", &module, &WgslBindgenOption::default());
 
     assert_tokens_snapshot!(actual);
   }
@@ -499,7 +656,7 @@ mod test {
     };
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_states("test", &module);
+    let actual = vertex_states("test", &module, &WgslBindgenOption::default());
 
     assert_tokens_snapshot!(actual);
   }
@@ -516,7 +673,7 @@ mod test {
     };
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = vertex_states("test", &module);
+    let actual = vertex_states("test", &module, &WgslBindgenOption::default());
 
     assert_tokens_snapshot!(actual);
   }
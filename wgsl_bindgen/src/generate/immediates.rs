@@ -0,0 +1,169 @@
+//! Generates setters for the immediate data (`var<immediate>`) of a shader.
+
+use heck::ToSnakeCase;
+use quote::{format_ident, quote};
+use syn::Index;
+
+use super::quote_shader_stages;
+use crate::quote_gen::{
+  encase_bytes_item, quote_encase_bytes, rust_type, RustSourceItem,
+  RustSourceItemCategory, RustSourceItemPath, MOD_REFERENCE_ROOT,
+};
+use crate::{
+  sanitized_upper_snake_case, wgsl, WgslBindgenOption, WgslTypeSerializeStrategy,
+};
+
+/// wgpu sets immediate data in whole words.
+const IMMEDIATE_DATA_ALIGNMENT: u32 = 4;
+
+/// The size of the immediate data range of a pipeline layout, large enough for
+/// the immediate data of every entry point. naga places every immediate
+/// variable at offset 0, so the ranges overlap and the largest one is enough.
+pub fn immediate_size(module: &naga::Module) -> u32 {
+  module
+    .global_variables
+    .iter()
+    .filter(|(_, global)| global.space == naga::AddressSpace::Immediate)
+    .map(|(_, global)| {
+      module.types[global.ty]
+        .inner
+        .size(module.to_ctx())
+        .next_multiple_of(IMMEDIATE_DATA_ALIGNMENT)
+    })
+    .max()
+    .unwrap_or(0)
+}
+
+/// The `SetImmediates` trait, its impls for the passes that can run the
+/// shader, and a setter for each immediate variable.
+pub fn set_immediates_items(
+  invoking_entry_module: &str,
+  module: &naga::Module,
+  options: &WgslBindgenOption,
+) -> Vec<RustSourceItem> {
+  // Immediate data no entry point reads needs no setter.
+  let immediates = module
+    .global_variables
+    .iter()
+    .filter(|(_, global)| global.space == naga::AddressSpace::Immediate)
+    .map(|(handle, global)| (global, wgsl::global_shader_stages(module, handle)))
+    .filter(|(_, stages)| !stages.is_empty())
+    .collect::<Vec<_>>();
+  if immediates.is_empty() {
+    return Vec::new();
+  }
+
+  let mut stages = wgpu::ShaderStages::empty();
+  let mut items = Vec::new();
+  for &(global, var_stages) in &immediates {
+    stages |= var_stages;
+
+    // A lone immediate variable gets the plain names, several are told apart
+    // by their variable names.
+    let var_name = global.name.as_deref().unwrap_or("immediates");
+    let (stages_name, setter_name) = if immediates.len() == 1 {
+      ("IMMEDIATE_STAGES".to_string(), "set_immediates".to_string())
+    } else {
+      (
+        format!("{}_IMMEDIATE_STAGES", sanitized_upper_snake_case(var_name)),
+        format!("set_{}_immediates", var_name.to_snake_case()),
+      )
+    };
+    let stages_ident = format_ident!("{stages_name}");
+    let setter_ident = format_ident!("{setter_name}");
+    let quoted_stages = quote_shader_stages(var_stages);
+
+    let ty = &module.types[global.ty];
+    let data_type = rust_type(Some(invoking_entry_module), module, ty, options);
+    let contents = match options.serialization_strategy {
+      WgslTypeSerializeStrategy::Bytemuck => quote!(bytemuck::bytes_of(data)),
+      WgslTypeSerializeStrategy::Zerocopy => quote!(zerocopy::IntoBytes::as_bytes(data)),
+      WgslTypeSerializeStrategy::Encase => quote_encase_bytes(quote!(data), false),
+    };
+
+    // Immediate data is set in whole words, so smaller data (e.g. an `f16`) is
+    // padded with zeros.
+    let size = ty.inner.size(module.to_ctx());
+    let set = if size.is_multiple_of(IMMEDIATE_DATA_ALIGNMENT) {
+      quote!(pass.set_immediates(0, #contents);)
+    } else {
+      let padded_size =
+        Index::from(size.next_multiple_of(IMMEDIATE_DATA_ALIGNMENT) as usize);
+      quote! {
+        let data = #contents;
+        let mut bytes = [0u8; #padded_size];
+        bytes[..data.len()].copy_from_slice(data);
+        pass.set_immediates(0, &bytes);
+      }
+    };
+
+    let stages_doc = format!("The stages of the entry points that read `{var_name}`.");
+    let mut setter_doc =
+      format!("Sets `{var_name}` for the entry points in [`{stages_name}`].");
+    if immediates.len() > 1 {
+      setter_doc.push_str(
+        " Every immediate variable starts at offset 0, so this overwrites the others set on `pass`.",
+      );
+    }
+    items.extend([
+      RustSourceItem::new(
+        RustSourceItemCategory::ConstVarDecls.into(),
+        RustSourceItemPath::new(invoking_entry_module.into(), stages_name.into()),
+        quote! {
+          #[doc = #stages_doc]
+          pub const #stages_ident: wgpu::ShaderStages = #quoted_stages;
+        },
+      ),
+      RustSourceItem::new(
+        RustSourceItemCategory::TypeImpls.into(),
+        RustSourceItemPath::new(invoking_entry_module.into(), setter_name.into()),
+        quote! {
+          #[doc = #setter_doc]
+          pub fn #setter_ident(pass: &mut impl SetImmediates, data: &#data_type) {
+            #set
+          }
+        },
+      ),
+    ]);
+  }
+
+  items.push(RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs.into(),
+    RustSourceItemPath::new(MOD_REFERENCE_ROOT.into(), "SetImmediates".into()),
+    quote! {
+      pub trait SetImmediates {
+        fn set_immediates(&mut self, offset: u32, data: &[u8]);
+      }
+    },
+  ));
+
+  let mut encoders = Vec::new();
+  if stages.contains(wgpu::ShaderStages::COMPUTE) {
+    encoders.push("ComputePass");
+  }
+  if stages.intersects(wgpu::ShaderStages::VERTEX_FRAGMENT) {
+    encoders.extend(["RenderPass", "RenderBundleEncoder"]);
+  }
+  items.extend(encoders.into_iter().map(|encoder| {
+    let encoder = format_ident!("{encoder}");
+    RustSourceItem::new(
+      RustSourceItemCategory::TraitImpls.into(),
+      RustSourceItemPath::new(
+        MOD_REFERENCE_ROOT.into(),
+        format!("impl SetImmediates for wgpu::{encoder}<'_>").into(),
+      ),
+      quote! {
+        impl SetImmediates for wgpu::#encoder<'_> {
+          fn set_immediates(&mut self, offset: u32, data: &[u8]) {
+              self.set_immediates(offset, data);
+          }
+        }
+      },
+    )
+  }));
+  if options.serialization_strategy.is_encase() {
+    items.push(encase_bytes_item());
+  }
+
+  items
+}
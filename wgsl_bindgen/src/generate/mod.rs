@@ -1,13 +1,15 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 pub(crate) mod bind_group;
 pub(crate) mod consts;
 pub(crate) mod entry;
+pub(crate) mod immediates;
 pub(crate) mod pipeline;
 pub(crate) mod shader_module;
 pub(crate) mod shader_registry;
 pub(crate) mod vertex_input_collector;
+pub(crate) mod vertex_pulling;
 
 pub(crate) fn quote_naga_capabilities(
   capabilities: naga::valid::Capabilities,
@@ -91,3 +93,89 @@ pub(crate) fn quote_shader_stages(shader_stages: wgpu::ShaderStages) -> TokenStr
     }
   }
 }
+
+pub(crate) fn quote_features(features: wgpu::Features) -> TokenStream {
+  let names = features
+    .iter_names()
+    .map(|(name, _)| format_ident!("{name}"))
+    .collect::<Vec<_>>();
+
+  match names.as_slice() {
+    [] => quote!(wgpu::Features::empty()),
+    [name] => quote!(wgpu::Features::#name),
+    _ => {
+      // `Features` has no const `union`, but the two halves it is built from do.
+      fn quote_half<'a>(
+        ty: TokenStream,
+        names: impl Iterator<Item = (&'a str, impl Sized)>,
+      ) -> TokenStream {
+        let flags = names
+          .map(|(name, _)| {
+            let name = format_ident!("{name}");
+            quote!(#ty::#name)
+          })
+          .collect::<Vec<_>>();
+        match flags.split_first() {
+          None => quote!(#ty::empty()),
+          Some((first, rest)) => quote!(#first #(.union(#rest))*),
+        }
+      }
+
+      let features_wgpu =
+        quote_half(quote!(wgpu::FeaturesWGPU), features.features_wgpu.iter_names());
+      let features_webgpu =
+        quote_half(quote!(wgpu::FeaturesWebGPU), features.features_webgpu.iter_names());
+      quote! {
+        wgpu::Features {
+          features_wgpu: #features_wgpu,
+          features_webgpu: #features_webgpu,
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn quote_features_combines_both_halves() {
+    let features = wgpu::Features::SHADER_F16
+      | wgpu::Features::SHADER_F64
+      | wgpu::Features::SHADER_INT64;
+
+    // The quoted expression, written out, evaluates to the same features.
+    const QUOTED: wgpu::Features = wgpu::Features {
+      features_wgpu: wgpu::FeaturesWGPU::SHADER_F64
+        .union(wgpu::FeaturesWGPU::SHADER_INT64),
+      features_webgpu: wgpu::FeaturesWebGPU::SHADER_F16,
+    };
+    assert_eq!(features, QUOTED);
+    assert_eq!(
+      quote! {
+        wgpu::Features {
+          features_wgpu: wgpu::FeaturesWGPU::SHADER_F64
+            .union(wgpu::FeaturesWGPU::SHADER_INT64),
+          features_webgpu: wgpu::FeaturesWebGPU::SHADER_F16,
+        }
+      }
+      .to_string(),
+      quote_features(features).to_string()
+    );
+  }
+
+  #[test]
+  fn quote_features_single_and_empty() {
+    assert_eq!(
+      quote!(wgpu::Features::empty()).to_string(),
+      quote_features(wgpu::Features::empty()).to_string()
+    );
+    assert_eq!(
+      quote!(wgpu::Features::SHADER_F16).to_string(),
+      quote_features(wgpu::Features::SHADER_F16).to_string()
+    );
+  }
+}
@@ -89,17 +89,7 @@ pub fn create_pipeline_layout_fn(
       quote!()
     };
 
-  let immediate_size = naga_module
-    .global_variables
-    .iter()
-    .find_map(|g| {
-      if g.1.space == naga::AddressSpace::Immediate {
-        Some(naga_module.types[g.1.ty].inner.size(naga_module.to_ctx()))
-      } else {
-        None
-      }
-    })
-    .unwrap_or(0);
+  let immediate_size = immediates::immediate_size(naga_module);
 
   let pipeline_layout_name = format!("{entry_name}::PipelineLayout");
 
@@ -9,9 +9,12 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, TokenStreamExt};
 use syn::{Ident, Index};
 
-use crate::generate::quote_naga_capabilities;
-use crate::naga_util::module_to_source;
-use crate::quote_gen::create_shader_raw_string_literal;
+use crate::generate::{quote_features, quote_naga_capabilities};
+use crate::naga_util::{entry_point_doc_comments, module_to_source};
+use crate::quote_gen::{
+  array_length_param, create_shader_raw_string_literal, generate_doc_comment, rust_type,
+};
+use crate::wgsl::{self, required_features};
 use crate::{
   sanitize_and_pascal_case, WgslBindgenOption, WgslEntryResult, WgslShaderSourceType,
 };
@@ -32,6 +35,19 @@ impl<'a> WgslEntryResult<'a> {
       None => quote!(None),
     }
   }
+
+  fn required_features_const(&self) -> TokenStream {
+    let features = required_features(&self.naga_module);
+    if features.is_empty() {
+      return quote!();
+    }
+
+    let features = quote_features(features);
+    quote! {
+      /// Device features that must be enabled to create this shader module.
+      pub const REQUIRED_FEATURES: wgpu::Features = #features;
+    }
+  }
 }
 
 impl WgslShaderSourceType {
@@ -170,13 +186,15 @@ impl WgslShaderSourceType {
 #[derive(Constructor)]
 struct ComputeModuleBuilder<'a> {
   module: &'a naga::Module,
-  source_type_flags: BitFlags<WgslShaderSourceType>,
+  options: &'a WgslBindgenOption,
 }
 
 impl<'a> ComputeModuleBuilder<'a> {
   fn build_compute_pipeline_fn(
+    &self,
     e: &naga::EntryPoint,
     source_type: WgslShaderSourceType,
+    doc_comment: &TokenStream,
   ) -> TokenStream {
     // Compute pipeline creation has few parameters and can be generated.
 
@@ -189,7 +207,7 @@ impl<'a> ComputeModuleBuilder<'a> {
     let create_shader_module_fn_name =
       format_ident!("{}", source_type.create_shader_module_fn_name());
 
-    let (param_defs, params) = source_type.shader_module_params_defs_and_params();
+    let (mut param_defs, params) = source_type.shader_module_params_defs_and_params();
 
     let return_type = source_type.get_return_type(quote!(wgpu::ComputePipeline));
     let propagate_operator = source_type.get_propagate_operator();
@@ -198,19 +216,48 @@ impl<'a> ComputeModuleBuilder<'a> {
       let module = super::#create_shader_module_fn_name(#params)#propagate_operator;
     };
 
+    let compilation_options = if self.module.overrides.is_empty() {
+      quote!(Default::default())
+    } else {
+      param_defs.extend(quote!(, overrides: &super::OverrideConstants));
+      quote! {
+        wgpu::PipelineCompilationOptions {
+          constants: &overrides.constants(),
+          ..Default::default()
+        }
+      }
+    };
+
+    // Override-sized workgroup arrays are mirrored on the host with const
+    // generic lengths, which must agree with the overrides the pipeline uses.
+    let length_params = wgsl::array_length_overrides(self.module)
+      .into_iter()
+      .map(|handle| array_length_param(self.module, handle))
+      .collect::<Vec<_>>();
+    let (generics, check_overrides) = if length_params.is_empty() {
+      (quote!(), quote!())
+    } else {
+      (
+        quote!(<#(const #length_params: usize),*>),
+        quote!(overrides.assert_array_lengths::<#(#length_params),*>();),
+      )
+    };
+
     let return_value = source_type.wrap_return_stmt(quote! {
       device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
           label: Some(#label),
           layout: Some(&layout),
           module: &module,
           entry_point: Some(#entry_point),
-          compilation_options: Default::default(),
+          compilation_options: #compilation_options,
           cache: None,
       })
     });
 
     quote! {
-        pub fn #pipeline_name(#param_defs) -> #return_type {
+        #doc_comment
+        pub fn #pipeline_name #generics(#param_defs) -> #return_type {
+            #check_overrides
             #module_creation
             let layout = super::create_pipeline_layout(device);
             #return_value
@@ -233,16 +280,63 @@ impl<'a> ComputeModuleBuilder<'a> {
       .filter(|e| e.stage == naga::ShaderStage::Compute)
   }
 
+  /// Host-side types for workgroup variables sized by an override, so their
+  /// footprint can be checked against the device limits.
+  fn workgroup_variable_types(&self) -> Vec<TokenStream> {
+    self
+      .module
+      .global_variables
+      .iter()
+      .filter(|(_, var)| var.space == naga::AddressSpace::WorkGroup)
+      .filter_map(|(_, var)| {
+        let naga::TypeInner::Array {
+          size: naga::ArraySize::Pending(length_override),
+          ..
+        } = self.module.types[var.ty].inner
+        else {
+          return None;
+        };
+
+        let var_name = var.name.as_ref()?;
+        let name = format_ident!("{}", sanitize_and_pascal_case(var_name));
+        let length = array_length_param(self.module, length_override);
+        let rust_type =
+          rust_type(None, self.module, &self.module.types[var.ty], self.options);
+        let doc = format!(
+          "Host-side layout of `var<workgroup> {var_name}`, sized by the `{}` override.",
+          self.module.overrides[length_override]
+            .name
+            .as_deref()
+            .unwrap_or_default()
+        );
+
+        let doc = generate_doc_comment(&doc);
+
+        Some(quote! {
+          #doc
+          pub type #name<const #length: usize> = #rust_type;
+        })
+      })
+      .collect()
+  }
+
   fn build(&self) -> TokenStream {
+    let workgroup_variable_types = self.workgroup_variable_types();
     let entry_points: Vec<_> = self
-      .entry_points_iter()
-      .map(|e| {
+      .module
+      .entry_points
+      .iter()
+      .enumerate()
+      .filter(|(_, e)| e.stage == naga::ShaderStage::Compute)
+      .map(|(index, e)| {
         let workgroup_size_constant = Self::workgroup_size(e);
+        let doc_comment = entry_point_doc_comments(self.module, index);
 
         let create_pipeline_fns = self
-          .source_type_flags
+          .options
+          .shader_source_type
           .iter()
-          .map(|source_type| Self::build_compute_pipeline_fn(e, source_type))
+          .map(|source_type| self.build_compute_pipeline_fn(e, source_type, &doc_comment))
           .collect::<Vec<_>>();
 
         quote! {
@@ -259,6 +353,7 @@ impl<'a> ComputeModuleBuilder<'a> {
       quote! {
           pub mod compute {
               use super::{_root, _root::*};
+              #(#workgroup_variable_types)*
               #(#entry_points)*
           }
       }
@@ -267,9 +362,9 @@ impl<'a> ComputeModuleBuilder<'a> {
 }
 pub(crate) fn compute_module(
   module: &naga::Module,
-  source_type_flags: BitFlags<WgslShaderSourceType>,
+  options: &WgslBindgenOption,
 ) -> TokenStream {
-  ComputeModuleBuilder::new(module, source_type_flags).build()
+  ComputeModuleBuilder::new(module, options).build()
 }
 
 fn generate_shader_module_embedded(entry: &WgslEntryResult) -> TokenStream {
@@ -720,7 +815,7 @@ pub(crate) fn shader_module(
         .into()
     });
 
-  let mut token_stream = TokenStream::new();
+  let mut token_stream = entry.required_features_const();
 
   if source_type.contains(EmbedSource) {
     token_stream.append_all(generate_shader_module_embedded(entry));
@@ -803,7 +898,42 @@ mod tests {
         "#};
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = compute_module(&module, WgslShaderSourceType::EmbedSource.into());
+    let actual = compute_module(
+      &module,
+      &WgslBindgenOption {
+        shader_source_type: WgslShaderSourceType::EmbedSource.into(),
+        ..Default::default()
+      },
+    );
+
+    assert_tokens_snapshot!(actual);
+  }
+
+  #[test]
+  fn write_compute_module_override_sized_workgroup_arrays() {
+    let source = indoc! {r#"
+            override block_size: u32 = 64u;
+            override TILE_COUNT: u32;
+
+            var<workgroup> scratch: array<f32, block_size>;
+            var<workgroup> tiles: array<array<u32, 4>, TILE_COUNT>;
+
+            @compute
+            @workgroup_size(64)
+            fn main(@builtin(local_invocation_index) index: u32) {
+                scratch[index] = f32(tiles[index][0]);
+            }
+        "#
+    };
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    let actual = compute_module(
+      &module,
+      &WgslBindgenOption {
+        shader_source_type: WgslShaderSourceType::EmbedSource.into(),
+        ..Default::default()
+      },
+    );
 
     assert_tokens_snapshot!(actual);
   }
@@ -822,7 +952,13 @@ mod tests {
     };
 
     let module = naga::front::wgsl::parse_str(source).unwrap();
-    let actual = compute_module(&module, WgslShaderSourceType::EmbedSource.into());
+    let actual = compute_module(
+      &module,
+      &WgslBindgenOption {
+        shader_source_type: WgslShaderSourceType::EmbedSource.into(),
+        ..Default::default()
+      },
+    );
 
     assert_tokens_snapshot!(actual);
   }
@@ -5,7 +5,8 @@ use smol_str::SmolStr;
 use std::collections::{BTreeMap, HashSet};
 
 use crate::quote_gen::RustSourceItem;
-use crate::wgsl::{get_all_vertex_input_structs, VertexInput};
+use crate::wgsl::{get_vertex_input_structs, VertexInput};
+use crate::{CreateModuleError, WgslBindgenOption};
 
 /// Holds vertex input structs for a single shader entry/module
 pub struct RawShaderVertexInputs<'a> {
@@ -37,7 +38,7 @@ impl<'a> RawShadersVertexInputs<'a> {
     mod_name: &str,
     naga_module: &'a naga::Module,
   ) -> RawShaderVertexInputs<'a> {
-    let vertex_inputs = get_all_vertex_input_structs(mod_name, naga_module);
+    let vertex_inputs = get_vertex_input_structs(mod_name, naga_module);
     RawShaderVertexInputs {
       containing_module: SmolStr::new(mod_name),
       vertex_inputs,
@@ -45,7 +46,10 @@ impl<'a> RawShadersVertexInputs<'a> {
   }
 
   /// Generate deduplicated vertex input struct implementations
-  pub fn generate_vertex_input_impls(self) -> Vec<RustSourceItem> {
+  pub fn generate_vertex_input_impls(
+    self,
+    options: &WgslBindgenOption,
+  ) -> Result<Vec<RustSourceItem>, CreateModuleError> {
     // Global deduplication: collect unique vertex input structs by their actual content/path
     let mut unique_vertex_inputs = BTreeMap::new();
 
@@ -63,10 +67,13 @@ impl<'a> RawShadersVertexInputs<'a> {
       }
     }
 
-    // Generate implementations for all unique vertex input structs
-    unique_vertex_inputs
-      .into_values()
-      .map(super::entry::generate_vertex_input_impl)
-      .collect()
+    // Generate implementations for all unique vertex input structs, and the
+    // vertex pulling variant of those matched by `vertex_pulling`
+    let mut items = Vec::new();
+    for vertex_input in unique_vertex_inputs.into_values() {
+      items.push(super::entry::generate_vertex_input_impl(vertex_input, options)?);
+      items.extend(super::vertex_pulling::vertex_pulling_items(vertex_input, options)?);
+    }
+    Ok(items)
   }
 }
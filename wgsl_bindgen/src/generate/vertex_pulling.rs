@@ -0,0 +1,143 @@
+//! Generates the vertex pulling variant of vertex input structs: a WGSL library
+//! that fetches them from a storage buffer, and the matching Rust struct with
+//! the storage buffer layout.
+
+use std::fmt::Write;
+
+use heck::ToSnakeCase;
+use naga::common::wgsl::TypeContext;
+use proc_macro2::Literal;
+use quote::{format_ident, quote};
+
+use crate::bevy_util::demangle_str;
+use crate::quote_gen::{
+  create_shader_raw_string_literal, quote_encase_bytes, RustSourceItem,
+  RustSourceItemCategory, RustSourceItemPath,
+};
+use crate::structs::{self, StructBufferUsage, StructBufferUsages};
+use crate::wgsl::{self, VertexInput};
+use crate::{CreateModuleError, WgslBindgenOption, WgslTypeSerializeStrategy};
+
+/// The WGSL library reading `input` from a storage buffer as `pulled_name`.
+fn vertex_pulling_source(
+  input: &VertexInput,
+  pulled_name: &str,
+  group: u32,
+  binding: u32,
+) -> String {
+  let module = input.naga_module;
+  let snake_name = input.item_path.name.to_snake_case();
+
+  let mut source = String::new();
+  let uses_f16 = input.fields.iter().any(|(_, member)| {
+    module.types[member.ty]
+      .inner
+      .scalar()
+      .is_some_and(|scalar| scalar == naga::Scalar::F16)
+  });
+  if uses_f16 {
+    source.push_str("enable f16;\n\n");
+  }
+
+  writeln!(source, "struct {pulled_name} {{").unwrap();
+  for (_, member) in &input.fields {
+    let name = member.name.as_deref().unwrap();
+    let ty = demangle_str(&module.to_ctx().type_to_string(member.ty)).to_string();
+    writeln!(source, "    {name}: {ty},").unwrap();
+  }
+  writeln!(source, "}}\n").unwrap();
+  writeln!(
+    source,
+    "@group({group}) @binding({binding}) var<storage, read> {snake_name}_buffer: array<{pulled_name}>;\n"
+  )
+  .unwrap();
+  writeln!(
+    source,
+    "fn fetch_{snake_name}(index: u32) -> {pulled_name} {{\n    return {snake_name}_buffer[index];\n}}"
+  )
+  .unwrap();
+  source
+}
+
+/// Returns the `*Pulled` struct of a vertex input matched by `vertex_pulling`,
+/// along with its WGSL library and buffer helper.
+pub(crate) fn vertex_pulling_items(
+  input: &VertexInput,
+  options: &WgslBindgenOption,
+) -> Result<Vec<RustSourceItem>, CreateModuleError> {
+  let struct_name = input.item_path.get_fully_qualified_name();
+  let Some(pulling) = wgsl::vertex_pulling(options, &struct_name) else {
+    return Ok(Vec::new());
+  };
+
+  let pulled_name = format!("{}Pulled", input.item_path.name);
+  let pulled_path =
+    RustSourceItemPath::new(input.item_path.module.clone(), pulled_name.into());
+  let source =
+    vertex_pulling_source(input, &pulled_path.name, pulling.group, pulling.binding);
+
+  // The library binds the struct in a storage buffer, so the existing struct
+  // generation gives it the storage buffer layout.
+  let pulled_module = naga::front::wgsl::parse_str(&source).map_err(|err| {
+    CreateModuleError::VertexPullingLibrary {
+      struct_name: struct_name.to_string(),
+      error: err.emit_to_string(&source),
+    }
+  })?;
+  let buffer_usages = StructBufferUsages::from([(
+    pulled_path.get_fully_qualified_name(),
+    StructBufferUsage {
+      usages: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+      has_dynamic_offset: false,
+    },
+  )]);
+  let mut items =
+    structs::structs_items(&pulled_path.module, &pulled_module, options, &buffer_usages)?;
+
+  let name = format_ident!("{}", pulled_path.name.as_str());
+  let fetch_fn = format!("fetch_{}", input.item_path.name.to_snake_case());
+  let wgsl_doc = format!(
+    "WGSL declaring `{}` and its storage buffer at `@group({}) @binding({})`, with \
+     `{fetch_fn}(index: u32)` reading one vertex from it. Compose it with a shader, eg: as a \
+     naga_oil composable module, to pull `{}` vertices instead of reading vertex attributes.",
+    pulled_path.name, pulling.group, pulling.binding, input.item_path.name
+  );
+  let source_literal = create_shader_raw_string_literal(&source);
+  let group = Literal::u32_unsuffixed(pulling.group);
+  let binding = Literal::u32_unsuffixed(pulling.binding);
+
+  let contents = match options.serialization_strategy {
+    WgslTypeSerializeStrategy::Bytemuck => quote!(bytemuck::cast_slice(vertices)),
+    WgslTypeSerializeStrategy::Zerocopy => {
+      quote!(zerocopy::IntoBytes::as_bytes(vertices))
+    }
+    WgslTypeSerializeStrategy::Encase => quote_encase_bytes(quote!(vertices), false),
+  };
+  let label = pulled_path.get_fully_qualified_name().to_string();
+
+  items.push(RustSourceItem::new(
+    RustSourceItemCategory::TypeImpls.into(),
+    pulled_path,
+    quote! {
+      impl #name {
+        #[doc = #wgsl_doc]
+        pub const PULLING_SHADER: &str = #source_literal;
+        pub const PULLING_GROUP: u32 = #group;
+        pub const PULLING_BINDING: u32 = #binding;
+
+        /// Creates a storage buffer holding `vertices` for [`Self::PULLING_SHADER`].
+        pub fn create_pulling_buffer(device: &wgpu::Device, vertices: &[Self]) -> wgpu::Buffer {
+          wgpu::util::DeviceExt::create_buffer_init(
+            device,
+            &wgpu::util::BufferInitDescriptor {
+              label: Some(#label),
+              contents: #contents,
+              usage: Self::BUFFER_USAGES,
+            },
+          )
+        }
+      }
+    },
+  ));
+  Ok(items)
+}
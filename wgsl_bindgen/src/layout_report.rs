@@ -0,0 +1,262 @@
+//! The WGSL memory layout of host-shareable structs, reported either as doc
+//! tables on the generated structs or as a JSON file next to the output.
+
+use std::fmt::Write;
+
+use enumflags2::bitflags;
+use naga::common::wgsl::TypeContext;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::bevy_util::demangle_str;
+use crate::WgslEntryResult;
+
+/// Where the layout of host-shareable structs is reported.
+#[bitflags]
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WgslLayoutReport {
+  /// Document each generated struct with a table of its member layout.
+  DocComments,
+
+  /// Write the layout of every struct to a `.layout.json` file next to the
+  /// output, e.g. `src/shader_bindings.layout.json`. Only applies to
+  /// `generate`, since `generate_string` has no output path.
+  Json,
+}
+
+/// The WGSL layout of a single struct member.
+#[derive(Debug, Clone)]
+pub(crate) struct MemberLayoutReport {
+  pub name: String,
+  pub ty: String,
+  pub offset: u32,
+  /// `None` for a runtime-sized array, whose size depends on its buffer.
+  pub size: Option<u32>,
+  /// The element stride of a runtime-sized array.
+  pub stride: Option<u32>,
+  pub alignment: u32,
+  /// Bytes between the end of this member and the next one, or the end of
+  /// the struct.
+  pub padding: u32,
+}
+
+/// The WGSL layout of a struct, as computed by `naga::proc::Layouter`.
+#[derive(Debug, Clone)]
+pub(crate) struct StructLayoutReport {
+  pub name: String,
+  pub size: u32,
+  pub alignment: u32,
+  pub members: Vec<MemberLayoutReport>,
+}
+
+impl StructLayoutReport {
+  pub fn from_naga(
+    name: &str,
+    naga_members: &[naga::StructMember],
+    layouter: &naga::proc::Layouter,
+    t_handle: naga::Handle<naga::Type>,
+    module: &naga::Module,
+  ) -> Self {
+    let layout = layouter[t_handle];
+    let naga_context = module.to_ctx();
+
+    let members = naga_members
+      .iter()
+      .enumerate()
+      .map(|(index, member)| {
+        let member_layout = layouter[member.ty];
+        let (size, stride) = match module.types[member.ty].inner {
+          naga::TypeInner::Array {
+            size: naga::ArraySize::Dynamic,
+            stride,
+            ..
+          } => (None, Some(stride)),
+          ref inner => (Some(inner.size(naga_context)), None),
+        };
+        let next_offset = naga_members
+          .get(index + 1)
+          .map_or(layout.size, |next| next.offset);
+        let padding = size.map_or(0, |size| next_offset - member.offset - size);
+
+        MemberLayoutReport {
+          name: member.name.clone().unwrap_or_default(),
+          ty: demangle_str(&naga_context.type_to_string(member.ty)).to_string(),
+          offset: member.offset,
+          size,
+          stride,
+          alignment: member_layout.alignment * 1u32,
+          padding,
+        }
+      })
+      .collect();
+
+    Self {
+      name: name.to_string(),
+      size: layout.size,
+      alignment: layout.alignment * 1u32,
+      members,
+    }
+  }
+
+  /// A markdown table of the member layout, as doc attributes.
+  pub fn doc_comment(&self) -> TokenStream {
+    let mut lines = vec![
+      format!(" WGSL layout: size {}, alignment {}.", self.size, self.alignment),
+      String::new(),
+      " | Member | Type | Offset | Size | Alignment | Padding |".to_string(),
+      " |---|---|---:|---:|---:|---:|".to_string(),
+    ];
+    lines.extend(self.members.iter().map(|member| {
+      let size = match (member.size, member.stride) {
+        (Some(size), _) => size.to_string(),
+        (None, Some(stride)) => format!("{stride} × N"),
+        (None, None) => "?".to_string(),
+      };
+      format!(
+        " | `{}` | `{}` | {} | {} | {} | {} |",
+        member.name, member.ty, member.offset, size, member.alignment, member.padding
+      )
+    }));
+    quote!(#(#[doc = #lines])*)
+  }
+
+  fn write_json(&self, out: &mut String) {
+    write!(
+      out,
+      "{{\"name\":{},\"size\":{},\"alignment\":{},\"members\":[",
+      json_string(&self.name),
+      self.size,
+      self.alignment
+    )
+    .unwrap();
+    for (index, member) in self.members.iter().enumerate() {
+      if index > 0 {
+        out.push(',');
+      }
+      let optional =
+        |value: Option<u32>| value.map_or("null".to_string(), |v| v.to_string());
+      write!(
+        out,
+        "{{\"name\":{},\"type\":{},\"offset\":{},\"size\":{},\"stride\":{},\"alignment\":{},\"padding\":{}}}",
+        json_string(&member.name),
+        json_string(&member.ty),
+        member.offset,
+        optional(member.size),
+        optional(member.stride),
+        member.alignment,
+        member.padding
+      )
+      .unwrap();
+    }
+    out.push_str("]}");
+  }
+}
+
+fn json_string(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len() + 2);
+  escaped.push('"');
+  for ch in value.chars() {
+    match ch {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      ch if ch.is_control() => write!(escaped, "\\u{:04x}", ch as u32).unwrap(),
+      ch => escaped.push(ch),
+    }
+  }
+  escaped.push('"');
+  escaped
+}
+
+/// The layout of the host-shareable structs of every entry as a JSON
+/// document, one line per struct.
+pub(crate) fn layout_report_json(
+  entries: &[WgslEntryResult<'_>],
+  options: &crate::WgslBindgenOption,
+) -> String {
+  let mut reports = crate::FastIndexMap::default();
+  for entry in entries {
+    for report in
+      crate::structs::struct_layout_reports(&entry.mod_name, &entry.naga_module, options)
+    {
+      reports.entry(report.name.clone()).or_insert(report);
+    }
+  }
+
+  let mut out = String::from("{\"structs\":[\n");
+  for (index, report) in reports.values().enumerate() {
+    if index > 0 {
+      out.push_str(",\n");
+    }
+    out.push_str("  ");
+    report.write_json(&mut out);
+  }
+  out.push_str("\n]}\n");
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use indoc::indoc;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn struct_layout_report() {
+    let source = indoc! {r#"
+      struct Light {
+        position: vec3<f32>,
+        intensity: f32,
+        color: vec3<f32>,
+      };
+
+      struct Lights {
+        count: u32,
+        lights: array<Light>,
+      };
+
+      @group(0) @binding(0) var<storage> lights: Lights;
+    "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    let mut layouter = naga::proc::Layouter::default();
+    layouter.update(module.to_ctx()).unwrap();
+
+    let report = |name: &str| {
+      let (handle, ty) = module
+        .types
+        .iter()
+        .find(|(_, ty)| ty.name.as_deref() == Some(name))
+        .unwrap();
+      let naga::TypeInner::Struct { members, .. } = &ty.inner else {
+        unreachable!()
+      };
+      let report =
+        StructLayoutReport::from_naga(name, members, &layouter, handle, &module);
+      let mut json = String::new();
+      report.write_json(&mut json);
+      json
+    };
+
+    assert_eq!(
+      report("Light"),
+      concat!(
+        r#"{"name":"Light","size":32,"alignment":16,"members":["#,
+        r#"{"name":"position","type":"vec3<f32>","offset":0,"size":12,"stride":null,"alignment":16,"padding":0},"#,
+        r#"{"name":"intensity","type":"f32","offset":12,"size":4,"stride":null,"alignment":4,"padding":0},"#,
+        r#"{"name":"color","type":"vec3<f32>","offset":16,"size":12,"stride":null,"alignment":16,"padding":4}"#,
+        "]}"
+      )
+    );
+    assert_eq!(
+      report("Lights"),
+      concat!(
+        r#"{"name":"Lights","size":48,"alignment":16,"members":["#,
+        r#"{"name":"count","type":"u32","offset":0,"size":4,"stride":null,"alignment":4,"padding":12},"#,
+        r#"{"name":"lights","type":"array<Light>","offset":16,"size":null,"stride":32,"alignment":16,"padding":0}"#,
+        "]}"
+      )
+    );
+  }
+}
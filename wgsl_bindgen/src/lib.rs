@@ -162,7 +162,9 @@ use case::CaseExt;
 use derive_more::IsVariant;
 use generate::bind_group::RawShadersBindGroups;
 use generate::entry::{self, entry_point_constants, vertex_struct_impls};
-use generate::{bind_group, consts, pipeline, shader_module, shader_registry};
+use generate::{
+  bind_group, consts, immediates, pipeline, shader_module, shader_registry,
+};
 use heck::ToPascalCase;
 use proc_macro2::{Span, TokenStream};
 use qs::{format_ident, quote, Ident, Index};
@@ -173,6 +175,7 @@ use thiserror::Error;
 pub mod bevy_util;
 mod bindgen;
 mod generate;
+mod layout_report;
 mod naga_util;
 mod quote_gen;
 mod structs;
@@ -188,9 +191,11 @@ pub mod qs {
 }
 
 pub use bindgen::*;
+pub use layout_report::WgslLayoutReport;
 pub use naga::FastIndexMap;
 pub use regex::Regex;
 pub use types::*;
+pub use wgpu::{VertexFormat, VertexStepMode};
 pub use wgsl_type::*;
 
 // Re-export ShaderDefValue for convenience
@@ -205,10 +210,22 @@ pub enum WgslTypeSerializeStrategy {
   #[default]
   Encase,
   Bytemuck,
+  /// Derives `zerocopy::{FromBytes, IntoBytes, Immutable, KnownLayout}` on the
+  /// generated structs. Structs ending in a runtime-sized array become
+  /// `KnownLayout` slice DSTs.
+  Zerocopy,
+}
+
+impl WgslTypeSerializeStrategy {
+  /// Whether generated structs are cast to bytes directly, which requires
+  /// explicit padding fields and layout assertions.
+  pub(crate) fn is_byte_castable(&self) -> bool {
+    matches!(self, Self::Bytemuck | Self::Zerocopy)
+  }
 }
 
 /// Errors while generating Rust source for a WGSl shader module.
-#[derive(Debug, Error)]
+#[derive(Debug, Error, miette::Diagnostic)]
 pub enum CreateModuleError {
   /// Bind group sets must be consecutive and start from 0.
   /// See `bind_group_layouts` for
@@ -220,6 +237,49 @@ pub enum CreateModuleError {
   #[error("duplicate binding found with index `{binding}`")]
   DuplicateBinding { binding: u32 },
 
+  /// Pipeline constants are passed to wgpu as `f64`, so overrides must have a
+  /// scalar type that converts to it losslessly.
+  #[error("override `{name}` has unsupported type `{ty}`")]
+  UnsupportedOverrideType { name: String, ty: String },
+
+  /// Structs bound as `var<uniform>` must follow the uniform address space
+  /// layout rules, which are stricter than those of storage buffers.
+  #[error("uniform binding `{binding}` has an invalid layout: field `{field}` {reason}")]
+  #[diagnostic(help("{help}"))]
+  UniformLayout {
+    binding: String,
+    field: String,
+    reason: String,
+    help: String,
+  },
+
+  /// Vertex formats are read by the shader as floats for normalized and float
+  /// formats, or as integers of the same signedness otherwise.
+  #[error(
+    "vertex attribute `{field}` of type `{ty}` cannot be read from format `{format}`"
+  )]
+  VertexFormatMismatch {
+    field: String,
+    format: String,
+    ty: String,
+  },
+
+  /// Padded fields get getters and setters named after them, which must not
+  /// clash with each other or with the other methods generated for the struct.
+  #[error(
+    "field `{field}` of struct `{struct_name}` would get an accessor `{method}`, which clashes with another method of the struct"
+  )]
+  PaddedFieldAccessorClash {
+    struct_name: String,
+    field: String,
+    method: String,
+  },
+
+  /// The vertex pulling library is WGSL generated from a vertex input struct,
+  /// which naga must be able to parse to lay out the pulled struct.
+  #[error("vertex pulling library of `{struct_name}` is invalid WGSL: {error}")]
+  VertexPullingLibrary { struct_name: String, error: String },
+
   #[error("duplicate content found `{0}`")]
   RustModuleBuilderError(#[from] quote_gen::RustModuleBuilderError),
 }
@@ -228,6 +288,7 @@ pub enum CreateModuleError {
 pub(crate) struct WgslEntryResult<'a> {
   mod_name: String,
   naga_module: naga::Module,
+  override_doc_comments: naga_util::OverrideDocComments,
   source_including_deps: SourceWithFullDependenciesResult<'a>,
 }
 
@@ -264,12 +325,26 @@ fn create_rust_bindings(
   let mut mod_builder = RustModBuilder::new(true, true);
 
   // Setup base type assertions if custom vector/matrix types are configured
-  if let Some(custom_wgsl_type_asserts) = custom_vector_matrix_assertions(options) {
+  let uses_f16 = entries.iter().any(|entry| {
+    wgsl::required_features(&entry.naga_module).contains(wgpu::Features::SHADER_F16)
+  });
+  if let Some(custom_wgsl_type_asserts) =
+    custom_vector_matrix_assertions(options, uses_f16)
+  {
     mod_builder.add(MOD_STRUCT_ASSERTIONS, custom_wgsl_type_asserts);
   }
 
   // Note: global methods are now added directly to ShaderEntry via shader_registry
 
+  // Buffer usages are merged across shaders, as a struct can be bound
+  // differently in each of them.
+  let struct_buffer_usages = structs::struct_buffer_usages(
+    entries
+      .iter()
+      .map(|entry| (entry.mod_name.as_str(), &entry.naga_module)),
+    options,
+  );
+
   // === PHASE 1: Generate basic components for each shader ===
   let mut all_shader_bind_groups = RawShadersBindGroups::new(options);
   let mut all_shader_vertex_inputs =
@@ -278,14 +353,26 @@ fn create_rust_bindings(
     let WgslEntryResult {
       mod_name,
       naga_module,
+      override_doc_comments,
       ..
     } = entry;
 
     // Generate core Rust types and constants from WGSL
-    mod_builder.add_items(structs::structs_items(mod_name, naga_module, options))?;
+    mod_builder.add_items(structs::structs_items(
+      mod_name,
+      naga_module,
+      options,
+      &struct_buffer_usages,
+    )?)?;
     mod_builder.add_items(consts::consts_items(mod_name, naga_module))?;
-    mod_builder
-      .add(mod_name, consts::pipeline_overridable_constants(naga_module, options));
+    mod_builder.add(
+      mod_name,
+      consts::pipeline_overridable_constants(
+        naga_module,
+        override_doc_comments,
+        options,
+      )?,
+    );
 
     // Collect vertex input structs (moved to Phase 2 for global deduplication)
     let shader_vertex_inputs =
@@ -296,15 +383,18 @@ fn create_rust_bindings(
     all_shader_vertex_inputs.add(shader_vertex_inputs);
 
     // Generate shader module creation functions
-    mod_builder.add(
-      mod_name,
-      shader_module::compute_module(naga_module, options.shader_source_type),
-    );
+    mod_builder.add(mod_name, shader_module::compute_module(naga_module, options));
     mod_builder.add(mod_name, entry_point_constants(naga_module));
 
     // Generate vertex and fragment state builders
-    mod_builder.add(mod_name, entry::vertex_states(mod_name, naga_module));
+    mod_builder.add(mod_name, entry::vertex_states(mod_name, naga_module, options));
+    mod_builder.add_items(entry::set_vertex_buffer_items(naga_module))?;
     mod_builder.add(mod_name, entry::fragment_states(naga_module));
+    mod_builder.add_items(immediates::set_immediates_items(
+      mod_name,
+      naga_module,
+      options,
+    ))?;
 
     // Collect bind group information for this shader
     let shader_stages = wgsl::shader_stages(naga_module);
@@ -323,7 +413,8 @@ fn create_rust_bindings(
   mod_builder.add_items(bind_groups)?;
 
   // Generate globally deduplicated vertex input implementations
-  let vertex_input_impls = all_shader_vertex_inputs.generate_vertex_input_impls();
+  let vertex_input_impls =
+    all_shader_vertex_inputs.generate_vertex_input_impls(options)?;
   mod_builder.add_items(vertex_input_impls)?;
 
   // === PHASE 3: Generate pipeline layouts and final shader modules ===
@@ -481,6 +572,7 @@ mod test {
     let entry = WgslEntryResult {
       mod_name: "test".into(),
       naga_module,
+      override_doc_comments: Default::default(),
       source_including_deps: SourceWithFullDependenciesResult {
         full_dependencies: Default::default(),
         source_file: &dummy_source,
@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use naga::{Handle, Override};
+use naga_oil::compose::preprocess::{
+  PreprocessOutput, Preprocessor, PreprocessorMetaData,
+};
+use naga_oil::compose::ShaderDefValue;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::bevy_util::{split_mangled_name, SourceWithFullDependenciesResult};
+use crate::FastIndexMap;
+
+/// Doc comments of WGSL `override` declarations, which naga's `DocComments`
+/// has no place for.
+pub type OverrideDocComments = FastIndexMap<Handle<Override>, Vec<String>>;
+
+/// `///` doc comments of the items declared in a single WGSL source file,
+/// keyed by the item names as written in that file.
+#[derive(Debug, Default)]
+struct SourceDocComments {
+  items: HashMap<String, Vec<String>>,
+  struct_members: HashMap<(String, String), Vec<String>>,
+}
+
+/// Prefix given to the entry's own items in the header, so they don't clash
+/// with the items of the file being parsed.
+const HEADER_ENTRY_ITEM_PREFIX: &str = "wgsl_bindgen_entry_";
+
+/// Writes the composed `module` back to WGSL, to be prepended to a source
+/// file so naga's front end can resolve the items it imports, like the
+/// headers naga_oil composes modules with.
+///
+/// Imported items keep their decorated names, which is what naga_oil's
+/// preprocessor substitutes at their uses.
+fn imports_header(module: &naga::Module) -> Option<String> {
+  let mut header = module.clone();
+  header.entry_points.clear();
+  header.doc_comments = None;
+
+  let rename = |name: &mut Option<String>| {
+    if let Some(name) = name
+      .as_mut()
+      .filter(|name| split_mangled_name(name).0.is_none())
+    {
+      *name = format!("{HEADER_ENTRY_ITEM_PREFIX}{name}");
+    }
+  };
+  // Types reference each other by handle, so they are reinserted in order.
+  let mut types = naga::UniqueArena::new();
+  for (_, ty) in module.types.iter() {
+    let mut ty = ty.clone();
+    rename(&mut ty.name);
+    types.insert(ty, naga::Span::UNDEFINED);
+  }
+  header.types = types;
+  for (_, constant) in header.constants.iter_mut() {
+    rename(&mut constant.name);
+  }
+  for (_, o) in header.overrides.iter_mut() {
+    rename(&mut o.name);
+  }
+  for (_, variable) in header.global_variables.iter_mut() {
+    rename(&mut variable.name);
+  }
+  for (_, function) in header.functions.iter_mut() {
+    rename(&mut function.name);
+  }
+
+  let info = naga::valid::Validator::new(
+    naga::valid::ValidationFlags::empty(),
+    naga::valid::Capabilities::all(),
+  )
+  .validate(&header)
+  .ok()?;
+  naga::back::wgsl::write_string(
+    &header,
+    &info,
+    naga::back::wgsl::WriterFlags::EXPLICIT_TYPES,
+  )
+  .ok()
+}
+
+/// Doc comments of the `override` declared on the line containing `offset`.
+/// naga parses them but has nowhere to keep them, so the lines before the
+/// declaration are read like naga's lexer does, stepping over attributes.
+fn override_doc_comments(source: &str, offset: usize) -> Vec<String> {
+  let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+  let mut lines = Vec::new();
+  for line in source[..line_start].lines().rev().map(str::trim) {
+    let is_attributes = line.starts_with('@') && !line.contains([';', '{', '}']);
+    if line.starts_with("///") {
+      lines.push(line.to_string());
+    } else if !(line.is_empty() || line.starts_with("//") || is_attributes) {
+      break;
+    }
+  }
+  lines.reverse();
+  lines
+}
+
+impl SourceDocComments {
+  /// Parses `content` with naga's WGSL front end, which collects the doc
+  /// comments, once naga_oil's preprocessor resolved its directives and
+  /// imports. `header` declares the imported items, see [imports_header].
+  ///
+  /// Returns `None` if naga cannot parse the file on its own, e.g. when it
+  /// declares naga_oil's `virtual` functions.
+  fn parse(
+    content: &str,
+    header: &str,
+    shader_defs: &HashMap<String, ShaderDefValue>,
+  ) -> Option<Self> {
+    let preprocessor = Preprocessor::default();
+    let PreprocessorMetaData {
+      defines,
+      wgsl_directives,
+      cleaned_source,
+      ..
+    } = preprocessor.get_preprocessor_metadata(content, true).ok()?;
+    let mut shader_defs = shader_defs.clone();
+    shader_defs.extend(defines);
+    let PreprocessOutput {
+      preprocessed_source,
+      ..
+    } = preprocessor
+      .preprocess(&cleaned_source, &shader_defs)
+      .ok()?;
+    let source =
+      format!("{}{header}{preprocessed_source}", wgsl_directives.to_wgsl_string());
+
+    let module =
+      naga::front::wgsl::Frontend::new_with_options(naga::front::wgsl::Options {
+        parse_doc_comments: true,
+        capabilities: naga::valid::Capabilities::all(),
+      })
+      .parse(&source)
+      .ok()?;
+
+    // Items from the header carry decorated names, so only the file's own
+    // items are collected.
+    let own_name = |name: Option<&String>| {
+      name
+        .filter(|name| split_mangled_name(name).0.is_none())
+        .cloned()
+    };
+    let mut docs = Self::default();
+    for (handle, o) in module.overrides.iter() {
+      let (Some(name), Some(span)) =
+        (own_name(o.name.as_ref()), module.overrides.get_span(handle).to_range())
+      else {
+        continue;
+      };
+      let lines = override_doc_comments(&source, span.start);
+      if !lines.is_empty() {
+        docs.items.insert(name, lines);
+      }
+    }
+    let Some(doc_comments) = module.doc_comments.as_deref() else {
+      return Some(docs);
+    };
+
+    let mut insert_item = |name: Option<&String>, lines: &Vec<String>| {
+      if let Some(name) = own_name(name) {
+        docs.items.insert(name, lines.clone());
+      }
+    };
+    for (handle, lines) in &doc_comments.types {
+      insert_item(module.types[*handle].name.as_ref(), lines);
+    }
+    for (handle, lines) in &doc_comments.constants {
+      insert_item(module.constants[*handle].name.as_ref(), lines);
+    }
+    for (handle, lines) in &doc_comments.global_variables {
+      insert_item(module.global_variables[*handle].name.as_ref(), lines);
+    }
+    for (handle, lines) in &doc_comments.functions {
+      insert_item(module.functions[*handle].name.as_ref(), lines);
+    }
+    for (index, lines) in &doc_comments.entry_points {
+      insert_item(Some(&module.entry_points[*index].name), lines);
+    }
+    // naga keys the doc comments of struct members by their index among the
+    // documented members only, so each is matched to the member that follows
+    // it in the struct's declaration.
+    let mut documented_members = FastIndexMap::<_, Vec<(usize, &Vec<String>)>>::default();
+    for ((handle, index), lines) in &doc_comments.struct_members {
+      documented_members
+        .entry(*handle)
+        .or_default()
+        .push((*index, lines));
+    }
+    for (handle, mut member_docs) in documented_members {
+      let ty = &module.types[handle];
+      let (Some(struct_name), Some(span)) =
+        (own_name(ty.name.as_ref()), module.types.get_span(handle).to_range())
+      else {
+        continue;
+      };
+      member_docs.sort_by_key(|(index, _)| *index);
+      let mut rest = &source[span];
+      for (_, lines) in member_docs {
+        let Some(last_line) = lines.last() else {
+          continue;
+        };
+        let Some(at) = rest.find(last_line.as_str()) else {
+          break;
+        };
+        rest = &rest[at + last_line.len()..];
+        let declaration = rest.split(':').next().unwrap_or_default().trim_end();
+        let member_name = declaration
+          .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+          .next()
+          .unwrap_or_default();
+        docs
+          .struct_members
+          .insert((struct_name.clone(), member_name.to_string()), lines.clone());
+      }
+    }
+
+    Some(docs)
+  }
+}
+
+/// Copies the WGSL doc comments of an entry and its dependencies onto the
+/// composed naga module.
+///
+/// Items are matched by name. Items naga_oil mangled while importing them are
+/// looked up in the dependency whose module name they carry, falling back to
+/// the first dependency that documents an item of that name.
+pub fn attach_doc_comments(
+  module: &mut naga::Module,
+  entry: &SourceWithFullDependenciesResult<'_>,
+  shader_defs: &HashMap<String, ShaderDefValue>,
+) -> OverrideDocComments {
+  let header = imports_header(module).unwrap_or_default();
+  let parse = |content: &str| {
+    SourceDocComments::parse(content, &header, shader_defs).unwrap_or_default()
+  };
+  let entry_docs = parse(&entry.source_file.content);
+  let dependency_docs = entry
+    .full_dependencies
+    .iter()
+    .map(|dependency| {
+      let module_name = dependency.module_name.as_ref().map(|name| name.to_string());
+      (module_name, parse(&dependency.content))
+    })
+    .collect::<Vec<_>>();
+
+  let source_docs_for = |mangled_name: &str| -> Option<(&SourceDocComments, String)> {
+    match split_mangled_name(mangled_name) {
+      (None, name) => Some((&entry_docs, name.to_string())),
+      (Some(module_name), name) => dependency_docs
+        .iter()
+        .find(|(dependency_name, _)| dependency_name.as_deref() == Some(&module_name))
+        .or_else(|| {
+          dependency_docs
+            .iter()
+            .find(|(_, docs)| docs.items.contains_key(name))
+        })
+        .map(|(_, docs)| (docs, name.to_string())),
+    }
+  };
+  let item_docs = |mangled_name: Option<&String>| -> Option<Vec<String>> {
+    let (docs, name) = source_docs_for(mangled_name?)?;
+    docs.items.get(&name).cloned()
+  };
+
+  let mut doc_comments = module.doc_comments.take().unwrap_or_default();
+
+  for (handle, ty) in module.types.iter() {
+    if let Some(docs) = item_docs(ty.name.as_ref()) {
+      doc_comments.types.entry(handle).or_insert(docs);
+    }
+    if let (naga::TypeInner::Struct { members, .. }, Some(name)) = (&ty.inner, &ty.name) {
+      let Some((docs, struct_name)) = source_docs_for(name) else {
+        continue;
+      };
+      for (index, member) in members.iter().enumerate() {
+        let Some(member_name) = &member.name else {
+          continue;
+        };
+        if let Some(member_docs) = docs
+          .struct_members
+          .get(&(struct_name.clone(), member_name.clone()))
+        {
+          doc_comments
+            .struct_members
+            .entry((handle, index))
+            .or_insert_with(|| member_docs.clone());
+        }
+      }
+    }
+  }
+  for (handle, constant) in module.constants.iter() {
+    if let Some(docs) = item_docs(constant.name.as_ref()) {
+      doc_comments.constants.entry(handle).or_insert(docs);
+    }
+  }
+  for (handle, variable) in module.global_variables.iter() {
+    if let Some(docs) = item_docs(variable.name.as_ref()) {
+      doc_comments.global_variables.entry(handle).or_insert(docs);
+    }
+  }
+  for (handle, function) in module.functions.iter() {
+    if let Some(docs) = item_docs(function.name.as_ref()) {
+      doc_comments.functions.entry(handle).or_insert(docs);
+    }
+  }
+  for (index, entry_point) in module.entry_points.iter().enumerate() {
+    if let Some(docs) = entry_docs.items.get(&entry_point.name) {
+      doc_comments
+        .entry_points
+        .entry(index)
+        .or_insert_with(|| docs.clone());
+    }
+  }
+
+  let is_empty = doc_comments.types.is_empty()
+    && doc_comments.struct_members.is_empty()
+    && doc_comments.constants.is_empty()
+    && doc_comments.global_variables.is_empty()
+    && doc_comments.functions.is_empty()
+    && doc_comments.entry_points.is_empty()
+    && doc_comments.module.is_empty();
+  if !is_empty {
+    module.doc_comments = Some(doc_comments);
+  }
+
+  module
+    .overrides
+    .iter()
+    .filter_map(|(handle, o)| Some((handle, item_docs(o.name.as_ref())?)))
+    .collect()
+}
+
+/// Converts WGSL doc comment lines, as retained by naga, into `#[doc]`
+/// attributes.
+pub fn quote_doc_comments(lines: Option<&Vec<String>>) -> TokenStream {
+  let docs = lines
+    .into_iter()
+    .flatten()
+    .flat_map(|line| {
+      if let Some(block) = line.strip_prefix("/**") {
+        let block = block.strip_suffix("*/").unwrap_or(block);
+        block
+          .lines()
+          .map(|line| {
+            let line = line.trim_start();
+            line.strip_prefix('*').unwrap_or(line).to_string()
+          })
+          .collect::<Vec<_>>()
+      } else {
+        vec![line.strip_prefix("///").unwrap_or(line).to_string()]
+      }
+    })
+    .map(|doc| quote!(#[doc = #doc]));
+  quote!(#(#docs)*)
+}
+
+/// Doc attributes for a WGSL struct.
+pub fn type_doc_comments(
+  module: &naga::Module,
+  handle: Handle<naga::Type>,
+) -> TokenStream {
+  let docs = module.doc_comments.as_ref();
+  quote_doc_comments(docs.and_then(|docs| docs.types.get(&handle)))
+}
+
+/// Doc attributes for the member at `index` of a WGSL struct.
+pub fn struct_member_doc_comments(
+  module: &naga::Module,
+  handle: Handle<naga::Type>,
+  index: usize,
+) -> TokenStream {
+  let docs = module.doc_comments.as_ref();
+  quote_doc_comments(docs.and_then(|docs| docs.struct_members.get(&(handle, index))))
+}
+
+/// Doc attributes for a WGSL `const`.
+pub fn constant_doc_comments(
+  module: &naga::Module,
+  handle: Handle<naga::Constant>,
+) -> TokenStream {
+  let docs = module.doc_comments.as_ref();
+  quote_doc_comments(docs.and_then(|docs| docs.constants.get(&handle)))
+}
+
+/// Doc attributes for the WGSL entry point at `index`.
+pub fn entry_point_doc_comments(module: &naga::Module, index: usize) -> TokenStream {
+  let docs = module.doc_comments.as_ref();
+  quote_doc_comments(docs.and_then(|docs| docs.entry_points.get(&index)))
+}
+
+#[cfg(test)]
+mod tests {
+  use indoc::indoc;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn parse_source_doc_comments() {
+    let source = indoc! {r#"
+      #define_import_path types
+
+      /// Per frame data.
+      ///
+      /// Shared by every pass.
+      struct Globals {
+        /// Seconds since startup.
+        time: f32,
+        // Not a doc comment.
+        frame: u32,
+        /// Where the light comes from.
+        @align(16) light_dir: vec3<f32>,
+      };
+
+      /// Maximum number of lights.
+      const MAX_LIGHTS: u32 = 4u;
+
+      /// Shadow softness.
+      @id(0) override softness: f32 = 0.5;
+
+      /// Frame uniforms.
+      @group(0) @binding(0)
+      var<uniform> globals: Globals;
+
+      /// Fullscreen vertex shader.
+      @vertex
+      fn vs_main() -> @builtin(position) vec4<f32> {
+        /// Not an item either.
+        var time = globals.time;
+        return vec4<f32>(time);
+      }
+    "#};
+
+    let docs = SourceDocComments::parse(source, "", &HashMap::new()).unwrap();
+    let item = |name: &str| docs.items.get(name).cloned().unwrap_or_default();
+    let member = |name: &str| {
+      docs
+        .struct_members
+        .get(&("Globals".to_string(), name.to_string()))
+        .cloned()
+        .unwrap_or_default()
+    };
+
+    assert_eq!(
+      item("Globals"),
+      ["/// Per frame data.", "///", "/// Shared by every pass."]
+    );
+    assert_eq!(member("time"), ["/// Seconds since startup."]);
+    assert_eq!(member("frame"), Vec::<String>::new());
+    assert_eq!(member("light_dir"), ["/// Where the light comes from."]);
+    assert_eq!(item("MAX_LIGHTS"), ["/// Maximum number of lights."]);
+    assert_eq!(item("softness"), ["/// Shadow softness."]);
+    assert_eq!(item("globals"), ["/// Frame uniforms."]);
+    assert_eq!(item("vs_main"), ["/// Fullscreen vertex shader."]);
+    assert_eq!(item("time"), Vec::<String>::new());
+  }
+}
@@ -1,2 +1,4 @@
+mod doc_comments;
 mod module_to_source;
+pub use doc_comments::*;
 pub use module_to_source::*;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 // https://github.com/LucentFlux/naga-to-tokenstream/blob/main/src/lib.rs#L26
 pub fn module_to_source(
   module: &naga::Module,
@@ -22,11 +24,21 @@ pub fn module_to_source(
 
   // Write to wgsl
   let info = info.unwrap();
-  let src = naga::back::wgsl::write_string(
+  let override_names = override_names(&module);
+  let placeholders = replace_override_sized_array_lengths(&mut module);
+  let mut src = naga::back::wgsl::write_string(
     &module,
     &info,
     naga::back::wgsl::WriterFlags::empty(),
   )?;
+  for (placeholder, length_override) in placeholders {
+    let override_name = &override_names[&length_override];
+    src = replace_array_length(&src, placeholder, override_name).ok_or_else(|| {
+      naga::back::wgsl::Error::Custom(format!(
+        "The length of an array sized by override `{override_name}` was not written as expected"
+      ))
+    })?;
+  }
 
   // Remove whitespace if minifying
   #[cfg(feature = "minify")]
@@ -37,3 +49,121 @@ pub fn module_to_source(
 
   Ok(src)
 }
+
+/// The names naga's WGSL writer gives the overrides of `module`, which differ
+/// from their declared names when those collide with WGSL keywords.
+fn override_names(
+  module: &naga::Module,
+) -> HashMap<naga::Handle<naga::Override>, String> {
+  let mut names = naga::FastHashMap::default();
+  naga::proc::Namer::default().reset(
+    module,
+    &naga::keywords::wgsl::RESERVED_SET,
+    &naga::keywords::wgsl::BUILTIN_IDENTIFIER_SET,
+    naga::proc::CaseInsensitiveKeywordSet::empty(),
+    &["__", "_naga"],
+    &mut names,
+  );
+  module
+    .overrides
+    .iter()
+    .map(|(handle, _)| (handle, names[&naga::proc::NameKey::Override(handle)].clone()))
+    .collect()
+}
+
+/// naga's WGSL writer drops the element type of override-sized arrays, so
+/// their lengths are swapped for placeholder lengths, one per array type and
+/// larger than any array naga validates, before writing. The writer's name of
+/// the override is substituted back for each placeholder.
+fn replace_override_sized_array_lengths(
+  module: &mut naga::Module,
+) -> Vec<(u32, naga::Handle<naga::Override>)> {
+  let pending_arrays = module
+    .types
+    .iter()
+    .filter_map(|(handle, ty)| match ty.inner {
+      naga::TypeInner::Array {
+        base,
+        size: naga::ArraySize::Pending(length_override),
+        stride,
+      } => Some((handle, base, length_override, stride)),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  pending_arrays
+    .into_iter()
+    .enumerate()
+    .map(|(index, (handle, base, length_override, stride))| {
+      let placeholder = u32::MAX - index as u32;
+      let ty = naga::Type {
+        name: module.types[handle].name.clone(),
+        inner: naga::TypeInner::Array {
+          base,
+          size: naga::ArraySize::Constant(placeholder.try_into().unwrap()),
+          stride,
+        },
+      };
+      module.types.replace(handle, ty);
+      (placeholder, length_override)
+    })
+    .collect()
+}
+
+/// Replaces `placeholder` with `override_name` where it is the length of an
+/// `array<T, placeholder>` type in `src`, leaving any other occurrence alone.
+/// `None` if no array type has that length.
+fn replace_array_length(
+  src: &str,
+  placeholder: u32,
+  override_name: &str,
+) -> Option<String> {
+  let written = format!(", {placeholder}>");
+  let mut replaced = String::with_capacity(src.len());
+  let mut rest = src;
+  let mut found = false;
+  while let Some(index) = rest.find(&written) {
+    let (before, after) = rest.split_at(index);
+    replaced.push_str(before);
+    if is_array_length(&replaced) {
+      replaced.push_str(&format!(", {override_name}>"));
+      found = true;
+    } else {
+      replaced.push_str(&written);
+    }
+    rest = &after[written.len()..];
+  }
+  replaced.push_str(rest);
+  found.then_some(replaced)
+}
+
+/// Whether the last unclosed `<` of `src` opens an `array` type.
+fn is_array_length(src: &str) -> bool {
+  let mut depth = 0usize;
+  for (index, c) in src.char_indices().rev() {
+    match c {
+      '>' => depth += 1,
+      '<' if depth == 0 => return src[..index].ends_with("array"),
+      '<' => depth -= 1,
+      _ => {}
+    }
+  }
+  false
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn replace_only_array_lengths() {
+    let src = "var<workgroup> a: array<array<f32, 4>, 7>;\nfn f() -> vec2<u32> { return vec2<u32>(1u, 7); }\nvar<workgroup> b: mat2x2<f32, 7>;";
+    assert_eq!(
+      replace_array_length(src, 7, "block_size").unwrap(),
+      "var<workgroup> a: array<array<f32, 4>, block_size>;\nfn f() -> vec2<u32> { return vec2<u32>(1u, 7); }\nvar<workgroup> b: mat2x2<f32, 7>;"
+    );
+    assert_eq!(replace_array_length("var<private> b: vec2<u32, 7>;", 7, "n"), None);
+  }
+}
@@ -4,6 +4,11 @@ use proc_macro2::Ident;
 pub(crate) const MOD_REFERENCE_ROOT: &str = "_root";
 pub(crate) const MOD_STRUCT_ASSERTIONS: &str = "layout_asserts";
 pub(crate) const MOD_BYTEMUCK_IMPLS: &str = "bytemuck_impls";
+pub(crate) const MOD_LAYOUT_TESTS: &str = "layout_tests";
+pub(crate) const MOD_UNIFORM_RING: &str = "uniform_ring";
+pub(crate) const MOD_TYPED_BUFFER: &str = "typed_buffer";
+pub(crate) const MOD_TYPED_TEXTURE: &str = "typed_texture";
+pub(crate) const MOD_ENCASE_BYTES: &str = "encase_bytes";
 
 pub(crate) fn mod_reference_root() -> Ident {
   unsafe { syn::parse_str(MOD_REFERENCE_ROOT).unwrap_unchecked() }
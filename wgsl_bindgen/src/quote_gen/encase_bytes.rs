@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::quote_gen::{
+  RustSourceItem, RustSourceItemCategory, RustSourceItemPath, MOD_ENCASE_BYTES,
+};
+
+/// The `encase_bytes` module's functions writing values with encase, shared by
+/// the generated buffer writers.
+pub(crate) fn encase_bytes_item() -> RustSourceItem {
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeImpls.into(),
+    RustSourceItemPath::new(MOD_ENCASE_BYTES.into(), "storage_bytes".into()),
+    quote! {
+      /// The bytes of `value` with its WGSL layout in a storage buffer.
+      pub fn storage_bytes<T>(value: &T) -> encase::internal::Result<Vec<u8>>
+      where
+        T: ?Sized + encase::ShaderType + encase::internal::WriteInto,
+      {
+        let mut buffer = encase::StorageBuffer::new(Vec::<u8>::new());
+        buffer.write(value)?;
+        Ok(buffer.into_inner())
+      }
+
+      /// The bytes of `value` with its WGSL layout in a uniform buffer. encase
+      /// panics if the type breaks the uniform address space layout rules.
+      pub fn uniform_bytes<T>(value: &T) -> encase::internal::Result<Vec<u8>>
+      where
+        T: ?Sized + encase::ShaderType + encase::internal::WriteInto,
+      {
+        let mut buffer = encase::UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(value)?;
+        Ok(buffer.into_inner())
+      }
+    },
+  )
+}
+
+/// The bytes of `value` through the generated `encase_bytes` module, with the
+/// uniform layout when the value is bound as a uniform. The buffers grow to
+/// fit the value, so writing cannot fail.
+pub(crate) fn quote_encase_bytes(value: TokenStream, uniform: bool) -> TokenStream {
+  let write = if uniform {
+    quote!(encase_bytes::uniform_bytes)
+  } else {
+    quote!(encase_bytes::storage_bytes)
+  };
+  quote!(&#write(#value).expect("encase grows a Vec<u8> to fit the value"))
+}
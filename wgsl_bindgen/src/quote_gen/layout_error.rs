@@ -0,0 +1,36 @@
+use quote::quote;
+
+use crate::quote_gen::{RustSourceItem, RustSourceItemCategory, RustSourceItemPath};
+
+/// The error of the generated `from_bytes` helpers.
+pub(crate) fn layout_error_item(module: &str) -> RustSourceItem {
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs | RustSourceItemCategory::TraitImpls,
+    RustSourceItemPath::new(module.into(), "LayoutError".into()),
+    quote! {
+      /// Why bytes could not be read as a struct with its WGSL layout.
+      #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+      pub enum LayoutError {
+        /// There are fewer bytes than the struct needs.
+        TooShort { expected: usize, actual: usize },
+        /// The bytes do not start at a multiple of the struct alignment.
+        Misaligned { alignment: usize },
+      }
+
+      impl ::core::fmt::Display for LayoutError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          match self {
+            Self::TooShort { expected, actual } => {
+              write!(f, "expected at least {expected} bytes, got {actual}")
+            }
+            Self::Misaligned { alignment } => {
+              write!(f, "bytes are not aligned to {alignment}")
+            }
+          }
+        }
+      }
+
+      impl std::error::Error for LayoutError {}
+    },
+  )
+}
@@ -0,0 +1,180 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::quote_gen::{
+  RustSourceItem, RustSourceItemCategory, RustSourceItemPath, MOD_LAYOUT_TESTS,
+};
+use crate::{WgslBindgenOption, WgslTypeSerializeStrategy};
+
+/// The helpers shared by the generated layout tests.
+pub(crate) fn layout_test_helper_item(options: &WgslBindgenOption) -> RustSourceItem {
+  let sentinel = match options.serialization_strategy {
+    WgslTypeSerializeStrategy::Bytemuck => quote! {
+      fn sentinel<T: bytemuck::Pod>(bytes: &[u8]) -> T {
+        let mut value = T::zeroed();
+        let len = bytes.len().min(::core::mem::size_of::<T>());
+        bytemuck::bytes_of_mut(&mut value)[..len].copy_from_slice(&bytes[..len]);
+        value
+      }
+    },
+    WgslTypeSerializeStrategy::Zerocopy => quote! {
+      fn sentinel<T: zerocopy::FromBytes + zerocopy::IntoBytes>(bytes: &[u8]) -> T {
+        let mut value = T::new_zeroed();
+        let len = bytes.len().min(::core::mem::size_of::<T>());
+        value.as_mut_bytes()[..len].copy_from_slice(&bytes[..len]);
+        value
+      }
+    },
+    WgslTypeSerializeStrategy::Encase => quote! {
+      fn sentinel<T: encase::ShaderType + encase::internal::CreateFrom>(bytes: &[u8]) -> T {
+        let mut bytes = bytes.to_vec();
+        bytes.resize(bytes.len().max(T::min_size().get() as usize), 0);
+        encase::StorageBuffer::new(bytes).create().unwrap()
+      }
+    },
+  };
+
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeImpls.into(),
+    RustSourceItemPath::new(MOD_LAYOUT_TESTS.into(), "sentinel".into()),
+    quote! {
+      /// Reads a field value from its WGSL bytes, zero-extended to the size
+      /// of the Rust type.
+      #sentinel
+
+      fn assert_field_bytes(bytes: &[u8], field: &str, offset: usize, expected: &[u8]) {
+        assert_eq!(
+          bytes.get(offset..offset + expected.len()),
+          Some(expected),
+          "`{field}` does not match its WGSL layout at offset {offset}",
+        );
+      }
+    },
+  )
+}
+
+pub(super) fn skipped_field_doc(member_name: &str) -> String {
+  format!(
+    "`{member_name}` has no host-shareable layout, so it is left zeroed and not checked."
+  )
+}
+
+/// The WGSL bytes of `ty` with a distinct value in every scalar, counting up
+/// from `next`, and zeroed padding. Returns `None` for types without a fixed
+/// host-shareable layout.
+pub(super) fn sentinel_bytes(
+  module: &naga::Module,
+  ty: naga::Handle<naga::Type>,
+  next: &mut u32,
+) -> Option<Vec<u8>> {
+  let mut bytes = vec![0; module.types[ty].inner.size(module.to_ctx()) as usize];
+  write_sentinel_bytes(module, ty, next, &mut bytes)?;
+  Some(bytes)
+}
+
+fn write_sentinel_bytes(
+  module: &naga::Module,
+  ty: naga::Handle<naga::Type>,
+  next: &mut u32,
+  bytes: &mut [u8],
+) -> Option<()> {
+  match module.types[ty].inner {
+    naga::TypeInner::Scalar(scalar) | naga::TypeInner::Atomic(scalar) => {
+      write_sentinel_scalar(scalar, next, bytes)
+    }
+    naga::TypeInner::Vector { size, scalar } => {
+      (0..size as usize).try_for_each(|index| {
+        let offset = index * scalar.width as usize;
+        write_sentinel_scalar(scalar, next, &mut bytes[offset..])
+      })
+    }
+    naga::TypeInner::Matrix {
+      columns,
+      rows,
+      scalar,
+    } => {
+      let column_stride = naga::proc::Alignment::from(rows) * scalar.width as u32;
+      (0..columns as usize).try_for_each(|column| {
+        (0..rows as usize).try_for_each(|row| {
+          let offset = column * column_stride as usize + row * scalar.width as usize;
+          write_sentinel_scalar(scalar, next, &mut bytes[offset..])
+        })
+      })
+    }
+    naga::TypeInner::Array {
+      base,
+      size: naga::ArraySize::Constant(count),
+      stride,
+    } => (0..count.get() as usize).try_for_each(|index| {
+      let offset = index * stride as usize;
+      write_sentinel_bytes(module, base, next, &mut bytes[offset..])
+    }),
+    naga::TypeInner::Struct { ref members, .. } => {
+      members.iter().try_for_each(|member| {
+        write_sentinel_bytes(
+          module,
+          member.ty,
+          next,
+          &mut bytes[member.offset as usize..],
+        )
+      })
+    }
+    _ => None,
+  }
+}
+
+/// The bits of `1.0` as an `f16`.
+const F16_ONE_BITS: u16 = 0x3C00;
+/// The number of finite `f16` values from `1.0` up to `+inf`.
+const F16_FINITE_STEPS: u32 = 0x7C00 - F16_ONE_BITS as u32;
+
+fn write_sentinel_scalar(
+  scalar: naga::Scalar,
+  next: &mut u32,
+  bytes: &mut [u8],
+) -> Option<()> {
+  let value = *next;
+  *next = next.wrapping_add(1);
+  // Floats get small whole numbers (or, for `f16`, steps above 1.0) so no
+  // sentinel is a NaN whose bits could change on a copy. `f16` bits from
+  // 0x3C00 (1.0) up to 0x7C00 (+inf) are all finite, so its sentinels repeat
+  // after that many scalars.
+  let value_bytes = match (scalar.kind, scalar.width) {
+    (naga::ScalarKind::Float, 2) => {
+      let steps = (value % F16_FINITE_STEPS) as u16;
+      (F16_ONE_BITS + steps).to_le_bytes().to_vec()
+    }
+    (naga::ScalarKind::Float, 4) => (value as f32).to_le_bytes().to_vec(),
+    (naga::ScalarKind::Float, 8) => (value as f64).to_le_bytes().to_vec(),
+    (naga::ScalarKind::Sint | naga::ScalarKind::Uint, width) => {
+      u64::from(value).to_le_bytes()[..width as usize].to_vec()
+    }
+    _ => return None,
+  };
+  bytes[..value_bytes.len()].copy_from_slice(&value_bytes);
+  Some(())
+}
+
+/// A `&[u8]` literal, written without suffixes.
+pub(super) fn byte_slice_literal(bytes: &[u8]) -> TokenStream {
+  let bytes = bytes
+    .iter()
+    .map(|byte| proc_macro2::Literal::u8_unsuffixed(*byte));
+  quote!(&[#(#bytes),*])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn f16_sentinels_stay_finite() {
+    let mut next = 1;
+    let mut bytes = [0; 2];
+    for _ in 0..3 * F16_FINITE_STEPS {
+      write_sentinel_scalar(naga::Scalar::F16, &mut next, &mut bytes).unwrap();
+      let bits = u16::from_le_bytes(bytes);
+      assert!((F16_ONE_BITS..0x7C00).contains(&bits), "{bits:#x} is not finite");
+    }
+  }
+}
@@ -1,19 +1,29 @@
 mod constants;
+mod encase_bytes;
+mod layout_error;
+mod layout_tests;
 mod rust_module_builder;
 mod rust_source_item;
 mod rust_struct_builder;
 mod rust_type_info;
 mod token_utils;
+mod typed_buffer;
+mod uniform_ring;
 
 use core::panic;
 
 pub(crate) use constants::*;
+pub(crate) use encase_bytes::*;
+pub(crate) use layout_error::*;
+pub(crate) use layout_tests::layout_test_helper_item;
 use proc_macro2::TokenStream;
 pub(crate) use rust_module_builder::*;
 pub(crate) use rust_source_item::*;
 pub(crate) use rust_struct_builder::*;
 pub(crate) use rust_type_info::*;
 pub(crate) use token_utils::*;
+pub(crate) use typed_buffer::*;
+pub(crate) use uniform_ring::*;
 
 use crate::bevy_util::demangle_str;
 
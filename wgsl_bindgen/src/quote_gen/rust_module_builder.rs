@@ -8,7 +8,7 @@ use smallvec::SmallVec;
 use syn::Ident;
 use thiserror::Error;
 
-use super::constants::MOD_REFERENCE_ROOT;
+use super::constants::{MOD_LAYOUT_TESTS, MOD_REFERENCE_ROOT};
 use super::{RustSourceItem, RustSourceItemCategory};
 use crate::pretty_print;
 use crate::quote_gen::constants::mod_reference_root;
@@ -160,6 +160,15 @@ pub struct RustModBuilderConfig {
 
 impl RustModBuilderConfig {
   fn build_module(&self, mod_name: &str) -> RustModule {
+    let mut module = self.build_module_contents(mod_name);
+    // Generated layout tests are only compiled by `cargo test`.
+    if mod_name == MOD_LAYOUT_TESTS {
+      module.module_attributes = quote!(#[cfg(test)]);
+    }
+    module
+  }
+
+  fn build_module_contents(&self, mod_name: &str) -> RustModule {
     if self.use_relative_root {
       // this helps import relative items for nested mods under this root
       // https://discord.com/channels/442252698964721669/448238009733742612/1207323647203868712
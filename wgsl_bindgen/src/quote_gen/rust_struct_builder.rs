@@ -1,5 +1,7 @@
 // See also: https://webgpufundamentals.org/webgpu/lessons/resources/wgsl-offset-computer.html
 
+use std::collections::HashSet;
+
 use derive_more::IsVariant;
 use naga::common::wgsl::TypeContext;
 use naga::StructMember;
@@ -8,19 +10,25 @@ use quote::{format_ident, quote};
 use smol_str::SmolStr;
 use syn::{Ident, Index};
 
+use super::layout_tests::{byte_slice_literal, sentinel_bytes, skipped_field_doc};
 use super::{
-  rust_type, PaddedTypeInfo, RustSourceItem, RustSourceItemPath, RustTypeInfo,
-  RustTypeInitConversion,
+  encase_bytes_item, layout_error_item, layout_test_helper_item, quote_encase_bytes,
+  rust_type, typed_buffer_item, uniform_ring_item, PaddedTypeInfo, RustSourceItem,
+  RustSourceItemPath, RustTypeInfo, RustTypeInitConversion,
 };
 use crate::bevy_util::demangle_str;
+use crate::layout_report::StructLayoutReport;
+use crate::naga_util::{struct_member_doc_comments, type_doc_comments};
 use crate::quote_gen::{
   generate_derive_attributes, generate_doc_comment, generate_impl_block,
   generate_struct_definition, generate_struct_field, RustSourceItemCategory,
-  MOD_BYTEMUCK_IMPLS, MOD_STRUCT_ASSERTIONS,
+  MOD_BYTEMUCK_IMPLS, MOD_ENCASE_BYTES, MOD_LAYOUT_TESTS, MOD_STRUCT_ASSERTIONS,
+  MOD_TYPED_BUFFER, MOD_UNIFORM_RING,
 };
+use crate::structs::StructBufferUsage;
 use crate::{
-  sanitized_upper_snake_case, WgslBindgenOption, WgslType, WgslTypeSerializeStrategy,
-  WgslTypeVisibility,
+  sanitized_upper_snake_case, wgsl, CreateModuleError, WgslBindgenOption, WgslType,
+  WgslTypeSerializeStrategy, WgslTypeVisibility,
 };
 
 impl WgslTypeVisibility {
@@ -98,11 +106,25 @@ impl<'a> NagaToRustStructState<'a> {
       let name_ident = Ident::new(member_name, Span::call_site());
       let member_naga_type = &naga_module.types[naga_member.ty];
 
-      let rust_type_info = rust_type(None, naga_module, member_naga_type, options);
+      let mut rust_type_info = rust_type(None, naga_module, member_naga_type, options);
       let is_runtime_sized_array = rust_type_info.size.is_none();
       let (resolved_rust_type, rust_type_is_overridden) =
         Self::get_rust_type(options, &fully_qualified_name, &rust_type_info, member_name);
 
+      // A vertex attribute stored in another buffer format holds its raw
+      // components instead of the WGSL value.
+      let vertex_format =
+        wgsl::vertex_format_override(options, &fully_qualified_name, naga_member)
+          .filter(|_| !rust_type_is_overridden);
+      let (resolved_rust_type, rust_type_is_overridden) = match vertex_format {
+        Some(format) => {
+          rust_type_info.init_type = None;
+          rust_type_info.init_conversion = None;
+          (wgsl::vertex_format_rust_type(format), true)
+        }
+        None => (resolved_rust_type, rust_type_is_overridden),
+      };
+
       // Runtime-sized arrays can only be the last field in a struct
       if is_runtime_sized_array && state.index != naga_members.len() - 1 {
         panic!("Only the last field of a struct can be a runtime-sized array");
@@ -203,6 +225,7 @@ impl<'a> NagaToRustStructState<'a> {
           naga_member,
           naga_type: member_naga_type,
           naga_ty_handle: naga_member.ty,
+          member_index: state.index,
           rust_type: syn::Type::Verbatim(resolved_rust_type),
           init_type,
           init_conversion,
@@ -228,6 +251,7 @@ pub struct Field<'a> {
   pub naga_member: &'a naga::StructMember,
   pub naga_type: &'a naga::Type,
   pub naga_ty_handle: naga::Handle<naga::Type>,
+  pub member_index: usize,
   pub rust_type: syn::Type,
   pub init_type: Option<syn::Type>,
   pub init_conversion: Option<RustTypeInitConversion>,
@@ -377,22 +401,44 @@ impl<'a> RustStructMemberEntry<'a> {
 
 pub struct RustStructBuilder<'a> {
   item_path: &'a RustSourceItemPath,
+  naga_ty_handle: naga::Handle<naga::Type>,
   members: Vec<RustStructMemberEntry<'a>>,
   is_host_sharable: bool,
   has_rts_array: bool,
   naga_module: &'a naga::Module,
   layout: naga::proc::TypeLayout,
+  layout_report: Option<StructLayoutReport>,
+  buffer_usages: Option<wgpu::BufferUsages>,
+  has_dynamic_offset: bool,
   options: &'a WgslBindgenOption,
 }
 
 impl<'a> RustStructBuilder<'a> {
+  /// Documents the struct with a table of its WGSL member layout.
+  pub fn set_layout_report(&mut self, layout_report: StructLayoutReport) {
+    self.layout_report = Some(layout_report);
+  }
+
+  /// Generates buffer creation helpers for a struct bound with `buffer_usage`,
+  /// and packs it in a `UniformRing` when it is bound with dynamic offsets.
+  pub fn set_buffer_usages(&mut self, buffer_usage: StructBufferUsage) {
+    self.buffer_usages = Some(buffer_usage.usages);
+    self.has_dynamic_offset = buffer_usage.has_dynamic_offset;
+  }
+
   fn name_ident(&self) -> Ident {
     Ident::new(self.item_path.name.as_ref(), Span::call_site())
   }
 
   fn is_directly_shareable(&self) -> bool {
-    self.options.serialization_strategy == WgslTypeSerializeStrategy::Bytemuck
-      && self.is_host_sharable
+    self.options.serialization_strategy.is_byte_castable() && self.is_host_sharable
+  }
+
+  /// Zerocopy structs ending in a runtime-sized array are emitted as slice
+  /// DSTs rather than through the const-generic tail used for bytemuck.
+  fn is_zerocopy_dst(&self) -> bool {
+    self.has_rts_array
+      && self.options.serialization_strategy == WgslTypeSerializeStrategy::Zerocopy
   }
 
   fn uses_generics_for_rts(&self) -> bool {
@@ -411,6 +457,77 @@ impl<'a> RustStructBuilder<'a> {
     })
   }
 
+  fn runtime_tail_field(&self) -> Option<&Field<'a>> {
+    self.members.iter().find_map(|member| match member {
+      RustStructMemberEntry::Field(field) if field.is_rsa => Some(field.as_ref()),
+      _ => None,
+    })
+  }
+
+  fn runtime_tail_stride(tail: &Field<'a>) -> usize {
+    match &tail.naga_type.inner {
+      naga::TypeInner::Array { stride, .. } => *stride as usize,
+      _ => unreachable!("runtime array field must use an array type"),
+    }
+  }
+
+  /// zerocopy can only derive `IntoBytes` for a slice DST when no tail length
+  /// leaves trailing padding, i.e. when both the tail offset and its stride
+  /// are multiples of the struct alignment.
+  fn zerocopy_dst_is_padding_free(&self) -> bool {
+    let Some(tail) = self.runtime_tail_field() else {
+      return true;
+    };
+    let alignment = self.custom_alignment().unwrap_or(self.layout.alignment) * 1u32;
+    let alignment = alignment as usize;
+    (tail.naga_member.offset as usize).is_multiple_of(alignment)
+      && Self::runtime_tail_stride(tail).is_multiple_of(alignment)
+  }
+
+  fn custom_alignment(&self) -> Option<naga::proc::Alignment> {
+    let fully_qualified_name = self.item_path.get_fully_qualified_name();
+    self
+      .options
+      .override_struct_alignment
+      .iter()
+      .find_map(|struct_align| {
+        struct_align
+          .struct_regex
+          .is_match(fully_qualified_name.as_str())
+          .then_some(struct_align.alignment as u32)
+      })
+      .and_then(naga::proc::Alignment::new)
+  }
+
+  /// Appends the derives requested through `add_struct_derives`, skipping any
+  /// that are already generated.
+  fn extend_with_custom_derives<'s>(&'s self, derives: &mut Vec<&'s str>) {
+    let fully_qualified_name = self.item_path.get_fully_qualified_name();
+    let custom_derives = self
+      .options
+      .struct_derives
+      .iter()
+      .filter(|custom| custom.struct_regex.is_match(&fully_qualified_name))
+      .flat_map(|custom| custom.derives.iter());
+
+    for derive in custom_derives {
+      if !derives.contains(&derive.as_str()) {
+        derives.push(derive);
+      }
+    }
+  }
+
+  fn custom_attributes(&self) -> TokenStream {
+    let fully_qualified_name = self.item_path.get_fully_qualified_name();
+    let attributes = self
+      .options
+      .struct_attributes
+      .iter()
+      .filter(|custom| custom.struct_regex.is_match(&fully_qualified_name))
+      .map(|custom| &custom.attributes);
+    quote!(#(#attributes)*)
+  }
+
   fn sized_name_ident(&self) -> Ident {
     if self.uses_generics_for_rts() {
       format_ident!("{}Sized", self.item_path.name.as_str())
@@ -495,6 +612,7 @@ impl<'a> RustStructBuilder<'a> {
 
   fn build_init_struct(&self) -> TokenStream {
     if !self.is_directly_shareable()
+      || self.is_zerocopy_dst()
       || (!self.uses_padding() && !self.options.always_generate_init_struct)
     {
       return quote!();
@@ -515,8 +633,14 @@ impl<'a> RustStructBuilder<'a> {
     for entry in self.members.iter() {
       match entry {
         RustStructMemberEntry::Field(field) => {
-          init_struct_members
-            .push(field.generate_init_member_definition(self.uses_generics_for_rts()));
+          let doc_comment = struct_member_doc_comments(
+            self.naga_module,
+            self.naga_ty_handle,
+            field.member_index,
+          );
+          let member_definition =
+            field.generate_init_member_definition(self.uses_generics_for_rts());
+          init_struct_members.push(quote!(#doc_comment #member_definition));
           mem_assignments.push(field.generate_member_instantiate(&init_var_name));
         }
         RustStructMemberEntry::Padding(padding) => {
@@ -525,8 +649,10 @@ impl<'a> RustStructBuilder<'a> {
       }
     }
 
-    let init_derives =
-      generate_derive_attributes(&["Debug", "PartialEq", "Clone", "Copy"]);
+    let mut init_derives = vec!["Debug", "PartialEq", "Clone", "Copy"];
+    self.extend_with_custom_derives(&mut init_derives);
+    let init_derives = generate_derive_attributes(&init_derives);
+    let custom_attributes = self.custom_attributes();
     let build_method = quote! {
       pub fn build(&self) -> #struct_name_in_usage {
         #struct_name {
@@ -545,6 +671,7 @@ impl<'a> RustStructBuilder<'a> {
     quote! {
       #[repr(C)]
       #init_derives
+      #custom_attributes
       #visibility struct #init_struct_name_def {
         #(#init_struct_members),*
       }
@@ -826,6 +953,94 @@ impl<'a> RustStructBuilder<'a> {
     }
   }
 
+  /// Builds the constructor of a zerocopy slice DST, which is allocated zeroed
+  /// through `FromZeros` so the explicit padding needs no separate writes.
+  fn build_fn_new_zerocopy_dst(&self) -> TokenStream {
+    let struct_name = self.name_ident();
+    let tail = self
+      .runtime_tail_field()
+      .expect("runtime-sized struct must have a runtime array field");
+    let tail_name = &tail.name_ident;
+    let element_type = &tail.rust_type;
+    let input_element_type = tail.input_type();
+    let tail_stride = Index::from(Self::runtime_tail_stride(tail));
+    let tail_write = match &tail.init_conversion {
+      Some(conversion) => {
+        let converted = conversion.generate(quote!(value));
+        quote! {
+          for (destination, value) in __wgsl_bindgen_this
+            .#tail_name
+            .iter_mut()
+            .zip(#tail_name.iter().copied())
+          {
+            *destination = #converted;
+          }
+        }
+      }
+      None => quote! {
+        __wgsl_bindgen_this.#tail_name.copy_from_slice(#tail_name);
+      },
+    };
+
+    let mut fixed_params = Vec::new();
+    let mut field_writes = Vec::new();
+    for entry in &self.members {
+      if let RustStructMemberEntry::Field(field) = entry {
+        if field.is_rsa {
+          continue;
+        }
+        let name = &field.name_ident;
+        fixed_params.push(field.generate_fn_new_param(false));
+        let value = field.generate_input_to_target_conversion(quote!(#name));
+        field_writes.push(quote!(__wgsl_bindgen_this.#name = #value;));
+      }
+    }
+
+    let to_bytes = (!self.zerocopy_dst_is_padding_free()).then(|| {
+      let member_bytes = self.members.iter().map(|member| {
+        let name = match member {
+          RustStructMemberEntry::Field(field) => &field.name_ident,
+          RustStructMemberEntry::Padding(padding) => &padding.pad_name,
+        };
+        quote! {
+          __wgsl_bindgen_bytes.extend_from_slice(zerocopy::IntoBytes::as_bytes(&self.#name));
+        }
+      });
+      quote! {
+        /// Returns the bytes of every field and tail element. The struct
+        /// cannot derive `IntoBytes` because some tail lengths leave trailing
+        /// padding, so its fields and explicit padding are copied in order.
+        pub fn to_bytes(&self) -> Vec<u8> {
+          let mut __wgsl_bindgen_bytes = Vec::with_capacity(::core::mem::size_of_val(self));
+          #(#member_bytes)*
+          __wgsl_bindgen_bytes
+        }
+      }
+    });
+
+    quote! {
+      const _: () = {
+        assert!(::core::mem::size_of::<#element_type>() == #tail_stride);
+      };
+
+      impl #struct_name {
+        pub fn new(
+          #(#fixed_params,)*
+          #tail_name: &[#input_element_type],
+        ) -> Box<Self> {
+          let mut __wgsl_bindgen_this =
+            <Self as zerocopy::FromZeros>::new_box_zeroed_with_elems(#tail_name.len())
+              .expect("runtime-sized struct allocation is too large");
+          #(#field_writes)*
+          #tail_write
+          __wgsl_bindgen_this
+        }
+
+        #to_bytes
+      }
+    }
+  }
+
   fn build_fields(&self) -> Vec<TokenStream> {
     let naga_context = self.naga_module.to_ctx();
     let members = self
@@ -840,20 +1055,35 @@ impl<'a> RustStructBuilder<'a> {
             naga_member: member,
             naga_type,
             naga_ty_handle,
+            member_index,
             init_type: _,
             init_conversion: _,
           } = field.as_ref();
 
+          let wgsl_doc_comment = struct_member_doc_comments(
+            self.naga_module,
+            self.naga_ty_handle,
+            *member_index,
+          );
           let doc_comment = if self.is_directly_shareable() {
             let offset = member.offset;
             let size = naga_type.inner.size(naga_context);
             let ty_name = naga_context.type_to_string(*naga_ty_handle);
             let ty_name = demangle_str(&ty_name);
             let doc = format!("offset: {offset}, size: {size}, type: `{ty_name}`");
+            let layout_doc_comment = generate_doc_comment(&doc);
 
-            generate_doc_comment(&doc)
+            if wgsl_doc_comment.is_empty() {
+              layout_doc_comment
+            } else {
+              quote! {
+                #wgsl_doc_comment
+                #[doc = ""]
+                #layout_doc_comment
+              }
+            }
           } else {
-            quote!()
+            wgsl_doc_comment
           };
 
           let runtime_size_attribute = if *is_rts
@@ -868,6 +1098,8 @@ impl<'a> RustStructBuilder<'a> {
 
           let field_type = if *is_rts && self.uses_generics_for_rts() {
             quote!(Tail)
+          } else if *is_rts && self.is_zerocopy_dst() {
+            quote!([#rust_type])
           } else {
             quote!(#rust_type)
           };
@@ -886,7 +1118,11 @@ impl<'a> RustStructBuilder<'a> {
   }
 
   fn build_derives(&self) -> Vec<&str> {
-    let mut derives = vec!["Debug", "PartialEq", "Clone"];
+    let mut derives = vec!["Debug", "PartialEq"];
+    // Slice DSTs are unsized and therefore cannot be `Clone`.
+    if !self.is_zerocopy_dst() {
+      derives.push("Clone");
+    }
 
     match self.options.serialization_strategy {
       WgslTypeSerializeStrategy::Bytemuck => {
@@ -898,11 +1134,25 @@ impl<'a> RustStructBuilder<'a> {
         }
         derives.push("encase::ShaderType");
       }
+      WgslTypeSerializeStrategy::Zerocopy => {
+        if !self.has_rts_array {
+          derives.push("Copy");
+        }
+        derives.push("zerocopy::FromBytes");
+        if self.zerocopy_dst_is_padding_free() {
+          derives.push("zerocopy::IntoBytes");
+        }
+        derives.push("zerocopy::Immutable");
+        derives.push("zerocopy::KnownLayout");
+      }
     }
     if self.options.derive_serde {
       derives.push("serde::Serialize");
-      derives.push("serde::Deserialize");
+      if !self.is_zerocopy_dst() {
+        derives.push("serde::Deserialize");
+      }
     }
+    self.extend_with_custom_derives(&mut derives);
     derives
   }
 
@@ -964,6 +1214,9 @@ impl<'a> RustStructBuilder<'a> {
 
     for m in &self.members {
       match m {
+        // `offset_of!` cannot name the unsized tail of a slice DST.
+        RustStructMemberEntry::Field(field) if field.is_rsa && self.is_zerocopy_dst() => {
+        }
         RustStructMemberEntry::Field(field) => {
           let name =
             Ident::new(field.naga_member.name.as_ref().unwrap(), Span::call_site());
@@ -986,17 +1239,22 @@ impl<'a> RustStructBuilder<'a> {
       let expected_alignment = custom_alignment.unwrap_or(self.layout.alignment) * 1u32;
       let expected_alignment = Index::from(expected_alignment as usize);
 
-      let size_assertion = if let Some(tail) = self.runtime_array_field() {
-        if tail.has_init_type() {
-          // A converted tail element can contain nested local helper types, so
-          // its stride assertion is emitted beside the type alias.
+      // `align_of` requires a sized type, so slice DSTs only have their field
+      // offsets and tail stride checked.
+      let alignment_assertion = if self.is_zerocopy_dst() {
+        quote!()
+      } else {
+        quote!(assert!(std::mem::align_of::<#struct_name>() == #expected_alignment);)
+      };
+
+      let size_assertion = if let Some(tail) = self.runtime_tail_field() {
+        if tail.has_init_type() || self.is_zerocopy_dst() {
+          // A converted tail element can contain nested local helper types and
+          // a DST tail element is named relative to its module, so their
+          // stride assertion is emitted beside the constructor.
           quote!()
         } else {
-          let stride = match &tail.naga_type.inner {
-            naga::TypeInner::Array { stride, .. } => *stride,
-            _ => unreachable!("runtime array field must use an array type"),
-          };
-          let stride = Index::from(stride as usize);
+          let stride = Index::from(Self::runtime_tail_stride(tail));
           let rust_type = &tail.rust_type;
           quote! {
             assert!(std::mem::size_of::<#rust_type>() == #stride);
@@ -1027,7 +1285,7 @@ impl<'a> RustStructBuilder<'a> {
       quote! {
         const #assertion_name: () = {
           #(#assert_member_offsets)*
-          assert!(std::mem::align_of::<#struct_name>() == #expected_alignment);
+          #alignment_assertion
           #size_assertion
         };
       }
@@ -1036,6 +1294,857 @@ impl<'a> RustStructBuilder<'a> {
     }
   }
 
+  /// The fields with padded types, with their conversion and unpadded type.
+  fn padded_fields(
+    &self,
+  ) -> impl Iterator<Item = (&Field<'a>, &RustTypeInitConversion, &syn::Type)> {
+    self
+      .members
+      .iter()
+      .filter_map(|entry| match entry {
+        RustStructMemberEntry::Field(field) if !field.is_rsa => Some(field.as_ref()),
+        _ => None,
+      })
+      .filter_map(|field| {
+        Some((field, field.init_conversion.as_ref()?, field.init_type.as_ref()?))
+      })
+  }
+
+  /// The unpadded element type of a padded array field, which gets element-wise
+  /// accessors too.
+  fn padded_element_type(
+    conversion: &RustTypeInitConversion,
+    init_type: &syn::Type,
+  ) -> Option<Box<syn::Type>> {
+    let RustTypeInitConversion::Array { .. } = conversion else {
+      return None;
+    };
+    match init_type {
+      syn::Type::Verbatim(tokens) => syn::parse2::<syn::TypeArray>(tokens.clone())
+        .ok()
+        .map(|array| array.elem),
+      syn::Type::Array(array) => Some(array.elem.clone()),
+      _ => None,
+    }
+  }
+
+  /// Checks that the accessors of padded fields, which are named after the
+  /// fields, don't clash with each other or with the other methods generated
+  /// for the struct, e.g. for a field named `from_bytes`.
+  pub fn check_padded_field_accessors(&self) -> Result<(), CreateModuleError> {
+    let mut methods = [
+      "new",
+      "as_bytes",
+      "to_bytes",
+      "from_bytes",
+      "tail_from_bytes",
+    ]
+    .into_iter()
+    .chain(
+      self
+        .buffer_usages
+        .map(|_| {
+          [
+            "create_buffer",
+            "create_storage_buffer_with_len",
+            "create_vertex_buffer",
+            "vertex_buffer_layout",
+          ]
+        })
+        .into_iter()
+        .flatten(),
+    )
+    .chain(self.has_dynamic_offset.then_some("aligned_stride"))
+    .map(str::to_string)
+    .collect::<HashSet<_>>();
+    if self.is_host_sharable {
+      methods.extend(self.members.iter().filter_map(|entry| match entry {
+        RustStructMemberEntry::Field(field) if !field.is_rsa => {
+          let member_name = field.naga_member.name.as_ref()?;
+          Some(format!("write_field_{member_name}"))
+        }
+        _ => None,
+      }));
+    }
+
+    for (field, conversion, init_type) in self.padded_fields() {
+      let name = field.name_ident.to_string();
+      let mut accessors = vec![name.clone(), format!("set_{name}")];
+      if Self::padded_element_type(conversion, init_type).is_some() {
+        accessors.extend([format!("{name}_at"), format!("set_{name}_at")]);
+      }
+      for method in accessors {
+        if !methods.insert(method.clone()) {
+          return Err(CreateModuleError::PaddedFieldAccessorClash {
+            struct_name: self.item_path.get_fully_qualified_name().to_string(),
+            field: name,
+            method,
+          });
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Getters and setters that take and return the unpadded constructor types of
+  /// padded fields, so a single field can be changed without going through
+  /// the `Init` struct.
+  fn build_padded_field_accessors(&self) -> TokenStream {
+    let accessors = self
+      .padded_fields()
+      .map(|(field, conversion, init_type)| {
+        let name = &field.name_ident;
+        let setter = format_ident!("set_{name}");
+        let get = conversion.generate_inverse(quote!(self.#name));
+        let set = conversion.generate(quote!(value));
+        let get_doc = format!("Returns `{name}` without its padding.");
+        let set_doc = format!("Sets `{name}`, padding it to its WGSL layout.");
+
+        let element_accessors = match conversion {
+          RustTypeInitConversion::Array {
+            element_conversion, ..
+          } => Self::padded_element_type(conversion, init_type).map(|element_type| {
+            let getter = format_ident!("{name}_at");
+            let setter = format_ident!("set_{name}_at");
+            let get = element_conversion.generate_inverse(quote!(self.#name[index]));
+            let set = element_conversion.generate(quote!(value));
+            let get_doc =
+              format!("Returns element `index` of `{name}` without its padding.");
+            let set_doc =
+              format!("Sets element `index` of `{name}`, padding it to its WGSL layout.");
+            quote! {
+              #[doc = #get_doc]
+              pub fn #getter(&self, index: usize) -> #element_type {
+                #get
+              }
+
+              #[doc = #set_doc]
+              pub fn #setter(&mut self, index: usize, value: #element_type) {
+                self.#name[index] = #set;
+              }
+            }
+          }),
+          RustTypeInitConversion::Padded { .. } => None,
+        };
+
+        quote! {
+          #[doc = #get_doc]
+          pub fn #name(&self) -> #init_type {
+            #get
+          }
+
+          #[doc = #set_doc]
+          pub fn #setter(&mut self, value: #init_type) {
+            self.#name = #set;
+          }
+
+          #element_accessors
+        }
+      })
+      .collect::<Vec<_>>();
+
+    if accessors.is_empty() {
+      return quote!();
+    }
+
+    let struct_name = self.name_ident();
+    let impl_header = match self.runtime_array_field() {
+      Some(tail) => {
+        let element_type = &tail.rust_type;
+        quote! {
+          impl<Tail> #struct_name<Tail>
+          where
+            Tail: WgslBindgenRuntimeArray<#element_type> + ?Sized
+        }
+      }
+      None => quote!(impl #struct_name),
+    };
+
+    quote! {
+      #impl_header {
+        #(#accessors)*
+      }
+    }
+  }
+
+  /// Helpers that read the struct back from bytes with its WGSL layout, e.g.
+  /// a mapped readback buffer. A runtime-sized array gets as many elements as
+  /// fit along with the struct padding after them, or with `tail_from_bytes`,
+  /// as many as fit at all, like `arrayLength` in WGSL.
+  fn build_from_bytes(&self) -> TokenStream {
+    if !self.is_host_sharable {
+      return quote!();
+    }
+
+    let struct_name = self.name_ident();
+    let doc = format!(
+      "Reads a `{}` from bytes with its WGSL layout. Bytes past the end of the struct are ignored.",
+      self.item_path.name
+    );
+    let alignment = self.custom_alignment().unwrap_or(self.layout.alignment) * 1u32;
+    let alignment = Index::from(alignment as usize);
+
+    match self.options.serialization_strategy {
+      WgslTypeSerializeStrategy::Bytemuck => match self.runtime_array_field() {
+        Some(tail) => self.build_from_bytes_runtime(tail, &doc),
+        None => quote! {
+          impl #struct_name {
+            #[doc = #doc]
+            pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+              let size = ::core::mem::size_of::<Self>();
+              let bytes = bytes.get(..size).ok_or(LayoutError::TooShort {
+                expected: size,
+                actual: bytes.len(),
+              })?;
+              bytemuck::try_from_bytes(bytes).map_err(|_| LayoutError::Misaligned {
+                alignment: #alignment,
+              })
+            }
+          }
+        },
+      },
+      WgslTypeSerializeStrategy::Zerocopy => {
+        // The size of the struct without any runtime-sized elements.
+        let min_size = match self.runtime_tail_field() {
+          Some(tail) => self
+            .custom_alignment()
+            .unwrap_or(self.layout.alignment)
+            .round_up(tail.naga_member.offset),
+          None => {
+            let size = if self.members.is_empty() {
+              0
+            } else {
+              self.layout.size
+            };
+            self
+              .custom_alignment()
+              .map_or(size, |alignment| alignment.round_up(size))
+          }
+        };
+        let min_size = Index::from(min_size as usize);
+        // There is no type of the fixed-size fields alone, so the struct with
+        // no tail elements stands in for them, like the `Sized` struct of
+        // bytemuck.
+        let tail_from_bytes = self.runtime_tail_field().map(|tail| {
+          let tail_name = &tail.name_ident;
+          let element_type = &tail.rust_type;
+          let tail_offset = Index::from(tail.naga_member.offset as usize);
+          let tail_doc = format!(
+            "Reads a `{}` from bytes with its WGSL layout, split into its fixed-size fields and its runtime-sized `{tail_name}`. Unlike [`Self::from_bytes`], `{tail_name}` gets every element that fits, like `arrayLength` in WGSL, even if the struct padding after them does not.",
+            self.item_path.name
+          );
+          quote! {
+            #[doc = #tail_doc]
+            pub fn tail_from_bytes(
+              bytes: &[u8],
+            ) -> Result<(&Self, &[#element_type]), LayoutError> {
+              Self::from_bytes(bytes)?;
+              let Ok((header, _)) =
+                <Self as zerocopy::FromBytes>::ref_from_prefix_with_elems(bytes, 0)
+              else {
+                unreachable!("`from_bytes` checked the size and alignment of the bytes");
+              };
+              let len = (bytes.len() - #tail_offset) / ::core::mem::size_of::<#element_type>();
+              let tail = &bytes[#tail_offset..][..len * ::core::mem::size_of::<#element_type>()];
+              let Ok(tail) = <[#element_type] as zerocopy::FromBytes>::ref_from_bytes(tail) else {
+                unreachable!("the tail is aligned like the struct");
+              };
+              Ok((header, tail))
+            }
+          }
+        });
+        quote! {
+          impl #struct_name {
+            #[doc = #doc]
+            pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+              match <Self as zerocopy::FromBytes>::ref_from_prefix(bytes) {
+                Ok((this, _)) => Ok(this),
+                Err(zerocopy::ConvertError::Alignment(_)) => {
+                  Err(LayoutError::Misaligned { alignment: #alignment })
+                }
+                Err(zerocopy::ConvertError::Size(_)) => Err(LayoutError::TooShort {
+                  expected: #min_size,
+                  actual: bytes.len(),
+                }),
+                Err(zerocopy::ConvertError::Validity(never)) => match never {},
+              }
+            }
+
+            #tail_from_bytes
+          }
+        }
+      }
+      WgslTypeSerializeStrategy::Encase => quote! {
+        impl #struct_name {
+          #[doc = #doc]
+          pub fn from_bytes(bytes: &[u8]) -> Result<Self, LayoutError> {
+            encase::StorageBuffer::new(bytes).create().map_err(|error| match error {
+              encase::internal::Error::BufferTooSmall { expected, found } => {
+                LayoutError::TooShort {
+                  expected: expected as usize,
+                  actual: found as usize,
+                }
+              }
+            })
+          }
+        }
+      },
+    }
+  }
+
+  fn build_from_bytes_runtime(&self, tail: &Field<'a>, doc: &str) -> TokenStream {
+    let struct_name = self.name_ident();
+    let sized_name = self.sized_name_ident();
+    let tail_name = &tail.name_ident;
+    let element_type = &tail.rust_type;
+    let pod_bounds = self.pod_bounds();
+    let tail_doc = format!(
+      "Reads a `{}` from bytes with its WGSL layout, split into its fixed-size fields and its runtime-sized `{tail_name}`. Unlike [`Self::from_bytes`], `{tail_name}` gets every element that fits, like `arrayLength` in WGSL, even if the struct padding after them does not.",
+      self.item_path.name
+    );
+
+    quote! {
+      impl #struct_name {
+        #[doc = #doc]
+        pub fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError>
+        where
+          #pod_bounds
+        {
+          use ::core::{mem, ptr};
+
+          let tail_offset = mem::offset_of!(#sized_name<0>, #tail_name);
+          let alignment = mem::align_of::<#sized_name<0>>();
+          // The struct is padded to its alignment, so the tail can only use the
+          // bytes up to the last multiple of it.
+          let aligned_len = bytes.len() / alignment * alignment;
+          let len = aligned_len.saturating_sub(tail_offset) / mem::size_of::<#element_type>();
+          let size = (tail_offset + len * mem::size_of::<#element_type>())
+            .next_multiple_of(alignment);
+          if bytes.len() < size {
+            return Err(LayoutError::TooShort {
+              expected: size,
+              actual: bytes.len(),
+            });
+          }
+          if bytes.as_ptr().align_offset(alignment) != 0 {
+            return Err(LayoutError::Misaligned { alignment });
+          }
+
+          let this = ptr::slice_from_raw_parts(bytes.as_ptr().cast::<#element_type>(), len)
+            as *const Self;
+          // SAFETY: the bytes are aligned and cover the whole struct with `len`
+          // tail elements, and every field is `Pod`.
+          Ok(unsafe { &*this })
+        }
+
+        #[doc = #tail_doc]
+        pub fn tail_from_bytes(
+          bytes: &[u8],
+        ) -> Result<(&#sized_name<0>, &[#element_type]), LayoutError>
+        where
+          #pod_bounds
+        {
+          use ::core::mem;
+
+          let this = Self::from_bytes(bytes)?;
+          let header = this as *const Self as *const #sized_name<0>;
+          let tail_offset = mem::offset_of!(#sized_name<0>, #tail_name);
+          let len = (bytes.len() - tail_offset) / mem::size_of::<#element_type>();
+          let tail = &bytes[tail_offset..][..len * mem::size_of::<#element_type>()];
+          // SAFETY: the fixed-size fields of both types share their layout, and
+          // `from_bytes` checked that the bytes cover at least `#sized_name<0>`.
+          Ok((unsafe { &*header }, bytemuck::cast_slice(tail)))
+        }
+      }
+    }
+  }
+
+  /// Whether a shader binds the struct as a uniform, whose layout encase
+  /// checks when writing it.
+  fn is_bound_as_uniform(&self) -> bool {
+    self
+      .buffer_usages
+      .is_some_and(|usages| usages.contains(wgpu::BufferUsages::UNIFORM))
+  }
+
+  /// Bounds that make a bytemuck runtime-sized struct castable to bytes.
+  fn pod_bounds(&self) -> TokenStream {
+    let field_types = self.members.iter().filter_map(|member| match member {
+      RustStructMemberEntry::Field(field) => Some(&field.rust_type),
+      RustStructMemberEntry::Padding(_) => None,
+    });
+    quote!(#(#field_types: bytemuck::Pod,)*)
+  }
+
+  /// Whether the struct is bound with dynamic offsets and has a fixed size, so
+  /// its values can be packed in a `UniformRing`.
+  fn is_ring_element(&self) -> bool {
+    self.has_dynamic_offset && self.runtime_tail_field().is_none()
+  }
+
+  /// Helpers that create buffers with the usages the struct is bound with, so
+  /// the flags follow the shaders when a binding changes address space.
+  fn build_buffer_helpers(&self) -> TokenStream {
+    let Some(buffer_usages) = self.buffer_usages else {
+      return quote!();
+    };
+
+    let struct_name = self.name_ident();
+    let label = self.item_path.get_fully_qualified_name().to_string();
+    let usages = buffer_usages.iter_names().map(|(name, _)| {
+      let name = format_ident!("{name}");
+      quote!(wgpu::BufferUsages::#name)
+    });
+    let usages = usages
+      .reduce(|usages, usage| quote!(#usages.union(#usage)))
+      .unwrap_or_else(|| quote!(wgpu::BufferUsages::empty()));
+    let usages_doc = format!(
+      "The usages of a buffer holding a `{}`, from how the shaders bind it.",
+      self.item_path.name
+    );
+
+    let (bounds, contents) = match self.options.serialization_strategy {
+      WgslTypeSerializeStrategy::Bytemuck if self.uses_generics_for_rts() => {
+        let pod_bounds = self.pod_bounds();
+        (quote!(where #pod_bounds), quote!(value.as_bytes()))
+      }
+      WgslTypeSerializeStrategy::Bytemuck => {
+        (quote!(), quote!(bytemuck::bytes_of(value)))
+      }
+      WgslTypeSerializeStrategy::Zerocopy if !self.zerocopy_dst_is_padding_free() => {
+        (quote!(), quote!(&value.to_bytes()))
+      }
+      WgslTypeSerializeStrategy::Zerocopy => {
+        (quote!(), quote!(zerocopy::IntoBytes::as_bytes(value)))
+      }
+      WgslTypeSerializeStrategy::Encase => {
+        (quote!(), quote_encase_bytes(quote!(value), self.is_bound_as_uniform()))
+      }
+    };
+
+    // With typed buffer bindings, the helpers vouch for what the buffer holds.
+    let typed = self.options.typed_buffer_bindings;
+    let buffer_type = if typed {
+      quote!(typed_buffer::Buffer<Self>)
+    } else {
+      quote!(wgpu::Buffer)
+    };
+    let wrap_buffer = |buffer: TokenStream| {
+      if typed {
+        quote!(typed_buffer::Buffer::from_raw_unchecked(#buffer))
+      } else {
+        buffer
+      }
+    };
+
+    let create_with_len = self
+      .runtime_tail_field()
+      .filter(|_| buffer_usages.contains(wgpu::BufferUsages::STORAGE))
+      .map(|tail| {
+        let tail_name = tail.naga_member.name.as_ref().unwrap();
+        let offset_name = format_ident!("OFFSET_{}", sanitized_upper_snake_case(tail_name));
+        let stride = Index::from(Self::runtime_tail_stride(tail));
+        let alignment = self.custom_alignment().unwrap_or(self.layout.alignment) * 1u32;
+        let alignment = Index::from(alignment as usize);
+        let doc = format!(
+          "Creates a zeroed storage buffer for a `{}` with `len` elements in `{tail_name}`.",
+          self.item_path.name
+        );
+        let buffer = wrap_buffer(quote! {
+          device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(#label),
+            size: size.next_multiple_of(#alignment),
+            usage: Self::BUFFER_USAGES,
+            mapped_at_creation: false,
+          })
+        });
+        quote! {
+          #[doc = #doc]
+          pub fn create_storage_buffer_with_len(
+            device: &wgpu::Device,
+            len: usize,
+          ) -> #buffer_type {
+            let size = Self::#offset_name + len as wgpu::BufferAddress * #stride;
+            #buffer
+          }
+        }
+      });
+
+    // Vertex buffers are created from a slice of vertices by the vertex input
+    // impls, so only bound structs get buffers of a single value.
+    let is_bound =
+      buffer_usages.intersects(wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::STORAGE);
+
+    // Only values bound with dynamic offsets are packed one aligned stride apart.
+    let ring_element = self.is_ring_element().then(|| {
+      let alignment = match (
+        buffer_usages.contains(wgpu::BufferUsages::UNIFORM),
+        buffer_usages.contains(wgpu::BufferUsages::STORAGE),
+      ) {
+        (true, true) => quote! {
+          let uniform = limits.min_uniform_buffer_offset_alignment;
+          let storage = limits.min_storage_buffer_offset_alignment;
+          if uniform > storage { uniform } else { storage }
+        },
+        (false, true) => quote!(limits.min_storage_buffer_offset_alignment),
+        _ => quote!(limits.min_uniform_buffer_offset_alignment),
+      };
+
+      quote! {
+        impl #struct_name {
+          /// The distance between consecutive values in a buffer bound with dynamic
+          /// offsets: [`Self::SIZE`] rounded up to the minimum offset alignment of `limits`.
+          pub const fn aligned_stride(limits: &wgpu::Limits) -> wgpu::BufferAddress {
+            let alignment = { #alignment };
+            Self::SIZE.next_multiple_of(alignment as wgpu::BufferAddress)
+          }
+        }
+
+        impl uniform_ring::UniformRingElement for #struct_name {
+          const SIZE: wgpu::BufferAddress = Self::SIZE;
+          const BUFFER_USAGES: wgpu::BufferUsages = Self::BUFFER_USAGES;
+
+          fn aligned_stride(limits: &wgpu::Limits) -> wgpu::BufferAddress {
+            Self::aligned_stride(limits)
+          }
+
+          fn write_bytes(&self, bytes: &mut [u8]) {
+            let value = self;
+            let contents: &[u8] = #contents;
+            bytes[..contents.len()].copy_from_slice(contents);
+          }
+        }
+      }
+    });
+
+    let create_buffer = is_bound.then(|| {
+      let buffer = wrap_buffer(quote! {
+        wgpu::util::DeviceExt::create_buffer_init(
+          device,
+          &wgpu::util::BufferInitDescriptor {
+            label: Some(#label),
+            contents: #contents,
+            usage: Self::BUFFER_USAGES,
+          },
+        )
+      });
+      quote! {
+        /// Creates a buffer holding `value` with [`Self::BUFFER_USAGES`].
+        pub fn create_buffer(device: &wgpu::Device, value: &Self) -> #buffer_type
+        #bounds
+        {
+          #buffer
+        }
+      }
+    });
+
+    quote! {
+      #ring_element
+
+      impl #struct_name {
+        #[doc = #usages_doc]
+        pub const BUFFER_USAGES: wgpu::BufferUsages = #usages;
+
+        #create_buffer
+
+        #create_with_len
+      }
+    }
+  }
+
+  /// The WGSL layout of the struct as consts, plus helpers that update a single
+  /// field of a buffer holding the struct.
+  fn build_layout_consts(&self) -> TokenStream {
+    let naga_context = self.naga_module.to_ctx();
+    let struct_name = self.name_ident();
+
+    let mut consts = Vec::new();
+    let mut write_fns = Vec::new();
+    for entry in &self.members {
+      let RustStructMemberEntry::Field(field) = entry else {
+        continue;
+      };
+      let member_name = field.naga_member.name.as_ref().unwrap();
+      let upper_name = sanitized_upper_snake_case(member_name);
+      let offset_name = format_ident!("OFFSET_{upper_name}");
+      let offset = Index::from(field.naga_member.offset as usize);
+      consts.push(quote!(pub const #offset_name: u64 = #offset;));
+
+      // The length of a runtime-sized array is only known from its buffer.
+      if field.is_rsa {
+        continue;
+      }
+      let size_name = format_ident!("SIZE_{upper_name}");
+      let size = Index::from(field.naga_type.inner.size(naga_context) as usize);
+      consts.push(quote!(pub const #size_name: u64 = #size;));
+
+      // Only buffers shared with the GPU follow the WGSL offsets.
+      if !self.is_host_sharable {
+        continue;
+      }
+      // Padded fields take the same unpadded type as their setter.
+      let (value_type, pad_value) = match &field.init_conversion {
+        Some(conversion) => {
+          let rust_type = &field.rust_type;
+          let padded = conversion.generate(quote!(value));
+          let pad_value = quote! {
+            let value = *value;
+            let value: &#rust_type = &#padded;
+          };
+          (field.input_type(), pad_value)
+        }
+        None => (&field.rust_type, quote!()),
+      };
+      let field_name = &field.name_ident;
+      let bytes = match self.options.serialization_strategy {
+        // A struct with a runtime-sized array is only castable when its
+        // fields are, like in `as_bytes`.
+        WgslTypeSerializeStrategy::Bytemuck if self.has_rts_array => {
+          quote!(let bytes = bytemuck::bytes_of(value);)
+        }
+        // Types from a type map like `mint` need not implement `Pod`, so the
+        // field is written through the struct, which always does.
+        WgslTypeSerializeStrategy::Bytemuck => quote! {
+          let mut this = <Self as bytemuck::Zeroable>::zeroed();
+          this.#field_name = *value;
+          let bytes = &bytemuck::bytes_of(&this)[Self::#offset_name as usize..];
+        },
+        WgslTypeSerializeStrategy::Zerocopy => {
+          quote!(let bytes = zerocopy::IntoBytes::as_bytes(value);)
+        }
+        WgslTypeSerializeStrategy::Encase => {
+          let bytes = quote_encase_bytes(quote!(value), self.is_bound_as_uniform());
+          quote!(let bytes = #bytes;)
+        }
+      };
+      let fn_name = format_ident!("write_field_{member_name}");
+      let doc = format!(
+        "Writes `{member_name}` of the `{}` stored at `base_offset` in `buffer`.",
+        self.item_path.name
+      );
+      write_fns.push(quote! {
+        #[doc = #doc]
+        pub fn #fn_name(
+          queue: &wgpu::Queue,
+          buffer: &wgpu::Buffer,
+          base_offset: wgpu::BufferAddress,
+          value: &#value_type,
+        ) {
+          #pad_value
+          // The Rust type may be wider than the WGSL member, e.g. a `vec3`
+          // stored as four scalars, so only the member itself is written.
+          #bytes
+          queue.write_buffer(
+            buffer,
+            base_offset + Self::#offset_name,
+            &bytes[..Self::#size_name as usize],
+          );
+        }
+      });
+    }
+
+    // A runtime-sized struct has no fixed size.
+    if !self.has_rts_array {
+      let struct_size = if self.members.is_empty() {
+        0
+      } else {
+        self.layout.size
+      };
+      let struct_size = self
+        .custom_alignment()
+        .map_or(struct_size, |alignment| alignment.round_up(struct_size));
+      let struct_size = Index::from(struct_size as usize);
+      consts.push(quote!(pub const SIZE: u64 = #struct_size;));
+    }
+
+    quote! {
+      impl #struct_name {
+        #(#consts)*
+        #(#write_fns)*
+      }
+    }
+  }
+
+  /// A test that fills every field with sentinel values, serializes the
+  /// struct and checks the bytes of each field at its WGSL offset. Runtime-sized
+  /// arrays get two elements.
+  fn build_layout_test(&self) -> Option<RustSourceItem> {
+    if !self.options.layout_tests || !self.is_host_sharable {
+      return None;
+    }
+
+    const TAIL_LEN: usize = 2;
+    let tail_len = Index::from(TAIL_LEN);
+    let strategy = self.options.serialization_strategy;
+    let fully_qualified_name_str = self.item_path.get_fully_qualified_name();
+    let fully_qualified_name =
+      syn::parse_str::<TokenStream>(&fully_qualified_name_str).unwrap();
+
+    // Fields without a host-shareable layout (e.g. `bool` in workgroup structs)
+    // have no sentinel bytes, so they are left zeroed and not checked.
+    let zeroed_field = match strategy {
+      WgslTypeSerializeStrategy::Bytemuck => quote!(bytemuck::Zeroable::zeroed()),
+      WgslTypeSerializeStrategy::Zerocopy => quote!(zerocopy::FromZeros::new_zeroed()),
+      WgslTypeSerializeStrategy::Encase => quote!(Default::default()),
+    };
+    let mut skipped_docs = Vec::new();
+    let mut next_sentinel = 1;
+    let mut expected_consts = Vec::new();
+    let mut field_values = Vec::new();
+    let mut tail_values = None;
+    let mut field_assertions = Vec::new();
+    for entry in &self.members {
+      let field = match entry {
+        RustStructMemberEntry::Field(field) => field,
+        RustStructMemberEntry::Padding(padding) => {
+          let pad_name = &padding.pad_name;
+          let pad_size = &padding.pad_size_tokens;
+          // The size of the padding after a field may be written in terms of
+          // the field's type, which is not nameable from here.
+          let zeroed = match strategy {
+            WgslTypeSerializeStrategy::Bytemuck => quote!(bytemuck::Zeroable::zeroed()),
+            WgslTypeSerializeStrategy::Zerocopy => {
+              quote!(zerocopy::FromZeros::new_zeroed())
+            }
+            WgslTypeSerializeStrategy::Encase => quote!([0; #pad_size]),
+          };
+          field_values.push((pad_name, zeroed));
+          continue;
+        }
+      };
+
+      let name = &field.name_ident;
+      let member_name = field.naga_member.name.as_ref().unwrap();
+      let const_name = format_ident!("{}", sanitized_upper_snake_case(member_name));
+      let offset = field.naga_member.offset as usize;
+
+      if field.is_rsa {
+        let naga::TypeInner::Array { base, stride, .. } = field.naga_type.inner else {
+          unreachable!("runtime array field must use an array type");
+        };
+        let Some(elements) = (0..TAIL_LEN)
+          .map(|_| sentinel_bytes(self.naga_module, base, &mut next_sentinel))
+          .collect::<Option<Vec<_>>>()
+        else {
+          skipped_docs.push(skipped_field_doc(member_name));
+          tail_values = Some((name, vec![zeroed_field.clone(); TAIL_LEN]));
+          continue;
+        };
+        let elements = elements.iter().map(|bytes| byte_slice_literal(bytes));
+        expected_consts
+          .push(quote!(const #const_name: [&[u8]; #tail_len] = [#(#elements),*];));
+
+        let values = (0..TAIL_LEN)
+          .map(|index| {
+            let index = Index::from(index);
+            quote!(sentinel(#const_name[#index]))
+          })
+          .collect::<Vec<_>>();
+        tail_values = Some((name, values));
+        for index in 0..TAIL_LEN {
+          let element_name = format!("{member_name}[{index}]");
+          let element_offset = Index::from(offset + index * stride as usize);
+          let index = Index::from(index);
+          field_assertions.push(quote! {
+            assert_field_bytes(bytes, #element_name, #element_offset, #const_name[#index]);
+          });
+        }
+      } else {
+        let Some(bytes) =
+          sentinel_bytes(self.naga_module, field.naga_ty_handle, &mut next_sentinel)
+        else {
+          skipped_docs.push(skipped_field_doc(member_name));
+          field_values.push((name, zeroed_field.clone()));
+          continue;
+        };
+        let bytes = byte_slice_literal(&bytes);
+        expected_consts.push(quote!(const #const_name: &[u8] = #bytes;));
+        field_values.push((name, quote!(sentinel(#const_name))));
+        let offset = Index::from(offset);
+        field_assertions.push(quote! {
+          assert_field_bytes(bytes, #member_name, #offset, #const_name);
+        });
+      }
+    }
+
+    let (value, bytes) = if self.is_zerocopy_dst() {
+      // A slice DST cannot be built with a struct expression, so it is
+      // allocated zeroed and filled in place.
+      let assignments = field_values
+        .iter()
+        .map(|(name, value)| quote!(value.#name = #value;));
+      let tail_assignments = tail_values.iter().flat_map(|(name, values)| {
+        values.iter().enumerate().map(move |(index, value)| {
+          let index = Index::from(index);
+          quote!(value.#name[#index] = #value;)
+        })
+      });
+      let bytes = if self.zerocopy_dst_is_padding_free() {
+        quote!(zerocopy::IntoBytes::as_bytes(&*value))
+      } else {
+        quote!(&value.to_bytes())
+      };
+      let value = quote! {
+        let mut value =
+          <#fully_qualified_name as zerocopy::FromZeros>::new_box_zeroed_with_elems(#tail_len)
+            .unwrap();
+        #(#assignments)*
+        #(#tail_assignments)*
+      };
+      (value, bytes)
+    } else {
+      let mut members = field_values
+        .iter()
+        .map(|(name, value)| quote!(#name: #value))
+        .collect::<Vec<_>>();
+      if let Some((name, values)) = &tail_values {
+        members.push(match strategy {
+          WgslTypeSerializeStrategy::Encase => quote!(#name: vec![#(#values),*]),
+          _ => quote!(#name: [#(#values),*]),
+        });
+      }
+      let struct_name = if self.uses_generics_for_rts() {
+        let sized_name = format!("{fully_qualified_name_str}Sized");
+        let sized_name = syn::parse_str::<TokenStream>(&sized_name).unwrap();
+        quote!(#sized_name::<#tail_len>)
+      } else {
+        fully_qualified_name
+      };
+      let bytes = match strategy {
+        WgslTypeSerializeStrategy::Bytemuck if self.uses_generics_for_rts() => {
+          quote!(value.as_bytes())
+        }
+        WgslTypeSerializeStrategy::Bytemuck => quote!(bytemuck::bytes_of(&value)),
+        WgslTypeSerializeStrategy::Zerocopy => {
+          quote!(zerocopy::IntoBytes::as_bytes(&value))
+        }
+        WgslTypeSerializeStrategy::Encase => quote_encase_bytes(quote!(&value), false),
+      };
+      (quote!(let value = #struct_name { #(#members),* };), bytes)
+    };
+
+    let test_name = format_ident!(
+      "{}",
+      sanitized_upper_snake_case(&fully_qualified_name_str).to_lowercase()
+    );
+    Some(RustSourceItem::new(
+      RustSourceItemCategory::TypeImpls.into(),
+      RustSourceItemPath::new(MOD_LAYOUT_TESTS.into(), fully_qualified_name_str.clone()),
+      quote! {
+        #(#[doc = #skipped_docs])*
+        #[test]
+        fn #test_name() {
+          #(#expected_consts)*
+
+          #value
+          let bytes: &[u8] = #bytes;
+
+          #(#field_assertions)*
+        }
+      },
+    ))
+  }
+
   pub fn build_bytemuck_impls(&self) -> TokenStream {
     let struct_name_in_usage = self.fully_qualified_struct_name_in_usage_fragment();
     let impl_fragment = self.impl_trait_for_fragment();
@@ -1100,7 +2209,7 @@ impl<'a> RustStructBuilder<'a> {
       self.options,
     )];
     items.extend(padded_types.iter().map(|padded_type| {
-      padded_layout_impl_item(self.item_path.module.as_str(), padded_type)
+      padded_layout_impl_item(self.item_path.module.as_str(), padded_type, self.options)
     }));
     items
   }
@@ -1116,24 +2225,12 @@ impl<'a> RustStructBuilder<'a> {
     // This allows vertex input field types without padding like vec3 for positions.
     let is_host_shareable = self.is_host_sharable;
 
-    let should_generate_padding = is_host_shareable
-      && self.options.serialization_strategy == WgslTypeSerializeStrategy::Bytemuck;
+    let should_generate_padding =
+      is_host_shareable && self.options.serialization_strategy.is_byte_castable();
 
     let derives = self.build_derives();
 
-    let fully_qualified_name = self.item_path.get_fully_qualified_name();
-    let fully_qualified_name = fully_qualified_name.as_str();
-    let custom_alignment = self
-      .options
-      .override_struct_alignment
-      .iter()
-      .find_map(|struct_align| {
-        struct_align
-          .struct_regex
-          .is_match(fully_qualified_name)
-          .then_some(struct_align.alignment as u32)
-      })
-      .and_then(naga::proc::Alignment::new);
+    let custom_alignment = self.custom_alignment();
 
     let alignment = custom_alignment.unwrap_or(self.layout.alignment) * 1u32;
     let alignment = Index::from(alignment as usize);
@@ -1146,10 +2243,16 @@ impl<'a> RustStructBuilder<'a> {
     let fields = self.build_fields();
     let struct_new_fn = if self.uses_generics_for_rts() {
       self.build_fn_new_runtime()
+    } else if self.is_zerocopy_dst() {
+      self.build_fn_new_zerocopy_dst()
     } else {
       self.build_fn_new()
     };
     let init_struct = self.build_init_struct();
+    let layout_consts = self.build_layout_consts();
+    let from_bytes = self.build_from_bytes();
+    let buffer_helpers = self.build_buffer_helpers();
+    let padded_field_accessors = self.build_padded_field_accessors();
     let assert_layout = self.build_layout_assertion(custom_alignment);
     let unsafe_bytemuck_pod_impl = self.build_bytemuck_impls();
     let fully_qualified_name = self.item_path.get_fully_qualified_name();
@@ -1158,9 +2261,28 @@ impl<'a> RustStructBuilder<'a> {
     // For now, keep the original complex struct definition due to generics handling
     let struct_name_def = self.struct_name_in_definition_fragment();
     let derive_attrs = generate_derive_attributes(&derives);
+    let custom_attributes = self.custom_attributes();
+    let wgsl_doc_comment = type_doc_comments(self.naga_module, self.naga_ty_handle);
+    let doc_comment = match &self.layout_report {
+      Some(report) => {
+        let layout_doc_comment = report.doc_comment();
+        if wgsl_doc_comment.is_empty() {
+          layout_doc_comment
+        } else {
+          quote! {
+            #wgsl_doc_comment
+            #[doc = ""]
+            #layout_doc_comment
+          }
+        }
+      }
+      None => wgsl_doc_comment,
+    };
     let struct_definition = quote! {
+      #doc_comment
       #repr_c
       #derive_attrs
+      #custom_attributes
       #visibility struct #struct_name_def {
           #(#fields),*
       }
@@ -1174,6 +2296,10 @@ impl<'a> RustStructBuilder<'a> {
           #struct_definition
 
           #struct_new_fn
+          #layout_consts
+          #from_bytes
+          #buffer_helpers
+          #padded_field_accessors
           #init_struct
         },
       ),
@@ -1193,6 +2319,22 @@ impl<'a> RustStructBuilder<'a> {
     ];
 
     items.extend(self.build_padded_helper_items());
+    if self.is_host_sharable {
+      items.push(layout_error_item(self.item_path.module.as_str()));
+    }
+    if self.is_ring_element() {
+      items.push(uniform_ring_item(self.options));
+    }
+    if self.buffer_usages.is_some() && self.options.typed_buffer_bindings {
+      items.push(typed_buffer_item(self.options));
+    }
+    if self.options.serialization_strategy.is_encase() {
+      items.push(encase_bytes_item());
+    }
+    if let Some(layout_test) = self.build_layout_test() {
+      items.push(layout_test_helper_item(self.options));
+      items.push(layout_test);
+    }
     if let Some(runtime_array_trait_helper) = self.build_runtime_array_trait_helper() {
       items.push(runtime_array_trait_helper);
     }
@@ -1202,6 +2344,7 @@ impl<'a> RustStructBuilder<'a> {
 
   pub fn from_naga(
     item_path: &'a RustSourceItemPath,
+    naga_ty_handle: naga::Handle<naga::Type>,
     naga_members: &'a [naga::StructMember],
     naga_module: &'a naga::Module,
     options: &'a WgslBindgenOption,
@@ -1221,12 +2364,16 @@ impl<'a> RustStructBuilder<'a> {
 
     RustStructBuilder {
       item_path,
+      naga_ty_handle,
       members,
       is_host_sharable,
       naga_module,
       options,
       has_rts_array,
       layout,
+      layout_report: None,
+      buffer_usages: None,
+      has_dynamic_offset: false,
     }
   }
 }
@@ -1235,18 +2382,24 @@ pub(crate) fn padded_helper_item(
   module: &str,
   options: &WgslBindgenOption,
 ) -> RustSourceItem {
-  let derives = generate_derive_attributes(&["Debug", "PartialEq", "Clone", "Copy"]);
+  let is_zerocopy = options.serialization_strategy == WgslTypeSerializeStrategy::Zerocopy;
+  // A packed field cannot be borrowed, so zerocopy serializes a copy of it.
+  let (serialize_bounds, serialized_field) = if is_zerocopy {
+    (quote!(T: Copy + serde::Serialize), quote!(&{ self.field }))
+  } else {
+    (quote!(T: serde::Serialize), quote!(&self.field))
+  };
   let serde_impls = options.derive_serde.then(|| {
     quote! {
       impl<const N: usize, T> serde::Serialize for Padded<N, T>
       where
-        T: serde::Serialize,
+        #serialize_bounds,
       {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
           S: serde::Serializer,
         {
-          serde::Serialize::serialize(&self.field, serializer)
+          serde::Serialize::serialize(#serialized_field, serializer)
         }
       }
 
@@ -1264,12 +2417,62 @@ pub(crate) fn padded_helper_item(
     }
   });
 
+  // zerocopy cannot prove a generic `repr(C)` struct is free of padding, so
+  // its `Padded` is packed instead. Where each element lands is still checked
+  // by the offset assertions of the struct holding the array.
+  let (repr, derives, layout_impls) = if is_zerocopy {
+    let derives = generate_derive_attributes(&[
+      "Debug",
+      "PartialEq",
+      "Clone",
+      "Copy",
+      "zerocopy::FromBytes",
+      "zerocopy::IntoBytes",
+      "zerocopy::Immutable",
+      "zerocopy::KnownLayout",
+    ]);
+    (quote!(#[repr(C, packed)]), derives, quote!())
+  } else {
+    let derives = generate_derive_attributes(&["Debug", "PartialEq", "Clone", "Copy"]);
+    let layout_impls = quote! {
+      #[doc(hidden)]
+      mod __wgsl_bindgen_padded_layout {
+        /// Marks a `Padded` instantiation whose layout is known to match its
+        /// WGSL array stride, which is what makes the `bytemuck` impls below
+        /// sound.
+        ///
+        /// # Safety
+        ///
+        /// Implementors must contain no implicit Rust padding and must have a
+        /// size exactly equal to the stride of the WGSL array they represent.
+        /// Every implementation is emitted next to a `const` assertion that
+        /// checks this, so do not implement this trait by hand.
+        pub unsafe trait Valid {}
+      }
+
+      #[allow(private_bounds)]
+      unsafe impl<const N: usize, T> bytemuck::Zeroable for Padded<N, T>
+      where
+        T: bytemuck::Zeroable,
+        Padded<N, T>: __wgsl_bindgen_padded_layout::Valid,
+      {}
+
+      #[allow(private_bounds)]
+      unsafe impl<const N: usize, T> bytemuck::Pod for Padded<N, T>
+      where
+        T: bytemuck::Pod,
+        Padded<N, T>: __wgsl_bindgen_padded_layout::Valid,
+      {}
+    };
+    (quote!(#[repr(C)]), derives, layout_impls)
+  };
+
   RustSourceItem::new(
     RustSourceItemCategory::TypeDefs | RustSourceItemCategory::TraitImpls,
     RustSourceItemPath::new(module.into(), "Padded".into()),
     quote! {
       #[doc(hidden)]
-      #[repr(C)]
+      #repr
       #derives
       pub struct Padded<const N: usize, T> {
         pub field: T,
@@ -1303,34 +2506,7 @@ pub(crate) fn padded_helper_item(
         padded
       }
 
-      #[doc(hidden)]
-      mod __wgsl_bindgen_padded_layout {
-        /// Marks a `Padded` instantiation whose layout is known to match its
-        /// WGSL array stride, which is what makes the `bytemuck` impls below
-        /// sound.
-        ///
-        /// # Safety
-        ///
-        /// Implementors must contain no implicit Rust padding and must have a
-        /// size exactly equal to the stride of the WGSL array they represent.
-        /// Every implementation is emitted next to a `const` assertion that
-        /// checks this, so do not implement this trait by hand.
-        pub unsafe trait Valid {}
-      }
-
-      #[allow(private_bounds)]
-      unsafe impl<const N: usize, T> bytemuck::Zeroable for Padded<N, T>
-      where
-        T: bytemuck::Zeroable,
-        Padded<N, T>: __wgsl_bindgen_padded_layout::Valid,
-      {}
-
-      #[allow(private_bounds)]
-      unsafe impl<const N: usize, T> bytemuck::Pod for Padded<N, T>
-      where
-        T: bytemuck::Pod,
-        Padded<N, T>: __wgsl_bindgen_padded_layout::Valid,
-      {}
+      #layout_impls
 
       #serde_impls
     },
@@ -1340,6 +2516,7 @@ pub(crate) fn padded_helper_item(
 pub(crate) fn padded_layout_impl_item(
   module: &str,
   padded_type: &PaddedTypeInfo,
+  options: &WgslBindgenOption,
 ) -> RustSourceItem {
   let PaddedTypeInfo {
     padding,
@@ -1347,6 +2524,14 @@ pub(crate) fn padded_layout_impl_item(
     inner_type,
   } = padded_type;
   let id = format!("Padded<{padding}, {}>", inner_type);
+  let valid_impl =
+    (options.serialization_strategy != WgslTypeSerializeStrategy::Zerocopy).then(|| {
+      quote! {
+        unsafe impl __wgsl_bindgen_padded_layout::Valid
+          for Padded<#padding, #inner_type>
+        {}
+      }
+    });
 
   RustSourceItem::new(
     RustSourceItemCategory::ConstVarDecls | RustSourceItemCategory::TraitImpls,
@@ -1363,9 +2548,7 @@ pub(crate) fn padded_layout_impl_item(
         );
       };
 
-      unsafe impl __wgsl_bindgen_padded_layout::Valid
-        for Padded<#padding, #inner_type>
-      {}
+      #valid_impl
     },
   )
 }
@@ -1,15 +1,15 @@
 use naga::{Scalar, ScalarKind, VectorSize};
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use strum::IntoEnumIterator;
-use syn::Index;
+use syn::{Ident, Index};
 
 use crate::bevy_util::demangle_str;
 use crate::quote_gen::demangle_and_fully_qualify;
 use crate::wgsl_type::WgslBuiltInMappedType;
 use crate::{
-  WgslBindgenOption, WgslMatType, WgslType, WgslTypeAlignmentAndSize,
-  WgslTypeSerializeStrategy, WgslVecType,
+  sanitized_upper_snake_case, WgslBindgenOption, WgslMatType, WgslType,
+  WgslTypeAlignmentAndSize, WgslTypeSerializeStrategy, WgslVecType,
 };
 
 #[derive(Debug, Clone)]
@@ -81,6 +81,36 @@ impl RustTypeInitConversion {
     }
   }
 
+  /// Converts `value`, a value of the emitted storage type, back into the
+  /// constructor parameter type by dropping its padding.
+  pub fn generate_inverse(&self, value: TokenStream) -> TokenStream {
+    self.generate_inverse_at_depth(value, 0)
+  }
+
+  /// Nested arrays each index with their own variable, named by depth.
+  fn generate_inverse_at_depth(&self, value: TokenStream, depth: usize) -> TokenStream {
+    match self {
+      Self::Array {
+        element_conversion, ..
+      } => {
+        let index = format_ident!("index_{depth}");
+        let element =
+          element_conversion.generate_inverse_at_depth(quote!(#value[#index]), depth + 1);
+        quote!(::core::array::from_fn(|#index| #element))
+      }
+      Self::Padded {
+        inner_conversion, ..
+      } => {
+        let field = quote!(#value.field);
+        inner_conversion
+          .as_ref()
+          .map_or(field.clone(), |conversion| {
+            conversion.generate_inverse_at_depth(field, depth)
+          })
+      }
+    }
+  }
+
   /// Maps `value`, an array of statically unknown length whose elements each
   /// need `self` applied to them.
   pub fn generate_array_map(&self, value: TokenStream) -> TokenStream {
@@ -198,8 +228,11 @@ impl ToTokens for RustTypeInfo {
   }
 }
 
+/// f16 types are only asserted when a shader uses them, so bindings without
+/// f16 do not need the `half` crate.
 pub(crate) fn custom_vector_matrix_assertions(
   options: &WgslBindgenOption,
+  uses_f16: bool,
 ) -> Option<TokenStream> {
   if options.serialization_strategy.is_encase() {
     return None;
@@ -221,8 +254,13 @@ pub(crate) fn custom_vector_matrix_assertions(
   }
 
   let assertions = WgslVecType::iter()
+    .filter(|ty| uses_f16 || !ty.is_f16())
     .filter_map(|ty| build_assert_for(options, ty))
-    .chain(WgslMatType::iter().filter_map(|ty| build_assert_for(options, ty)))
+    .chain(
+      WgslMatType::iter()
+        .filter(|ty| uses_f16 || !ty.is_f16())
+        .filter_map(|ty| build_assert_for(options, ty)),
+    )
     .collect::<Vec<_>>();
 
   Some(quote! {
@@ -274,6 +312,8 @@ pub(crate) fn rust_scalar_type(
     (ScalarKind::Uint, 2) => RustTypeInfo(quote!(u16), 2, alignment),
     (ScalarKind::Sint, 4) => RustTypeInfo(quote!(i32), 4, alignment),
     (ScalarKind::Uint, 4) => RustTypeInfo(quote!(u32), 4, alignment),
+    (ScalarKind::Sint, 8) => RustTypeInfo(quote!(i64), 8, alignment),
+    (ScalarKind::Uint, 8) => RustTypeInfo(quote!(u64), 8, alignment),
     (ScalarKind::Float, 2) => RustTypeInfo(quote!(half::f16), 2, alignment),
     (ScalarKind::Float, 4) => RustTypeInfo(quote!(f32), 4, alignment),
     (ScalarKind::Float, 8) => RustTypeInfo(quote!(f64), 8, alignment),
@@ -296,7 +336,7 @@ fn get_stride_and_padding(
   let total_bytes = alignment.round_up(used_bytes);
   let padding_bytes = total_bytes - used_bytes;
 
-  if options.serialization_strategy == WgslTypeSerializeStrategy::Bytemuck {
+  if options.serialization_strategy.is_byte_castable() {
     (total_bytes, padding_bytes)
   } else {
     (total_bytes, 0)
@@ -345,6 +385,15 @@ fn map_naga_vec_type(
     (Bi, Float, 2) => Vec2h,
     (Tri, Float, 2) => Vec3h,
     (Quad, Float, 2) => Vec4h,
+    (Bi, Float, 8) => Vec2d,
+    (Tri, Float, 8) => Vec3d,
+    (Quad, Float, 8) => Vec4d,
+    (Bi, Sint, 8) => Vec2i64,
+    (Tri, Sint, 8) => Vec3i64,
+    (Quad, Sint, 8) => Vec4i64,
+    (Bi, Uint, 8) => Vec2u64,
+    (Tri, Uint, 8) => Vec3u64,
+    (Quad, Uint, 8) => Vec4u64,
     _ => return None,
   };
 
@@ -370,22 +419,31 @@ fn map_naga_mat_type(
   let ty = match (columns, rows, scalar.kind, scalar.width) {
     (Bi, Bi, Float, 4) => Mat2x2f,
     (Bi, Bi, Float, 2) => Mat2x2h,
+    (Bi, Bi, Float, 8) => Mat2x2d,
     (Tri, Bi, Float, 4) => Mat3x2f,
     (Tri, Bi, Float, 2) => Mat3x2h,
+    (Tri, Bi, Float, 8) => Mat3x2d,
     (Quad, Bi, Float, 4) => Mat4x2f,
     (Quad, Bi, Float, 2) => Mat4x2h,
+    (Quad, Bi, Float, 8) => Mat4x2d,
     (Bi, Tri, Float, 4) => Mat2x3f,
     (Bi, Tri, Float, 2) => Mat2x3h,
+    (Bi, Tri, Float, 8) => Mat2x3d,
     (Tri, Tri, Float, 4) => Mat3x3f,
     (Tri, Tri, Float, 2) => Mat3x3h,
+    (Tri, Tri, Float, 8) => Mat3x3d,
     (Quad, Tri, Float, 4) => Mat4x3f,
     (Quad, Tri, Float, 2) => Mat4x3h,
+    (Quad, Tri, Float, 8) => Mat4x3d,
     (Bi, Quad, Float, 4) => Mat2x4f,
     (Bi, Quad, Float, 2) => Mat2x4h,
+    (Bi, Quad, Float, 8) => Mat2x4d,
     (Tri, Quad, Float, 4) => Mat3x4f,
     (Tri, Quad, Float, 2) => Mat3x4h,
+    (Tri, Quad, Float, 8) => Mat3x4d,
     (Quad, Quad, Float, 4) => Mat4x4f,
     (Quad, Quad, Float, 2) => Mat4x4h,
+    (Quad, Quad, Float, 8) => Mat4x4d,
     _ => return None,
   };
 
@@ -396,6 +454,22 @@ fn map_naga_mat_type(
   ty.get_mapped_type(&options.type_map)
 }
 
+/// The const generic parameter standing in for the length of the arrays sized
+/// by the override `handle`.
+pub(crate) fn array_length_param(
+  module: &naga::Module,
+  handle: naga::Handle<naga::Override>,
+) -> Ident {
+  let name = module.overrides[handle].name.as_deref().unwrap_or_default();
+  let name = demangle_str(name);
+  let name = if name.chars().any(char::is_lowercase) {
+    sanitized_upper_snake_case(&name)
+  } else {
+    name.replace("::", "_")
+  };
+  Ident::new(&name, Span::call_site())
+}
+
 /// Generates a Rust type information for a Naga type.
 ///
 /// Specify the invoke entry module to generate fully qualified type name.///
@@ -488,7 +562,7 @@ pub(crate) fn rust_type(
       let total_size = (size.get() as usize) * (*stride as usize);
 
       // Check if we need padding between array elements
-      if options.serialization_strategy == WgslTypeSerializeStrategy::Bytemuck {
+      if options.serialization_strategy.is_byte_castable() {
         let element_size = inner_ty.size.unwrap_or(0);
         let actual_stride = *stride as usize;
 
@@ -541,7 +615,7 @@ pub(crate) fn rust_type(
       let (member_type, init_type, init_conversion) = match options.serialization_strategy
       {
         WgslTypeSerializeStrategy::Encase => (quote!(Vec<#element_type>), None, None),
-        WgslTypeSerializeStrategy::Bytemuck => {
+        WgslTypeSerializeStrategy::Bytemuck | WgslTypeSerializeStrategy::Zerocopy => {
           let element_size = element_type.size.unwrap_or(0);
           let stride = *stride as usize;
           if element_size < stride {
@@ -576,10 +650,22 @@ pub(crate) fn rust_type(
       }
     }
     naga::TypeInner::Array {
-      size: naga::ArraySize::Pending(_),
-      ..
+      base,
+      size: naga::ArraySize::Pending(length_override),
+      stride: _,
     } => {
-      unimplemented!("Pending arrays are not supported yet");
+      // The length is only known once the override is set, so the array is
+      // sized by a const generic parameter named after the override.
+      let element_type =
+        rust_type(invoking_entry_module, module, &module.types[*base], options);
+      let length = array_length_param(module, *length_override);
+      RustTypeInfo {
+        tokens: quote!([#element_type; #length]),
+        size: None,
+        alignment,
+        init_type: None,
+        init_conversion: None,
+      }
     }
     naga::TypeInner::Struct { members, span: _ } => {
       let name_str = ty.name.as_ref().unwrap();
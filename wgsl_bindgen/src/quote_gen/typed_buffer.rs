@@ -0,0 +1,137 @@
+use quote::quote;
+
+use crate::quote_gen::{
+  quote_encase_bytes, RustSourceItem, RustSourceItemCategory, RustSourceItemPath,
+  MOD_TYPED_BUFFER,
+};
+use crate::{WgslBindgenOption, WgslTypeSerializeStrategy};
+
+/// The `typed_buffer` module's buffers and buffer bindings that carry the type
+/// they hold, so bind group entries only take buffers of the type they bind.
+pub(crate) fn typed_buffer_item(options: &WgslBindgenOption) -> RustSourceItem {
+  let (element_bounds, contents) = match options.serialization_strategy {
+    WgslTypeSerializeStrategy::Bytemuck => {
+      (quote!(T: bytemuck::Pod), quote!(bytemuck::cast_slice(values)))
+    }
+    WgslTypeSerializeStrategy::Zerocopy => (
+      quote!(T: zerocopy::IntoBytes + zerocopy::Immutable),
+      quote!(zerocopy::IntoBytes::as_bytes(values)),
+    ),
+    WgslTypeSerializeStrategy::Encase => (
+      quote!([T]: encase::ShaderType + encase::internal::WriteInto),
+      quote_encase_bytes(quote!(values), false),
+    ),
+  };
+
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs
+      | RustSourceItemCategory::TypeImpls
+      | RustSourceItemCategory::TraitImpls,
+    RustSourceItemPath::new(MOD_TYPED_BUFFER.into(), "TypedBuffer".into()),
+    quote! {
+      /// A buffer holding a `T` with its WGSL layout, as made by the generated
+      /// buffer creation helpers.
+      pub struct Buffer<T: ?Sized> {
+        buffer: wgpu::Buffer,
+        _contents: ::core::marker::PhantomData<fn() -> *const T>,
+      }
+
+      impl<T: ?Sized> Buffer<T> {
+        /// Wraps a buffer the caller knows holds a `T` with its WGSL layout.
+        /// Nothing checks that it does.
+        pub fn from_raw_unchecked(buffer: wgpu::Buffer) -> Self {
+          Self {
+            buffer,
+            _contents: ::core::marker::PhantomData,
+          }
+        }
+
+        /// The binding of the whole buffer.
+        pub fn binding(&self) -> TypedBuffer<'_, T> {
+          TypedBuffer::new_unchecked(self.buffer.as_entire_buffer_binding())
+        }
+
+        pub fn inner(&self) -> &wgpu::Buffer {
+          &self.buffer
+        }
+
+        pub fn into_inner(self) -> wgpu::Buffer {
+          self.buffer
+        }
+      }
+
+      impl<T> Buffer<[T]>
+      where
+        #element_bounds
+      {
+        /// Creates a storage buffer holding `values` as a runtime-sized array,
+        /// for bindings of a `[T]`.
+        pub fn from_slice(device: &wgpu::Device, values: &[T]) -> Self {
+          Self::from_raw_unchecked(wgpu::util::DeviceExt::create_buffer_init(
+            device,
+            &wgpu::util::BufferInitDescriptor {
+              label: Some(::core::any::type_name::<[T]>()),
+              contents: #contents,
+              usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            },
+          ))
+        }
+      }
+
+      impl<T: ?Sized> ::core::ops::Deref for Buffer<T> {
+        type Target = wgpu::Buffer;
+
+        fn deref(&self) -> &wgpu::Buffer {
+          &self.buffer
+        }
+      }
+
+      impl<T: ?Sized> ::core::fmt::Debug for Buffer<T> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_tuple("Buffer").field(&self.buffer).finish()
+        }
+      }
+
+      /// A binding of a buffer holding a `T`, taken by bind group entries in
+      /// place of a `wgpu::BufferBinding`. A `T` of `[E]` binds a runtime-sized
+      /// array of `E`.
+      pub struct TypedBuffer<'a, T: ?Sized> {
+        binding: wgpu::BufferBinding<'a>,
+        _contents: ::core::marker::PhantomData<fn() -> *const T>,
+      }
+
+      impl<'a, T: ?Sized> TypedBuffer<'a, T> {
+        /// Wraps a binding the caller knows covers a `T` with its WGSL layout.
+        /// Nothing checks that it does.
+        pub fn new_unchecked(binding: wgpu::BufferBinding<'a>) -> Self {
+          Self {
+            binding,
+            _contents: ::core::marker::PhantomData,
+          }
+        }
+
+        pub fn into_binding(self) -> wgpu::BufferBinding<'a> {
+          self.binding
+        }
+      }
+
+      impl<'a, T: ?Sized> From<TypedBuffer<'a, T>> for wgpu::BufferBinding<'a> {
+        fn from(typed: TypedBuffer<'a, T>) -> Self {
+          typed.binding
+        }
+      }
+
+      impl<T: ?Sized> Clone for TypedBuffer<'_, T> {
+        fn clone(&self) -> Self {
+          Self::new_unchecked(self.binding.clone())
+        }
+      }
+
+      impl<T: ?Sized> ::core::fmt::Debug for TypedBuffer<'_, T> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+          f.debug_tuple("TypedBuffer").field(&self.binding).finish()
+        }
+      }
+    },
+  )
+}
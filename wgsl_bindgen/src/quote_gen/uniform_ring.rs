@@ -0,0 +1,125 @@
+use quote::quote;
+
+use crate::quote_gen::{
+  RustSourceItem, RustSourceItemCategory, RustSourceItemPath, MOD_UNIFORM_RING,
+};
+use crate::WgslBindgenOption;
+
+/// The `uniform_ring` module's `UniformRing`, packing generated structs at dynamic offset
+/// boundaries, and the trait the structs implement for it.
+pub(crate) fn uniform_ring_item(options: &WgslBindgenOption) -> RustSourceItem {
+  let typed_binding = options.typed_buffer_bindings.then(|| {
+    quote! {
+      /// [`Self::binding`] as the typed buffer binding of a `T`.
+      pub fn typed_binding(&self) -> typed_buffer::TypedBuffer<'_, T> {
+        typed_buffer::TypedBuffer::new_unchecked(self.binding())
+      }
+    }
+  });
+
+  RustSourceItem::new(
+    RustSourceItemCategory::TypeDefs | RustSourceItemCategory::TypeImpls,
+    RustSourceItemPath::new(MOD_UNIFORM_RING.into(), "UniformRing".into()),
+    quote! {
+      /// A generated struct bound as a uniform or storage buffer, which can be
+      /// packed in a [`UniformRing`].
+      pub trait UniformRingElement {
+        /// The size of the struct with its WGSL layout.
+        const SIZE: wgpu::BufferAddress;
+        /// The usages of a buffer holding the struct.
+        const BUFFER_USAGES: wgpu::BufferUsages;
+        /// The distance between consecutive values in a buffer bound with dynamic offsets.
+        fn aligned_stride(limits: &wgpu::Limits) -> wgpu::BufferAddress;
+        /// Writes the struct with its WGSL layout to the start of `bytes`.
+        fn write_bytes(&self, bytes: &mut [u8]);
+      }
+
+      /// One buffer holding up to `capacity` values of `T`, each starting at a
+      /// multiple of the device's minimum dynamic offset alignment, eg: per-draw
+      /// uniforms selected with the dynamic offsets of their bind group.
+      #[derive(Debug)]
+      pub struct UniformRing<T> {
+        buffer: wgpu::Buffer,
+        stride: wgpu::BufferAddress,
+        capacity: usize,
+        next: usize,
+        _element: ::core::marker::PhantomData<fn() -> T>,
+      }
+
+      impl<T: UniformRingElement> UniformRing<T> {
+        pub fn new(device: &wgpu::Device, capacity: usize) -> Self {
+          let stride = T::aligned_stride(&device.limits());
+          let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("UniformRing"),
+            size: stride * capacity as wgpu::BufferAddress,
+            usage: T::BUFFER_USAGES,
+            mapped_at_creation: false,
+          });
+          Self {
+            buffer,
+            stride,
+            capacity,
+            next: 0,
+            _element: ::core::marker::PhantomData,
+          }
+        }
+
+        /// Writes `values` one stride apart after the values written since the
+        /// last [`Self::reset`] and returns the dynamic offset of each, or `None`
+        /// if they do not fit before the end of the buffer. The writes take
+        /// effect at the next queue submit, so everything written between two
+        /// submits must fit in the capacity.
+        pub fn write(
+          &mut self,
+          queue: &wgpu::Queue,
+          values: &[T],
+        ) -> Option<Vec<wgpu::DynamicOffset>> {
+          let start = self.next;
+          let end = start.checked_add(values.len()).filter(|end| *end <= self.capacity)?;
+          let stride = self.stride as usize;
+          let mut bytes = vec![0u8; stride * values.len()];
+          for (value, bytes) in values.iter().zip(bytes.chunks_exact_mut(stride)) {
+            value.write_bytes(bytes);
+          }
+          queue.write_buffer(&self.buffer, self.stride * start as wgpu::BufferAddress, &bytes);
+          self.next = end;
+          Some((start..end).map(|index| self.offset(index)).collect())
+        }
+
+        /// Writes the next values from the start of the buffer again. Call it
+        /// once the work reading the values written so far has been submitted.
+        pub fn reset(&mut self) {
+          self.next = 0;
+        }
+
+        /// The dynamic offset of the value at `index`.
+        pub fn offset(&self, index: usize) -> wgpu::DynamicOffset {
+          (index as wgpu::BufferAddress * self.stride) as wgpu::DynamicOffset
+        }
+
+        /// The binding of a single value, moved through the buffer by the dynamic offset.
+        pub fn binding(&self) -> wgpu::BufferBinding<'_> {
+          wgpu::BufferBinding {
+            buffer: &self.buffer,
+            offset: 0,
+            size: wgpu::BufferSize::new(T::SIZE),
+          }
+        }
+
+        #typed_binding
+
+        pub fn buffer(&self) -> &wgpu::Buffer {
+          &self.buffer
+        }
+
+        pub fn stride(&self) -> wgpu::BufferAddress {
+          self.stride
+        }
+
+        pub fn capacity(&self) -> usize {
+          self.capacity
+        }
+      }
+    },
+  )
+}
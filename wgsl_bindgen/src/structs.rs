@@ -1,23 +1,35 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use crate::layout_report::{StructLayoutReport, WgslLayoutReport};
 use crate::quote_gen::{RustSourceItem, RustSourceItemPath, RustStructBuilder};
-use crate::{WgslBindgenOption, WgslTypeSerializeStrategy};
+use crate::{CreateModuleError, WgslBindgenOption, WgslTypeSerializeStrategy};
 use naga::{Handle, Type};
+use smol_str::SmolStr;
+
+/// How the shaders bind a struct as a buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct StructBufferUsage {
+  pub usages: wgpu::BufferUsages,
+  /// Whether a binding of the struct is matched by `override_dynamic_offset`.
+  pub has_dynamic_offset: bool,
+}
+
+/// The buffer usages of each struct bound directly as a uniform or storage
+/// buffer, by fully qualified name.
+pub type StructBufferUsages = HashMap<SmolStr, StructBufferUsage>;
 
 /// Returns a list of Rust structs that represent the WGSL structs in the module.
 pub fn structs_items(
   invoking_entry_module: &str,
   module: &naga::Module,
   options: &WgslBindgenOption,
-) -> Vec<RustSourceItem> {
+  buffer_usages: &StructBufferUsages,
+) -> Result<Vec<RustSourceItem>, CreateModuleError> {
   // Initialize the layout calculator provided by naga.
   let mut layouter = naga::proc::Layouter::default();
   layouter.update(module.to_ctx()).unwrap();
 
-  let mut global_variable_types = HashSet::new();
-  for g in module.global_variables.iter() {
-    add_types_recursive(&mut global_variable_types, module, g.1.ty);
-  }
+  let global_variable_types = global_variable_types(module);
 
   // Create matching Rust structs for WGSL structs.
   // This is a UniqueArena, so each struct will only be generated once.
@@ -39,7 +51,7 @@ pub fn structs_items(
           .any(|e| e.function.arguments.iter().any(|a| a.ty == *h))
         || global_variable_types.contains(h)
     })
-    .flat_map(|(t_handle, ty)| {
+    .map(|(t_handle, ty)| {
       if let naga::TypeInner::Struct { members, .. } = &ty.inner {
         let rust_item_path = RustSourceItemPath::from_mangled(
           ty.name.as_ref().unwrap(),
@@ -50,7 +62,7 @@ pub fn structs_items(
         if options.type_map.contains_key(&crate::WgslType::Struct {
           fully_qualified_name: rust_item_path.get_fully_qualified_name().into(),
         }) {
-          Vec::new()
+          Ok(Vec::new())
         } else {
           rust_struct(
             &rust_item_path,
@@ -60,12 +72,52 @@ pub fn structs_items(
             module,
             options,
             &global_variable_types,
+            buffer_usages,
           )
         }
       } else {
-        Vec::new()
+        Ok(Vec::new())
       }
     })
+    .collect::<Result<Vec<_>, _>>()
+    .map(|items| items.into_iter().flatten().collect())
+}
+
+/// Returns the layout of the host-shareable WGSL structs in the module that are
+/// generated as Rust structs.
+pub fn struct_layout_reports(
+  invoking_entry_module: &str,
+  module: &naga::Module,
+  options: &WgslBindgenOption,
+) -> Vec<StructLayoutReport> {
+  let mut layouter = naga::proc::Layouter::default();
+  layouter.update(module.to_ctx()).unwrap();
+
+  let global_variable_types = global_variable_types(module);
+  module
+    .types
+    .iter()
+    .filter(|(h, _)| global_variable_types.contains(h))
+    .filter_map(|(t_handle, ty)| {
+      let naga::TypeInner::Struct { members, .. } = &ty.inner else {
+        return None;
+      };
+      let rust_item_path =
+        RustSourceItemPath::from_mangled(ty.name.as_ref()?, invoking_entry_module);
+      let fully_qualified_name = rust_item_path.get_fully_qualified_name();
+      if options.type_map.contains_key(&crate::WgslType::Struct {
+        fully_qualified_name: fully_qualified_name.clone().into(),
+      }) {
+        return None;
+      }
+      Some(StructLayoutReport::from_naga(
+        &fully_qualified_name,
+        members,
+        &layouter,
+        t_handle,
+        module,
+      ))
+    })
     .collect()
 }
 
@@ -78,7 +130,8 @@ fn rust_struct(
   naga_module: &naga::Module,
   options: &WgslBindgenOption,
   global_variable_types: &HashSet<Handle<Type>>,
-) -> Vec<RustSourceItem> {
+  buffer_usages: &StructBufferUsages,
+) -> Result<Vec<RustSourceItem>, CreateModuleError> {
   let layout = layouter[t_handle];
 
   // Assume types used in global variables are host shareable and require validation.
@@ -90,12 +143,12 @@ fn rust_struct(
   let is_host_sharable = global_variable_types.contains(&t_handle);
 
   let has_rts_array = struct_has_rts_array_member(naga_members, naga_module);
-  let is_directly_sharable = options.serialization_strategy
-    == WgslTypeSerializeStrategy::Bytemuck
-    && is_host_sharable;
+  let is_directly_sharable =
+    options.serialization_strategy.is_byte_castable() && is_host_sharable;
 
-  let builder = RustStructBuilder::from_naga(
+  let mut builder = RustStructBuilder::from_naga(
     rust_item_path,
+    t_handle,
     naga_members,
     naga_module,
     options,
@@ -104,7 +157,99 @@ fn rust_struct(
     is_host_sharable,
     has_rts_array,
   );
-  builder.build()
+  if is_host_sharable
+    && options
+      .layout_report
+      .contains(WgslLayoutReport::DocComments)
+  {
+    builder.set_layout_report(StructLayoutReport::from_naga(
+      &rust_item_path.get_fully_qualified_name(),
+      naga_members,
+      layouter,
+      t_handle,
+      naga_module,
+    ));
+  }
+  if let Some(usages) = buffer_usages.get(&rust_item_path.get_fully_qualified_name()) {
+    builder.set_buffer_usages(*usages);
+  }
+  builder.check_padded_field_accessors()?;
+  Ok(builder.build())
+}
+
+/// Collects the buffer usages of the structs bound as uniform or storage
+/// buffers, or as arrays in them, and of the vertex input structs across all
+/// entry modules, so a struct shared between modules gets every usage it is
+/// bound with. All of them can be written with `Queue::write_buffer`.
+pub fn struct_buffer_usages<'a>(
+  modules: impl IntoIterator<Item = (&'a str, &'a naga::Module)>,
+  options: &WgslBindgenOption,
+) -> StructBufferUsages {
+  let mut usages = StructBufferUsages::new();
+  let mut add_usage = |name, usage, has_dynamic_offset| {
+    let entry = usages.entry(name).or_insert(StructBufferUsage {
+      usages: wgpu::BufferUsages::COPY_DST,
+      has_dynamic_offset: false,
+    });
+    entry.usages |= usage;
+    entry.has_dynamic_offset |= has_dynamic_offset;
+  };
+  for (mod_name, module) in modules {
+    // A buffer holding an array of structs, e.g. `var<storage> xs: array<X>`,
+    // is a buffer of the structs too.
+    let struct_name = |mut ty: Handle<Type>| {
+      while let naga::TypeInner::Array { base, .. } = module.types[ty].inner {
+        ty = base;
+      }
+      let ty = &module.types[ty];
+      match (&ty.inner, &ty.name) {
+        (naga::TypeInner::Struct { .. }, Some(name)) => Some(
+          RustSourceItemPath::from_mangled(name, mod_name).get_fully_qualified_name(),
+        ),
+        _ => None,
+      }
+    };
+
+    for (_, global) in module.global_variables.iter() {
+      if global.binding.is_none() {
+        continue;
+      }
+      let usage = crate::wgsl::buffer_usages(global.space);
+      if let (Some(usage), Some(name)) = (usage, struct_name(global.ty)) {
+        // Dynamic offsets step through values of a struct bound on its own,
+        // not through arrays of it.
+        let has_dynamic_offset =
+          matches!(module.types[global.ty].inner, naga::TypeInner::Struct { .. })
+            && global.name.as_ref().is_some_and(|binding| {
+              let binding = RustSourceItemPath::from_mangled(binding, mod_name);
+              crate::wgsl::has_dynamic_offset(
+                options,
+                &binding.get_fully_qualified_name(),
+              )
+            });
+        add_usage(name, usage, has_dynamic_offset);
+      }
+    }
+
+    for entry_point in &module.entry_points {
+      if entry_point.stage == naga::ShaderStage::Vertex {
+        for argument in &entry_point.function.arguments {
+          if let Some(name) = struct_name(argument.ty) {
+            add_usage(name, wgpu::BufferUsages::VERTEX, false);
+          }
+        }
+      }
+    }
+  }
+  usages
+}
+
+fn global_variable_types(module: &naga::Module) -> HashSet<Handle<Type>> {
+  let mut types = HashSet::new();
+  for g in module.global_variables.iter() {
+    add_types_recursive(&mut types, module, g.1.ty);
+  }
+  types
 }
 
 fn add_types_recursive(
@@ -159,7 +304,8 @@ mod tests {
   };
 
   pub fn structs(module: &naga::Module, options: &WgslBindgenOption) -> Vec<TokenStream> {
-    structs_items("", module, options)
+    structs_items("", module, options, &struct_buffer_usages([("", module)], options))
+      .unwrap()
       .into_iter()
       .map(|s| s.tokenstream)
       .collect()
@@ -698,6 +844,23 @@ mod tests {
     assert_tokens_snapshot!(actual)
   }
 
+  #[test]
+  fn write_runtime_sized_array_zerocopy() {
+    let module = runtime_sized_array_module();
+
+    let structs = structs(
+      &module,
+      &WgslBindgenOption {
+        serialization_strategy: WgslTypeSerializeStrategy::Zerocopy,
+        ..Default::default()
+      },
+    );
+
+    let actual = quote!(#(#structs)*);
+
+    assert_tokens_snapshot!(actual)
+  }
+
   #[test]
   #[should_panic]
   fn write_runtime_sized_array_not_last_field() {
@@ -722,6 +885,83 @@ mod tests {
     );
   }
 
+  #[test]
+  fn write_custom_struct_derives_and_attributes() {
+    let source = indoc! {r#"
+        struct Counters {
+          hits: u32,
+          misses: vec3<u32>,
+        }
+
+        struct Other {
+          value: u32,
+        }
+
+        @group(0) @binding(0)
+        var<storage, read_write> counters: Counters;
+
+        @group(0) @binding(1)
+        var<uniform> other: Other;
+      "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+
+    let structs = structs(
+      &module,
+      &WgslBindgenOption {
+        serialization_strategy: WgslTypeSerializeStrategy::Bytemuck,
+        struct_derives: vec![("^Counters$", ["Eq", "Hash", "Clone"]).into()],
+        struct_attributes: vec![(
+          "^Counters$",
+          quote!(#[cfg_attr(feature = "inspector", derive(Reflect))]),
+        )
+          .into()],
+        ..Default::default()
+      },
+    );
+    let actual = quote!(#(#structs)*);
+
+    assert_tokens_snapshot!(actual);
+  }
+
+  #[test]
+  fn write_struct_doc_comments() {
+    let source = indoc! {r#"
+        /// Per frame camera data.
+        ///
+        /// Uploaded once per frame.
+        struct Camera {
+          /// World to clip space transform.
+          view_proj: mat4x4<f32>,
+          /// Camera position in world space.
+          position: vec3<f32>,
+          near: f32,
+        }
+
+        @group(0) @binding(0)
+        var<uniform> camera: Camera;
+      "#};
+
+    let module =
+      naga::front::wgsl::Frontend::new_with_options(naga::front::wgsl::Options {
+        parse_doc_comments: true,
+        ..naga::front::wgsl::Options::new()
+      })
+      .parse(source)
+      .unwrap();
+
+    let structs = structs(
+      &module,
+      &WgslBindgenOption {
+        serialization_strategy: WgslTypeSerializeStrategy::Bytemuck,
+        ..Default::default()
+      },
+    );
+    let actual = quote!(#(#structs)*);
+
+    assert_tokens_snapshot!(actual);
+  }
+
   #[test]
   fn write_nonpower_of_2_mats_for_bytemuck_option() {
     let source = indoc! {r#"
@@ -773,6 +1013,49 @@ mod tests {
     assert_tokens_snapshot!(actual);
   }
 
+  #[test]
+  fn write_f16_structs_for_bytemuck_glam_option() {
+    let source = indoc! {r#"
+        enable f16;
+
+        struct ScalarsF16 {
+          a: f16,
+          b: f32,
+          c: f16,
+        }
+
+        struct VectorsF16 {
+          a: vec2<f16>,
+          b: vec3<f16>,
+          c: f16,
+          d: vec4<f16>,
+        }
+
+        struct ArraysF16 {
+          a: array<vec3<f16>, 3>,
+          b: mat3x3<f16>,
+        }
+
+        @group(0) @binding(0) var<storage> scalars: ScalarsF16;
+        @group(0) @binding(1) var<storage> vectors: VectorsF16;
+        @group(0) @binding(2) var<storage> arrays: ArraysF16;
+      "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+
+    let structs = structs(
+      &module,
+      &WgslBindgenOption {
+        serialization_strategy: WgslTypeSerializeStrategy::Bytemuck,
+        type_map: GlamWgslTypeMap.build(WgslTypeSerializeStrategy::Bytemuck),
+        ..Default::default()
+      },
+    );
+    let actual = quote!(#(#structs)*);
+
+    assert_tokens_snapshot!(actual);
+  }
+
   #[test]
   fn write_nonpower_of_2_mats() {
     let source = indoc! {r#"
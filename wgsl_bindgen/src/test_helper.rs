@@ -25,6 +25,9 @@ macro_rules! assert_tokens_snapshot {
   }};
 }
 
+/// Compiles the generated code in a crate of its own and runs its tests, e.g.
+/// the generated `layout_tests`. Given a block of tests, runs them next to the
+/// generated code too, which they see through `super::*`.
 #[macro_export]
 macro_rules! assert_rust_compilation {
     ($output:expr) => {{
@@ -34,9 +37,36 @@ macro_rules! assert_rust_compilation {
         // Extract just the function name (after the last ::) and sanitize for use as project name
         let test_name = full_name.split("::").last().unwrap_or(full_name).replace("::", "_");
         if let Err(e) = $crate::test_helper::try_compilation_test_with_name(&formatted_output, &test_name) {
-            panic!("Generated code failed to compile: {e}\n\n");
+            panic!("Generated code failed to compile or its tests failed: {e}\n\n");
         }
     }};
+    ($output:expr, { $($tests:tt)* }) => {{
+        let formatted_output = $crate::pretty_print(&$output);
+        let full_name = stdext::function_name!();
+        let test_name = full_name.split("::").last().unwrap_or(full_name).replace("::", "_");
+        let tests = stringify!($($tests)*);
+        if let Err(e) = $crate::test_helper::try_generated_tests_with_name(&formatted_output, tests, &test_name) {
+            panic!("Generated code failed its tests: {e}\n\n");
+        }
+    }};
+}
+
+/// The options shared by the feature tests: no hash checks, rerun directives,
+/// header comments or serde derives. Type maps build from the serialization
+/// strategy, so they are left to the test.
+pub fn feature_test_builder(
+  workspace_root: &str,
+  serialization_strategy: crate::WgslTypeSerializeStrategy,
+) -> crate::WgslBindgenOptionBuilder {
+  let mut builder = crate::WgslBindgenOptionBuilder::default();
+  builder
+    .workspace_root(workspace_root)
+    .skip_hash_check(true)
+    .serialization_strategy(serialization_strategy)
+    .derive_serde(false)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true);
+  builder
 }
 
 /// Try to compile generated code and return a Result instead of panicking
@@ -54,8 +84,8 @@ macro_rules! assert_rust_compilation {
 ///
 /// # Returns
 ///
-/// * `Ok(())` if compilation succeeds
-/// * `Err(String)` if compilation fails with error details
+/// * `Ok(())` if compilation and the generated tests succeed
+/// * `Err(String)` if compilation or a generated test fails with error details
 pub fn try_compilation_test(generated_code: &str) -> Result<(), String> {
   try_compilation_test_with_name(generated_code, "wgsl_bindgen_compile_test")
 }
@@ -84,7 +114,8 @@ pub fn try_compilation_test_with_name(
       Ok(())
     }
     Ok(false) => Err(
-      "Generated code failed to compile (see previous output for details)".to_string(),
+      "Generated code failed to compile or its tests failed (see previous output for details)"
+        .to_string(),
     ),
     Err(e) => Err(format!("Compilation test setup failed: {e}")),
   };
@@ -93,6 +124,33 @@ pub fn try_compilation_test_with_name(
   result
 }
 
+/// Runs `tests` in a `#[cfg(test)]` module appended to the generated code
+pub fn try_generated_tests_with_name(
+  generated_code: &str,
+  tests: &str,
+  test_name: &str,
+) -> Result<(), String> {
+  let temp_dir = PathBuf::from("tests")
+    .join("output")
+    .join("compile_test_workspace")
+    .join(test_name);
+  fs::create_dir_all(&temp_dir)
+    .map_err(|e| format!("Failed to create temp directory: {e}"))?;
+
+  let code = format!(
+    "{generated_code}\n#[cfg(test)]\nmod generated_code_tests {{\n  use super::*;\n  {tests}\n}}\n"
+  );
+  let compile_test = create_single_file_compile_test(&temp_dir, test_name, &code)?;
+
+  match compile_test.run_cargo("test") {
+    Ok(true) => Ok(()),
+    Ok(false) => {
+      Err("Generated code tests failed (see previous output for details)".to_string())
+    }
+    Err(e) => Err(format!("Test setup failed: {e}")),
+  }
+}
+
 /// Create a simple compilation test for a single file
 fn create_single_file_compile_test(
   workspace_dir: &std::path::Path,
@@ -125,10 +183,14 @@ struct SingleFileCompileTest {
 }
 
 impl SingleFileCompileTest {
-  /// Test compilation of the single file
+  /// Builds the single file and runs its tests, so generated tests like the
+  /// `layout_tests` module run too
   pub fn test_compilation(&self) -> Result<bool, Box<dyn std::error::Error>> {
-    use std::process::Command;
+    self.run_cargo("test")
+  }
 
+  /// Runs `cargo check` or `cargo test` on the library of the single file
+  fn run_cargo(&self, subcommand: &str) -> Result<bool, Box<dyn std::error::Error>> {
     // Find the workspace root by looking for Cargo.toml with [workspace]
     let mut workspace_root =
       std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
@@ -155,9 +217,10 @@ impl SingleFileCompileTest {
 
     let target_dir = workspace_root.join("target");
 
-    // Run cargo check on the test workspace with color output
+    // Run cargo on the test workspace with color output
     let output = Command::new("cargo")
-      .arg("check")
+      .arg(subcommand)
+      .arg("--lib")
       .arg("--all-features")
       .arg("--target-dir")
       .arg(target_dir.to_str().unwrap())
@@ -167,7 +230,7 @@ impl SingleFileCompileTest {
       .output()?;
 
     if output.status.success() {
-      println!("✓ Generated file compiles successfully");
+      println!("✓ Generated file passes `cargo {subcommand}`");
       Ok(true)
     } else {
       eprintln!("✗ Compilation failed:");
@@ -203,6 +266,17 @@ fn detect_required_dependencies_from_content(
   if content.contains("serde::") {
     deps.insert("serde".to_string());
   }
+  if content.contains("half::") {
+    deps.insert("half".to_string());
+  }
+  if content.contains("zerocopy::") {
+    deps.insert("zerocopy".to_string());
+  }
+  for math_crate in ["mint", "cgmath", "ultraviolet"] {
+    if content.contains(&format!("{math_crate}::")) {
+      deps.insert(math_crate.to_string());
+    }
+  }
 
   // Always include core dependencies that are commonly used
   deps.insert("wgpu".to_string());
@@ -249,18 +323,14 @@ edition = "2021"
           .get("naga")
           .map(|s| s.as_str())
           .unwrap_or("30.0");
-        cargo_toml.push_str(&format!("wgpu = {{ version = \"{wgpu_version}\", features = [\"wgsl\", \"naga-ir\"] }}\nnaga = {{ version = \"{naga_version}\", features = [\"wgsl-out\"] }}\n"));
+        cargo_toml.push_str(&format!("wgpu = {{ version = \"{wgpu_version}\", features = [\"wgsl\", \"naga-ir\", \"noop\"] }}\nnaga = {{ version = \"{naga_version}\", features = [\"wgsl-out\"] }}\n"));
       }
       "glam" => {
         let version = workspace_deps
           .get("glam")
           .map(|s| s.as_str())
           .unwrap_or("0.30");
-        let features = if dependencies.contains("serde") {
-          "[\"bytemuck\", \"serde\"]"
-        } else {
-          "[\"bytemuck\"]"
-        };
+        let features = crate_features(&["bytemuck"], dependencies);
         cargo_toml.push_str(&format!(
           "glam = {{ version = \"{version}\", features = {features} }}\n"
         ));
@@ -297,6 +367,48 @@ edition = "2021"
           "serde = {{ version = \"{version}\", features = [\"derive\"] }}\n"
         ));
       }
+      "half" => {
+        let version = workspace_deps
+          .get("half")
+          .map(|s| s.as_str())
+          .unwrap_or("2.4");
+        let features = crate_features(&["bytemuck"], dependencies);
+        cargo_toml.push_str(&format!(
+          "half = {{ version = \"{version}\", features = {features} }}\n"
+        ));
+      }
+      "mint" => {
+        let version = workspace_deps
+          .get("mint")
+          .map(|s| s.as_str())
+          .unwrap_or("0.5");
+        cargo_toml.push_str(&format!("mint = \"{version}\"\n"));
+      }
+      "cgmath" => {
+        let version = workspace_deps
+          .get("cgmath")
+          .map(|s| s.as_str())
+          .unwrap_or("0.18");
+        cargo_toml.push_str(&format!("cgmath = \"{version}\"\n"));
+      }
+      "ultraviolet" => {
+        let version = workspace_deps
+          .get("ultraviolet")
+          .map(|s| s.as_str())
+          .unwrap_or("0.10");
+        cargo_toml.push_str(&format!(
+          "ultraviolet = {{ version = \"{version}\", features = [\"int\", \"bytemuck\"] }}\n"
+        ));
+      }
+      "zerocopy" => {
+        let version = workspace_deps
+          .get("zerocopy")
+          .map(|s| s.as_str())
+          .unwrap_or("0.8");
+        cargo_toml.push_str(&format!(
+          "zerocopy = {{ version = \"{version}\", features = [\"derive\", \"alloc\"] }}\n"
+        ));
+      }
       _ => {}
     }
   }
@@ -304,6 +416,25 @@ edition = "2021"
   cargo_toml
 }
 
+/// Builds the feature list of a math crate, enabling its `serde` and
+/// `zerocopy` integrations when the generated code needs them.
+fn crate_features(
+  base: &[&str],
+  dependencies: &std::collections::HashSet<String>,
+) -> String {
+  let features = base
+    .iter()
+    .copied()
+    .chain(
+      ["serde", "zerocopy"]
+        .into_iter()
+        .filter(|dep| dependencies.contains(*dep)),
+    )
+    .map(|feature| format!("\"{feature}\""))
+    .collect::<Vec<_>>();
+  format!("[{}]", features.join(", "))
+}
+
 /// Read dependency versions from workspace Cargo.toml
 #[cfg(test)]
 fn read_workspace_dependencies(
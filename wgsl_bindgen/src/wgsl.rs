@@ -1,27 +1,151 @@
+use std::collections::HashMap;
+
+use naga::common::wgsl::TypeContext;
 use naga::StructMember;
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::Index;
 
+use crate::bevy_util::demangle_str;
 use crate::quote_gen::RustSourceItemPath;
+use crate::{CreateModuleError, VertexPulling, WgslBindgenOption};
 
 pub fn shader_stages(module: &naga::Module) -> wgpu::ShaderStages {
   module
     .entry_points
     .iter()
-    .map(|entry| match entry.stage {
-      naga::ShaderStage::Vertex => wgpu::ShaderStages::VERTEX,
-      naga::ShaderStage::Fragment => wgpu::ShaderStages::FRAGMENT,
-      naga::ShaderStage::Compute => wgpu::ShaderStages::COMPUTE,
-      naga::ShaderStage::Task => wgpu::ShaderStages::TASK,
-      naga::ShaderStage::Mesh => wgpu::ShaderStages::MESH,
-      naga::ShaderStage::RayGeneration => wgpu::ShaderStages::RAY_GENERATION,
-      naga::ShaderStage::Miss => wgpu::ShaderStages::MISS,
-      naga::ShaderStage::AnyHit => wgpu::ShaderStages::ANY_HIT,
-      naga::ShaderStage::ClosestHit => wgpu::ShaderStages::CLOSEST_HIT,
+    .map(|entry| shader_stage(entry.stage))
+    .collect()
+}
+
+fn shader_stage(stage: naga::ShaderStage) -> wgpu::ShaderStages {
+  match stage {
+    naga::ShaderStage::Vertex => wgpu::ShaderStages::VERTEX,
+    naga::ShaderStage::Fragment => wgpu::ShaderStages::FRAGMENT,
+    naga::ShaderStage::Compute => wgpu::ShaderStages::COMPUTE,
+    naga::ShaderStage::Task => wgpu::ShaderStages::TASK,
+    naga::ShaderStage::Mesh => wgpu::ShaderStages::MESH,
+    naga::ShaderStage::RayGeneration => wgpu::ShaderStages::RAY_GENERATION,
+    naga::ShaderStage::Miss => wgpu::ShaderStages::MISS,
+    naga::ShaderStage::AnyHit => wgpu::ShaderStages::ANY_HIT,
+    naga::ShaderStage::ClosestHit => wgpu::ShaderStages::CLOSEST_HIT,
+  }
+}
+
+/// The stages of the entry points that read `global`, either directly or
+/// through the functions they call.
+pub fn global_shader_stages(
+  module: &naga::Module,
+  global: naga::Handle<naga::GlobalVariable>,
+) -> wgpu::ShaderStages {
+  let mut function_uses = HashMap::new();
+  module
+    .entry_points
+    .iter()
+    .filter(|entry| {
+      function_uses_global(module, &entry.function, global, &mut function_uses)
     })
+    .map(|entry| shader_stage(entry.stage))
     .collect()
 }
 
+fn function_uses_global(
+  module: &naga::Module,
+  function: &naga::Function,
+  global: naga::Handle<naga::GlobalVariable>,
+  function_uses: &mut HashMap<naga::Handle<naga::Function>, bool>,
+) -> bool {
+  let uses_directly = function.expressions.iter().any(|(_, expression)| {
+    matches!(expression, naga::Expression::GlobalVariable(handle) if *handle == global)
+  });
+  if uses_directly {
+    return true;
+  }
+
+  let mut callees = Vec::new();
+  called_functions(&function.body, &mut callees);
+  callees.into_iter().any(|callee| {
+    if let Some(&uses) = function_uses.get(&callee) {
+      return uses;
+    }
+    let uses =
+      function_uses_global(module, &module.functions[callee], global, function_uses);
+    function_uses.insert(callee, uses);
+    uses
+  })
+}
+
+fn called_functions(
+  block: &naga::Block,
+  functions: &mut Vec<naga::Handle<naga::Function>>,
+) {
+  for statement in block.iter() {
+    match statement {
+      naga::Statement::Block(block) => called_functions(block, functions),
+      naga::Statement::If { accept, reject, .. } => {
+        called_functions(accept, functions);
+        called_functions(reject, functions);
+      }
+      naga::Statement::Switch { cases, .. } => {
+        for case in cases {
+          called_functions(&case.body, functions);
+        }
+      }
+      naga::Statement::Loop {
+        body, continuing, ..
+      } => {
+        called_functions(body, functions);
+        called_functions(continuing, functions);
+      }
+      naga::Statement::Call { function, .. } => functions.push(*function),
+      _ => {}
+    }
+  }
+}
+
+/// The device features a module needs beyond the WebGPU defaults.
+pub fn required_features(module: &naga::Module) -> wgpu::Features {
+  module
+    .types
+    .iter()
+    .filter_map(|(_, ty)| match ty.inner {
+      naga::TypeInner::Scalar(scalar)
+      | naga::TypeInner::Vector { scalar, .. }
+      | naga::TypeInner::Matrix { scalar, .. }
+      | naga::TypeInner::Atomic(scalar) => Some(scalar),
+      _ => None,
+    })
+    .map(|scalar| match (scalar.kind, scalar.width) {
+      (naga::ScalarKind::Float, 2) => wgpu::Features::SHADER_F16,
+      (naga::ScalarKind::Float, 8) => wgpu::Features::SHADER_F64,
+      (naga::ScalarKind::Sint | naga::ScalarKind::Uint, 8) => {
+        wgpu::Features::SHADER_INT64
+      }
+      _ => wgpu::Features::empty(),
+    })
+    .fold(wgpu::Features::empty(), |features, feature| features | feature)
+}
+
+/// The overrides used as the element count of an override-sized array.
+pub fn array_length_overrides(
+  module: &naga::Module,
+) -> Vec<naga::Handle<naga::Override>> {
+  let mut overrides = module
+    .types
+    .iter()
+    .filter_map(|(_, ty)| match ty.inner {
+      naga::TypeInner::Array {
+        size: naga::ArraySize::Pending(handle),
+        ..
+      } => Some(handle),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  overrides.sort();
+  overrides.dedup();
+  overrides
+}
+
 pub fn buffer_binding_type(storage: naga::AddressSpace) -> TokenStream {
   match storage {
     naga::AddressSpace::Uniform => quote!(wgpu::BufferBindingType::Uniform),
@@ -42,12 +166,24 @@ pub fn buffer_binding_type(storage: naga::AddressSpace) -> TokenStream {
   }
 }
 
+/// The buffer usage needed to bind a buffer in `storage`, matching the binding
+/// type from [`buffer_binding_type`]. Other address spaces are not bound from
+/// buffers.
+pub fn buffer_usages(storage: naga::AddressSpace) -> Option<wgpu::BufferUsages> {
+  match storage {
+    naga::AddressSpace::Uniform => Some(wgpu::BufferUsages::UNIFORM),
+    naga::AddressSpace::Storage { .. } => Some(wgpu::BufferUsages::STORAGE),
+    _ => None,
+  }
+}
+
 pub fn vertex_format(ty: &naga::Type) -> wgpu::VertexFormat {
   // Not all wgsl types work as vertex attributes in wgpu.
   match &ty.inner {
     naga::TypeInner::Scalar(scalar) => match (scalar.kind, scalar.width) {
       (naga::ScalarKind::Sint, 4) => wgpu::VertexFormat::Sint32,
       (naga::ScalarKind::Uint, 4) => wgpu::VertexFormat::Uint32,
+      (naga::ScalarKind::Float, 2) => wgpu::VertexFormat::Float16,
       (naga::ScalarKind::Float, 4) => wgpu::VertexFormat::Float32,
       (naga::ScalarKind::Float, 8) => wgpu::VertexFormat::Float64,
       _ => todo!(),
@@ -60,6 +196,7 @@ pub fn vertex_format(ty: &naga::Type) -> wgpu::VertexFormat {
         (naga::ScalarKind::Uint, 2) => wgpu::VertexFormat::Uint16x2,
         (naga::ScalarKind::Uint, 4) => wgpu::VertexFormat::Uint32x2,
         (naga::ScalarKind::Sint, 4) => wgpu::VertexFormat::Sint32x2,
+        (naga::ScalarKind::Float, 2) => wgpu::VertexFormat::Float16x2,
         (naga::ScalarKind::Float, 4) => wgpu::VertexFormat::Float32x2,
         (naga::ScalarKind::Float, 8) => wgpu::VertexFormat::Float64x2,
         _ => todo!(),
@@ -78,6 +215,7 @@ pub fn vertex_format(ty: &naga::Type) -> wgpu::VertexFormat {
         (naga::ScalarKind::Uint, 2) => wgpu::VertexFormat::Uint16x4,
         (naga::ScalarKind::Uint, 4) => wgpu::VertexFormat::Uint32x4,
         (naga::ScalarKind::Sint, 4) => wgpu::VertexFormat::Sint32x4,
+        (naga::ScalarKind::Float, 2) => wgpu::VertexFormat::Float16x4,
         (naga::ScalarKind::Float, 4) => wgpu::VertexFormat::Float32x4,
         (naga::ScalarKind::Float, 8) => wgpu::VertexFormat::Float64x4,
         _ => todo!(),
@@ -87,64 +225,150 @@ pub fn vertex_format(ty: &naga::Type) -> wgpu::VertexFormat {
   }
 }
 
-pub struct VertexInput<'a> {
-  pub item_path: RustSourceItemPath,
-  pub fields: Vec<(u32, StructMember)>,
-  pub naga_module: &'a naga::Module,
+/// The `override_vertex_step_mode` of a vertex input, named by its fully
+/// qualified struct name or its argument name, if one matches.
+pub fn vertex_step_mode_override(
+  options: &WgslBindgenOption,
+  input_name: &str,
+) -> Option<wgpu::VertexStepMode> {
+  options
+    .override_vertex_step_mode
+    .iter()
+    .find(|o| o.input_regex.is_match(input_name))
+    .map(|o| o.step_mode)
 }
 
-// TODO: Handle errors.
-// Collect the necessary data to generate an equivalent Rust struct.
-pub fn get_vertex_input_structs<'a>(
-  invoking_entry_module: &str,
-  module: &'a naga::Module,
-) -> Vec<VertexInput<'a>> {
-  // TODO: Handle multiple entries?
-  module
-    .entry_points
+/// The `vertex_pulling` of a vertex input struct, named by its fully
+/// qualified name, if one matches.
+pub fn vertex_pulling<'a>(
+  options: &'a WgslBindgenOption,
+  struct_name: &str,
+) -> Option<&'a VertexPulling> {
+  options
+    .vertex_pulling
     .iter()
-    .find(|e| e.stage == naga::ShaderStage::Vertex)
-    .map(|vertex_entry| {
-      vertex_entry
-        .function
-        .arguments
-        .iter()
-        .filter(|a| a.binding.is_none())
-        .filter_map(|argument| {
-          let arg_type = &module.types[argument.ty];
-          match &arg_type.inner {
-            naga::TypeInner::Struct { members, span: _ } => {
-              let item_path = RustSourceItemPath::from_mangled(
-                arg_type.name.as_ref().unwrap(),
-                invoking_entry_module,
-              );
-
-              let input = VertexInput {
-                item_path,
-                fields: members
-                  .iter()
-                  .filter_map(|member| {
-                    // Skip builtins since they have no location binding.
-                    let location = match member.binding.as_ref().unwrap() {
-                      naga::Binding::BuiltIn(_) => None,
-                      naga::Binding::Location { location, .. } => Some(*location),
-                    }?;
-
-                    Some((location, member.clone()))
-                  })
-                  .collect(),
-                naga_module: module,
-              };
-
-              Some(input)
-            }
-            // An argument has to have a binding unless it is a structure.
-            _ => None,
-          }
-        })
-        .collect()
+    .find(|o| o.struct_regex.is_match(struct_name))
+}
+
+/// Whether a buffer binding, named by its fully qualified name, is matched by
+/// `override_dynamic_offset`.
+pub fn has_dynamic_offset(options: &WgslBindgenOption, binding_name: &str) -> bool {
+  options
+    .override_dynamic_offset
+    .iter()
+    .any(|o| o.binding_regex.is_match(binding_name))
+}
+
+/// The `override_vertex_format` of a vertex attribute member of `struct_name`,
+/// if one matches.
+pub fn vertex_format_override(
+  options: &WgslBindgenOption,
+  struct_name: &str,
+  member: &StructMember,
+) -> Option<wgpu::VertexFormat> {
+  if !matches!(member.binding, Some(naga::Binding::Location { .. })) {
+    return None;
+  }
+  let member_name = member.name.as_deref()?;
+  options
+    .override_vertex_format
+    .iter()
+    .find(|o| o.struct_regex.is_match(struct_name) && o.field_regex.is_match(member_name))
+    .map(|o| o.format)
+}
+
+/// The format of a vertex attribute member of `struct_name` in its buffer,
+/// checking that an overridden format can be read as the member's WGSL type.
+pub fn vertex_attribute_format(
+  options: &WgslBindgenOption,
+  struct_name: &str,
+  member: &StructMember,
+  module: &naga::Module,
+) -> Result<wgpu::VertexFormat, CreateModuleError> {
+  let ty = &module.types[member.ty];
+  let Some(format) = vertex_format_override(options, struct_name, member) else {
+    return Ok(vertex_format(ty));
+  };
+
+  // Normalized and float formats are read as floats, the others keep their
+  // signedness. The component count may differ from the shader type.
+  use wgpu::VertexFormat as F;
+  let format_kind = match format {
+    F::Uint8 | F::Uint8x2 | F::Uint8x4 | F::Uint16 | F::Uint16x2 | F::Uint16x4 => {
+      naga::ScalarKind::Uint
+    }
+    F::Uint32 | F::Uint32x2 | F::Uint32x3 | F::Uint32x4 => naga::ScalarKind::Uint,
+    F::Sint8 | F::Sint8x2 | F::Sint8x4 | F::Sint16 | F::Sint16x2 | F::Sint16x4 => {
+      naga::ScalarKind::Sint
+    }
+    F::Sint32 | F::Sint32x2 | F::Sint32x3 | F::Sint32x4 => naga::ScalarKind::Sint,
+    _ => naga::ScalarKind::Float,
+  };
+  let shader_kind = match &ty.inner {
+    naga::TypeInner::Scalar(scalar) | naga::TypeInner::Vector { scalar, .. } => {
+      Some(scalar.kind)
+    }
+    _ => None,
+  };
+  if shader_kind == Some(format_kind) {
+    Ok(format)
+  } else {
+    Err(CreateModuleError::VertexFormatMismatch {
+      field: format!("{struct_name}::{}", member.name.as_deref().unwrap_or_default()),
+      format: format!("{format:?}"),
+      ty: demangle_str(&module.to_ctx().type_to_string(member.ty)).to_string(),
     })
-    .unwrap_or_default()
+  }
+}
+
+/// The Rust type of a vertex attribute stored in `format`.
+pub fn vertex_format_rust_type(format: wgpu::VertexFormat) -> TokenStream {
+  use wgpu::VertexFormat as F;
+  let (scalar, count) = match format {
+    F::Uint8 | F::Unorm8 => (quote!(u8), 1),
+    F::Uint8x2 | F::Unorm8x2 => (quote!(u8), 2),
+    F::Uint8x4 | F::Unorm8x4 | F::Unorm8x4Bgra => (quote!(u8), 4),
+    F::Sint8 | F::Snorm8 => (quote!(i8), 1),
+    F::Sint8x2 | F::Snorm8x2 => (quote!(i8), 2),
+    F::Sint8x4 | F::Snorm8x4 => (quote!(i8), 4),
+    F::Uint16 | F::Unorm16 => (quote!(u16), 1),
+    F::Uint16x2 | F::Unorm16x2 => (quote!(u16), 2),
+    F::Uint16x4 | F::Unorm16x4 => (quote!(u16), 4),
+    F::Sint16 | F::Snorm16 => (quote!(i16), 1),
+    F::Sint16x2 | F::Snorm16x2 => (quote!(i16), 2),
+    F::Sint16x4 | F::Snorm16x4 => (quote!(i16), 4),
+    F::Float16 => (quote!(half::f16), 1),
+    F::Float16x2 => (quote!(half::f16), 2),
+    F::Float16x4 => (quote!(half::f16), 4),
+    F::Float32 => (quote!(f32), 1),
+    F::Float32x2 => (quote!(f32), 2),
+    F::Float32x3 => (quote!(f32), 3),
+    F::Float32x4 => (quote!(f32), 4),
+    F::Uint32 | F::Unorm10_10_10_2 => (quote!(u32), 1),
+    F::Uint32x2 => (quote!(u32), 2),
+    F::Uint32x3 => (quote!(u32), 3),
+    F::Uint32x4 => (quote!(u32), 4),
+    F::Sint32 => (quote!(i32), 1),
+    F::Sint32x2 => (quote!(i32), 2),
+    F::Sint32x3 => (quote!(i32), 3),
+    F::Sint32x4 => (quote!(i32), 4),
+    F::Float64 => (quote!(f64), 1),
+    F::Float64x2 => (quote!(f64), 2),
+    F::Float64x3 => (quote!(f64), 3),
+    F::Float64x4 => (quote!(f64), 4),
+  };
+  if count == 1 {
+    scalar
+  } else {
+    let count = Index::from(count);
+    quote!([#scalar; #count])
+  }
+}
+
+pub struct VertexInput<'a> {
+  pub item_path: RustSourceItemPath,
+  pub fields: Vec<(u32, StructMember)>,
+  pub naga_module: &'a naga::Module,
 }
 
 pub struct VertexPrimitiveInput<'a> {
@@ -225,8 +449,9 @@ pub fn get_vertex_input_structs_for_entry_point<'a>(
     .collect()
 }
 
-/// Get all unique vertex input structs from all vertex entry points
-pub fn get_all_vertex_input_structs<'a>(
+/// Get the unique vertex input structs of every vertex entry point in the
+/// module, in order of first use.
+pub fn get_vertex_input_structs<'a>(
   invoking_entry_module: &str,
   module: &'a naga::Module,
 ) -> Vec<VertexInput<'a>> {
@@ -241,8 +466,8 @@ pub fn get_all_vertex_input_structs<'a>(
         entry_point,
       );
       for input in inputs {
-        let type_name = input.item_path.name.clone();
-        if seen_types.insert(type_name) {
+        // Structs imported from different modules may share a name.
+        if seen_types.insert(input.item_path.get_fully_qualified_name()) {
           all_inputs.push(input);
         }
       }
@@ -338,6 +563,71 @@ mod tests {
     );
   }
 
+  fn global_stages_by_name(source: &str) -> Vec<(String, wgpu::ShaderStages)> {
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    module
+      .global_variables
+      .iter()
+      .map(|(handle, global)| {
+        (global.name.clone().unwrap(), global_shader_stages(&module, handle))
+      })
+      .collect()
+  }
+
+  #[test]
+  fn global_shader_stages_through_calls() {
+    let source = indoc! {r#"
+            @group(0) @binding(0) var<uniform> transform: mat4x4<f32>;
+            @group(0) @binding(1) var color_texture: texture_2d<f32>;
+            @group(0) @binding(2) var<storage, read_write> values: array<f32>;
+            @group(0) @binding(3) var<uniform> unused: vec4<f32>;
+
+            fn load_color() -> vec4<f32> {
+                return textureLoad(color_texture, vec2<i32>(0), 0);
+            }
+
+            fn shade() -> vec4<f32> {
+                var color = vec4<f32>(0.0);
+                if transform[0].x > 0.0 {
+                    color = load_color();
+                }
+                return color;
+            }
+
+            @vertex
+            fn vs_main() -> @builtin(position) vec4<f32> {
+                return transform * vec4<f32>(1.0);
+            }
+
+            @fragment
+            fn fs_main() -> @location(0) vec4<f32> {
+                return shade();
+            }
+
+            @compute
+            @workgroup_size(64)
+            fn cs_main() {
+                loop {
+                    values[0] = load_color().x;
+                    break;
+                }
+            }
+        "#};
+
+    assert_eq!(
+      vec![
+        ("transform".to_string(), wgpu::ShaderStages::VERTEX_FRAGMENT),
+        (
+          "color_texture".to_string(),
+          wgpu::ShaderStages::FRAGMENT | wgpu::ShaderStages::COMPUTE
+        ),
+        ("values".to_string(), wgpu::ShaderStages::COMPUTE),
+        ("unused".to_string(), wgpu::ShaderStages::NONE),
+      ],
+      global_stages_by_name(source)
+    );
+  }
+
   #[test]
   fn vertex_input_structs_two_structs() {
     let source = indoc! {r#"
@@ -382,4 +672,107 @@ mod tests {
     assert_eq!("in5", vertex_inputs[1].fields[2].1.name.as_ref().unwrap());
     assert_eq!(5, vertex_inputs[1].fields[2].0);
   }
+
+  #[test]
+  fn vertex_input_structs_every_vertex_entry() {
+    let source = indoc! {r#"
+            struct MeshVertex {
+                @location(0) position: vec3<f32>,
+            };
+
+            struct SpriteVertex {
+                @location(0) corner: vec2<f32>,
+                @location(1) uv: vec2<f32>,
+            };
+
+            @vertex
+            fn vs_mesh(vertex: MeshVertex, @location(1) tint: vec4<f32>) -> @builtin(position) vec4<f32> {
+                return vec4<f32>(vertex.position, 1.0) * tint;
+            }
+
+            @vertex
+            fn vs_sprite(vertex: SpriteVertex) -> @builtin(position) vec4<f32> {
+                return vec4<f32>(vertex.corner + vertex.uv, 0.0, 1.0);
+            }
+
+            @vertex
+            fn vs_mesh_again(vertex: MeshVertex) -> @builtin(position) vec4<f32> {
+                return vec4<f32>(vertex.position, 1.0);
+            }
+        "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+
+    let vertex_inputs = get_vertex_input_structs("", &module);
+    let names: Vec<_> = vertex_inputs
+      .iter()
+      .map(|input| input.item_path.name.as_str())
+      .collect();
+    assert_eq!(vec!["MeshVertex", "SpriteVertex"], names);
+
+    let primitives: Vec<_> = module
+      .entry_points
+      .iter()
+      .map(|entry_point| {
+        get_vertex_input_primitives_for_entry_point(&module, entry_point)
+          .into_iter()
+          .map(|input| (input.name, input.location))
+          .collect::<Vec<_>>()
+      })
+      .collect();
+    assert_eq!(vec![vec![("tint".to_string(), 1)], Vec::new(), Vec::new()], primitives);
+  }
+
+  #[test]
+  fn vertex_format_f16() {
+    let source = indoc! {r#"
+            enable f16;
+
+            struct VertexInput {
+                @location(0) a: f16,
+                @location(1) b: vec2<f16>,
+                @location(2) c: vec4<f16>,
+            };
+
+            @vertex
+            fn main(in: VertexInput) -> @builtin(position) vec4<f32> {
+                return vec4<f32>(0.0);
+            }
+        "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+
+    let vertex_inputs = get_vertex_input_structs("", &module);
+    let formats: Vec<_> = vertex_inputs[0]
+      .fields
+      .iter()
+      .map(|(_, member)| vertex_format(&module.types[member.ty]))
+      .collect();
+
+    assert_eq!(
+      vec![
+        wgpu::VertexFormat::Float16,
+        wgpu::VertexFormat::Float16x2,
+        wgpu::VertexFormat::Float16x4,
+      ],
+      formats
+    );
+    assert_eq!(wgpu::Features::SHADER_F16, required_features(&module));
+  }
+
+  #[test]
+  fn required_features_none() {
+    let source = indoc! {r#"
+            struct Uniforms {
+                a: vec4<f32>,
+            };
+            var<uniform> uniforms: Uniforms;
+
+            @fragment
+            fn main() {}
+        "#};
+
+    let module = naga::front::wgsl::parse_str(source).unwrap();
+    assert_eq!(wgpu::Features::empty(), required_features(&module));
+  }
 }
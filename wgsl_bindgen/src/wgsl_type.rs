@@ -22,6 +22,15 @@ pub enum WgslVecType {
   Vec2h,
   Vec3h,
   Vec4h,
+  Vec2d,
+  Vec3d,
+  Vec4d,
+  Vec2i64,
+  Vec3i64,
+  Vec4i64,
+  Vec2u64,
+  Vec3u64,
+  Vec4u64,
 }
 
 /// The `WgslType` enum represents various Wgsl matrices.
@@ -46,6 +55,40 @@ pub enum WgslMatType {
   Mat4x2h,
   Mat4x3h,
   Mat4x4h,
+  Mat2x2d,
+  Mat2x3d,
+  Mat2x4d,
+  Mat3x2d,
+  Mat3x3d,
+  Mat3x4d,
+  Mat4x2d,
+  Mat4x3d,
+  Mat4x4d,
+}
+
+impl WgslVecType {
+  pub(crate) fn is_f16(&self) -> bool {
+    use WgslVecType::*;
+    matches!(self, Vec2h | Vec3h | Vec4h)
+  }
+}
+
+impl WgslMatType {
+  pub(crate) fn is_f16(&self) -> bool {
+    use WgslMatType::*;
+    matches!(
+      self,
+      Mat2x2h
+        | Mat2x3h
+        | Mat2x4h
+        | Mat3x2h
+        | Mat3x3h
+        | Mat3x4h
+        | Mat4x2h
+        | Mat4x3h
+        | Mat4x4h
+    )
+  }
 }
 
 pub(crate) trait WgslTypeAlignmentAndSize {
@@ -58,10 +101,13 @@ impl WgslTypeAlignmentAndSize for WgslVecType {
     match self {
       Vec2i | Vec2u | Vec2f => (8, 8),
       Vec2h => (4, 4),
+      Vec2d | Vec2i64 | Vec2u64 => (16, 16),
       Vec3i | Vec3u | Vec3f => (16, 12),
       Vec3h => (8, 6),
+      Vec3d | Vec3i64 | Vec3u64 => (32, 24),
       Vec4i | Vec4u | Vec4f => (16, 16),
       Vec4h => (8, 8),
+      Vec4d | Vec4i64 | Vec4u64 => (32, 32),
     }
   }
 }
@@ -73,22 +119,31 @@ impl WgslTypeAlignmentAndSize for WgslMatType {
       // AlignOf(vecR), SizeOf(array<vecR, C>)
       Mat2x2f => (8, 16),
       Mat2x2h => (4, 8),
+      Mat2x2d => (16, 32),
       Mat3x2f => (8, 24),
       Mat3x2h => (4, 12),
+      Mat3x2d => (16, 48),
       Mat4x2f => (8, 32),
       Mat4x2h => (4, 16),
+      Mat4x2d => (16, 64),
       Mat2x3f => (16, 32),
       Mat2x3h => (8, 16),
+      Mat2x3d => (32, 64),
       Mat3x3f => (16, 48),
       Mat3x3h => (8, 24),
+      Mat3x3d => (32, 96),
       Mat4x3f => (16, 64),
       Mat4x3h => (8, 32),
+      Mat4x3d => (32, 128),
       Mat2x4f => (16, 32),
       Mat2x4h => (8, 16),
+      Mat2x4d => (32, 64),
       Mat3x4f => (16, 48),
       Mat3x4h => (8, 24),
+      Mat3x4d => (32, 96),
       Mat4x4f => (16, 64),
       Mat4x4h => (8, 32),
+      Mat4x4d => (32, 128),
     }
   }
 }
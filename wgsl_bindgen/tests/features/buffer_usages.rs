@@ -0,0 +1,54 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const BUFFER_USAGES_DIR: &str = "tests/shaders/features/buffer_usages";
+
+#[test]
+fn test_buffer_usages_follow_bindings_across_shaders() -> Result<()> {
+  let actual =
+    feature_test_builder(BUFFER_USAGES_DIR, WgslTypeSerializeStrategy::Bytemuck)
+      .entry_points(vec![
+        format!("{BUFFER_USAGES_DIR}/render.wgsl"),
+        format!("{BUFFER_USAGES_DIR}/simulate.wgsl"),
+      ])
+      .type_map(GlamWgslTypeMap)
+      .build()?
+      .generate_string()
+      .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    use wgpu::BufferUsages;
+
+    #[test]
+    fn structs_get_the_usages_of_every_binding() {
+      // Bound as a uniform in one shader and as a storage buffer in the other.
+      assert_eq!(
+        common::Camera::BUFFER_USAGES,
+        BufferUsages::COPY_DST | BufferUsages::UNIFORM | BufferUsages::STORAGE
+      );
+      // A vertex input, and bound as a storage buffer on its own and as an
+      // array.
+      assert_eq!(
+        common::Particle::BUFFER_USAGES,
+        BufferUsages::COPY_DST | BufferUsages::VERTEX | BufferUsages::STORAGE
+      );
+      assert_eq!(
+        common::Light::BUFFER_USAGES,
+        BufferUsages::COPY_DST | BufferUsages::STORAGE
+      );
+      assert_eq!(
+        common::Instance::BUFFER_USAGES,
+        BufferUsages::COPY_DST | BufferUsages::VERTEX
+      );
+      assert_eq!(
+        common::Trail::BUFFER_USAGES,
+        BufferUsages::COPY_DST | BufferUsages::STORAGE
+      );
+    }
+  });
+  Ok(())
+}
@@ -0,0 +1,33 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+#[test]
+fn test_wgsl_doc_comments_are_propagated() -> Result<()> {
+  let actual = WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/doc_comments")
+    .add_entry_point("tests/shaders/features/doc_comments/lighting.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .type_map(GlamWgslTypeMap)
+    .shader_source_type(WgslShaderSourceType::EmbedSource)
+    .derive_serde(false)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  assert!(actual.contains(r#"#[doc = " Scene wide lighting parameters."]"#));
+  assert!(actual.contains(r#"#[doc = " Light position in world space."]"#));
+  assert!(
+    actual.contains(r#"#[doc = " Upper bound on the number of lights in a scene."]"#)
+  );
+  assert!(actual.contains(r#"#[doc = " Exposure applied before tonemapping."]"#));
+  assert!(actual.contains(r#"#[doc = " Accumulates the contribution of every light."]"#));
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
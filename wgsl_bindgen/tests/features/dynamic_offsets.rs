@@ -0,0 +1,233 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+fn generate_dynamic_offsets(
+  strategy: WgslTypeSerializeStrategy,
+  type_map: impl WgslTypeMapBuild,
+) -> Result<String> {
+  WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/dynamic_offsets")
+    .add_entry_point("tests/shaders/features/dynamic_offsets/mesh.wgsl")
+    .add_entry_point("tests/shaders/features/dynamic_offsets/sky.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(strategy)
+    .type_map(type_map)
+    .override_dynamic_offset(["^frame::camera$".into(), "^mesh::(material|skin)$".into()])
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()?
+    .generate_string()
+    .into_diagnostic()
+}
+
+#[test]
+fn test_dynamic_offsets() -> Result<()> {
+  let actual =
+    generate_dynamic_offsets(WgslTypeSerializeStrategy::Bytemuck, GlamWgslTypeMap)?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    struct RecordGroups(Vec<(u32, Vec<wgpu::DynamicOffset>)>);
+
+    impl _root::SetBindGroup for RecordGroups {
+      fn set_bind_group(
+        &mut self,
+        index: u32,
+        _bind_group: &wgpu::BindGroup,
+        offsets: &[wgpu::DynamicOffset],
+      ) {
+        self.0.push((index, offsets.to_vec()));
+      }
+    }
+
+    fn buffer(
+      device: &wgpu::Device,
+      size: u64,
+      usage: wgpu::BufferUsages,
+    ) -> wgpu::Buffer {
+      device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size,
+        usage,
+        mapped_at_creation: false,
+      })
+    }
+
+    fn dynamic_bindings(layout: &wgpu::BindGroupLayoutDescriptor) -> Vec<u32> {
+      let mut bindings = layout
+        .entries
+        .iter()
+        .filter(|entry| {
+          matches!(
+            entry.ty,
+            wgpu::BindingType::Buffer {
+              has_dynamic_offset: true,
+              ..
+            }
+          )
+        })
+        .map(|entry| entry.binding)
+        .collect::<Vec<_>>();
+      bindings.sort();
+      bindings
+    }
+
+    #[test]
+    fn only_the_matched_bindings_are_dynamic() {
+      assert_eq!(dynamic_bindings(&frame::WgpuBindGroup0::LAYOUT_DESCRIPTOR), [1]);
+      assert_eq!(dynamic_bindings(&mesh::WgpuBindGroup1::LAYOUT_DESCRIPTOR), [0, 1]);
+    }
+
+    // Setting every group takes the offsets of each dynamic one, in binding
+    // order rather than declaration order.
+    #[test]
+    fn bind_groups_are_set_with_their_offsets() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let uniform = wgpu::BufferUsages::UNIFORM;
+      let globals = buffer(&device, frame::Globals::SIZE, uniform);
+      let camera = buffer(&device, 2 * 256, uniform);
+      let material = buffer(&device, 2 * 256, uniform);
+      let skin = buffer(&device, 2 * 256, wgpu::BufferUsages::STORAGE);
+      let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+          width: 1,
+          height: 1,
+          depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+      });
+      let color_texture = texture.create_view(&Default::default());
+
+      let bind_group0 = frame::WgpuBindGroup0::from_bindings(
+        &device,
+        frame::WgpuBindGroup0Entries::new(frame::WgpuBindGroup0EntriesParams {
+          globals: globals.as_entire_buffer_binding(),
+          camera: wgpu::BufferBinding {
+            buffer: &camera,
+            offset: 0,
+            size: wgpu::BufferSize::new(frame::Camera::SIZE),
+          },
+        }),
+      );
+      let bind_group1 = mesh::WgpuBindGroup1::from_bindings(
+        &device,
+        mesh::WgpuBindGroup1Entries::new(mesh::WgpuBindGroup1EntriesParams {
+          material: wgpu::BufferBinding {
+            buffer: &material,
+            offset: 0,
+            size: wgpu::BufferSize::new(mesh::Material::SIZE),
+          },
+          skin: wgpu::BufferBinding {
+            buffer: &skin,
+            offset: 0,
+            size: wgpu::BufferSize::new(64),
+          },
+          color_texture: &color_texture,
+        }),
+      );
+
+      let mut groups = RecordGroups(Vec::new());
+      mesh::WgpuBindGroups {
+        bind_group0: &bind_group0,
+        bind_group1: &bind_group1,
+      }
+      .set(
+        &mut groups,
+        frame::WgpuBindGroup0DynamicOffsets { camera: 256 },
+        mesh::WgpuBindGroup1DynamicOffsets {
+          skin: 256,
+          material: 512,
+        },
+      );
+      assert_eq!(groups.0, [(0, vec![256]), (1, vec![256, 512])]);
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_uniform_ring_packs_bound_structs() -> Result<()> {
+  let actual =
+    generate_dynamic_offsets(WgslTypeSerializeStrategy::Bytemuck, GlamWgslTypeMap)?;
+
+  // `Globals` is bound without dynamic offsets, so it is never packed in a ring.
+  assert!(!actual.contains("impl uniform_ring::UniformRingElement for Globals {"));
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output, {
+    fn material(red: f32) -> mesh::Material {
+      mesh::Material::new(glam::vec4(red, 0.0, 0.0, 1.0))
+    }
+
+    #[test]
+    fn values_are_one_aligned_stride_apart() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let limits = device.limits();
+      assert_eq!(
+        mesh::Material::aligned_stride(&limits),
+        limits.min_uniform_buffer_offset_alignment as wgpu::BufferAddress
+      );
+
+      let ring = uniform_ring::UniformRing::<mesh::Material>::new(&device, 4);
+      assert_eq!(ring.stride(), mesh::Material::aligned_stride(&limits));
+      assert_eq!(ring.buffer().size(), 4 * ring.stride());
+      assert_eq!(ring.binding().size, wgpu::BufferSize::new(mesh::Material::SIZE));
+    }
+
+    #[test]
+    fn writes_advance_through_the_ring_until_reset() {
+      let (device, queue) = wgpu::Device::noop(&Default::default());
+      let mut ring = uniform_ring::UniformRing::<frame::Camera>::new(&device, 4);
+      let stride = ring.stride() as wgpu::DynamicOffset;
+      let camera = frame::Camera::new(glam::Mat4::IDENTITY);
+
+      assert_eq!(ring.write(&queue, &[camera; 3]), Some(vec![0, stride, 2 * stride]));
+      // Two more values do not fit before the end, and nothing is overwritten.
+      assert_eq!(ring.write(&queue, &[camera; 2]), None);
+      assert_eq!(ring.write(&queue, &[camera]), Some(vec![3 * stride]));
+
+      // After the writes are submitted, the ring starts over.
+      queue.submit([]);
+      ring.reset();
+      assert_eq!(ring.write(&queue, &[camera; 2]), Some(vec![0, stride]));
+
+      let mut materials = uniform_ring::UniformRing::<mesh::Material>::new(&device, 2);
+      assert_eq!(materials.write(&queue, &[material(1.0)]), Some(vec![0]));
+      assert_eq!(materials.write(&queue, &[material(0.5)]), Some(vec![stride]));
+    }
+
+    #[test]
+    fn more_values_than_the_capacity_do_not_fit() {
+      let (device, queue) = wgpu::Device::noop(&Default::default());
+      let mut ring = uniform_ring::UniformRing::<mesh::Material>::new(&device, 2);
+      assert_eq!(ring.write(&queue, &[material(1.0); 3]), None);
+      assert_eq!(
+        ring
+          .write(&queue, &[material(1.0); 2])
+          .map(|offsets| offsets.len()),
+        Some(2)
+      );
+    }
+  });
+  Ok(())
+}
+
+// encase writes the uniforms of the Rust type map with array strides it
+// rejects, so the encase ring is only compiled.
+#[test]
+fn test_uniform_ring_encase() -> Result<()> {
+  let actual =
+    generate_dynamic_offsets(WgslTypeSerializeStrategy::Encase, RustWgslTypeMap)?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
@@ -0,0 +1,35 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+#[test]
+fn test_f16_storage_and_vertex_inputs() -> Result<()> {
+  let actual = WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/f16")
+    .add_entry_point("tests/shaders/features/f16/f16_particles.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .type_map(GlamWgslTypeMap)
+    .ir_capabilities(naga::valid::Capabilities::SHADER_FLOAT16)
+    .shader_source_type(WgslShaderSourceType::EmbedSource)
+    .derive_serde(false)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  assert!(actual.contains(
+    "pub const REQUIRED_FEATURES: wgpu::Features = wgpu::Features::SHADER_F16;"
+  ));
+  assert!(actual.contains("pub position: [half::f16; 3]"));
+  assert!(actual.contains("pub size: half::f16"));
+  assert!(actual.contains("format: wgpu::VertexFormat::Float16x2"));
+  assert!(actual.contains("format: wgpu::VertexFormat::Float16x4"));
+  assert!(actual.contains("format: wgpu::VertexFormat::Float16,"));
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
@@ -0,0 +1,91 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+fn generate_frame(
+  serialization_strategy: WgslTypeSerializeStrategy,
+  type_map: impl WgslTypeMapBuild,
+) -> Result<String> {
+  feature_test_builder("tests/shaders/features/field_layout", serialization_strategy)
+    .add_entry_point("tests/shaders/features/field_layout/frame.wgsl")
+    .type_map(type_map)
+    .shader_source_type(WgslShaderSourceType::EmbedSource)
+    .build()?
+    .generate_string()
+    .into_diagnostic()
+}
+
+#[test]
+fn test_field_offset_and_size_consts() -> Result<()> {
+  let actual = generate_frame(WgslTypeSerializeStrategy::Bytemuck, GlamWgslTypeMap)?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn consts_match_the_wgsl_layout() {
+      use frame::Frame;
+      assert_eq!((Frame::OFFSET_CAMERA_POS, Frame::SIZE_CAMERA_POS), (64, 12));
+      assert_eq!((Frame::OFFSET_TIME, Frame::SIZE_TIME), (76, 4));
+      assert_eq!((Frame::OFFSET_LIGHT_DIRS, Frame::SIZE_LIGHT_DIRS), (80, 64));
+      assert_eq!((Frame::OFFSET_EXPOSURE, Frame::SIZE_EXPOSURE), (144, 4));
+      assert_eq!(Frame::SIZE, 160);
+      assert_eq!(Frame::SIZE as usize, std::mem::size_of::<Frame>());
+    }
+
+    #[test]
+    fn fields_sit_at_their_offsets() {
+      use frame::Frame;
+      let mut value: Frame = bytemuck::Zeroable::zeroed();
+      value.camera_pos = glam::Vec3::new(1.0, 2.0, 3.0);
+      value.exposure = 4.0;
+      let bytes = bytemuck::bytes_of(&value);
+      let camera_pos = Frame::OFFSET_CAMERA_POS as usize;
+      assert_eq!(
+        &bytes[camera_pos..camera_pos + Frame::SIZE_CAMERA_POS as usize],
+        bytemuck::bytes_of(&[1.0f32, 2.0, 3.0])
+      );
+      let exposure = Frame::OFFSET_EXPOSURE as usize;
+      assert_eq!(&bytes[exposure..exposure + 4], bytemuck::bytes_of(&4.0f32));
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_encase_field_writers_share_the_bytes_helper() -> Result<()> {
+  let actual = feature_test_builder(
+    "tests/shaders/features/field_layout",
+    WgslTypeSerializeStrategy::Encase,
+  )
+  .add_entry_point("tests/shaders/features/field_layout/material.wgsl")
+  .build()?
+  .generate_string()
+  .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn uniform_bytes_follow_the_field_consts() {
+      use material::Material;
+      let value = Material {
+        roughness: 0.5,
+        metallic: 1.0,
+        layer: 2,
+        flags: 3,
+      };
+      let bytes = encase_bytes::uniform_bytes(&value).unwrap();
+      assert_eq!(bytes.len(), Material::SIZE as usize);
+      assert_eq!(bytes, encase_bytes::storage_bytes(&value).unwrap());
+
+      let read = |offset: u64| {
+        let offset = offset as usize;
+        <[u8; 4]>::try_from(&bytes[offset..offset + 4]).unwrap()
+      };
+      assert_eq!(read(Material::OFFSET_METALLIC), 1.0f32.to_le_bytes());
+      assert_eq!(read(Material::OFFSET_FLAGS), 3u32.to_le_bytes());
+    }
+  });
+  Ok(())
+}
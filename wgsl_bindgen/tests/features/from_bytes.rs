@@ -0,0 +1,214 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::*;
+
+const FROM_BYTES_DIR: &str = "tests/shaders/features/from_bytes";
+
+fn generate_readback(
+  strategy: WgslTypeSerializeStrategy,
+  type_map: impl WgslTypeMapBuild + 'static,
+) -> Result<proc_macro2::TokenStream> {
+  let actual = feature_test_builder(FROM_BYTES_DIR, strategy)
+    .add_entry_point(format!("{FROM_BYTES_DIR}/readback.wgsl"))
+    .type_map(type_map)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+  Ok(parse_str(&actual).unwrap())
+}
+
+// `Samples` is a `mat4x4<f32>` followed by a runtime-sized `array<f32>`, so it
+// is 16-byte aligned. WGSL reads three values from a 76-byte buffer, but a
+// `Samples` with three values is 80 bytes long with its trailing padding.
+
+#[test]
+fn test_from_bytes_bytemuck() -> Result<()> {
+  let output = generate_readback(WgslTypeSerializeStrategy::Bytemuck, GlamWgslTypeMap)?;
+  assert_rust_compilation!(output, {
+    #[repr(C, align(16))]
+    struct Aligned([u8; 96]);
+
+    fn samples_bytes() -> Aligned {
+      let mut bytes = Aligned([0; 96]);
+      bytes.0[..4].copy_from_slice(&2.0f32.to_ne_bytes());
+      for (i, value) in [1.0f32, 2.0, 3.0, 4.0, 5.0].iter().enumerate() {
+        bytes.0[64 + i * 4..68 + i * 4].copy_from_slice(&value.to_ne_bytes());
+      }
+      bytes
+    }
+
+    #[test]
+    fn runtime_sized_tails_get_the_elements_that_fit() {
+      let bytes = samples_bytes();
+      let samples = readback::Samples::from_bytes(&bytes.0[..76]).unwrap();
+      assert_eq!(samples.values, []);
+      assert_eq!(samples.transform.x_axis.x, 2.0);
+
+      let samples = readback::Samples::from_bytes(&bytes.0[..87]).unwrap();
+      assert_eq!(samples.values, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn tail_from_bytes_splits_the_fixed_size_fields() {
+      let bytes = samples_bytes();
+      let (header, values) = readback::Samples::tail_from_bytes(&bytes.0[..76]).unwrap();
+      assert_eq!(header.transform.x_axis.x, 2.0);
+      assert_eq!(values, [1.0, 2.0, 3.0]);
+
+      let (_, values) = readback::Samples::tail_from_bytes(&bytes.0[..64]).unwrap();
+      assert_eq!(values, []);
+    }
+
+    #[test]
+    fn too_short_and_misaligned_bytes_are_errors() {
+      let bytes = samples_bytes();
+      assert_eq!(
+        readback::Samples::from_bytes(&bytes.0[..60]).unwrap_err(),
+        readback::LayoutError::TooShort {
+          expected: 64,
+          actual: 60
+        }
+      );
+      assert_eq!(
+        readback::Samples::from_bytes(&bytes.0[4..80]).unwrap_err(),
+        readback::LayoutError::Misaligned { alignment: 16 }
+      );
+    }
+
+    #[test]
+    fn sized_structs_ignore_trailing_bytes() {
+      let mut bytes = Aligned([0; 96]);
+      bytes.0[8..12].copy_from_slice(&7u32.to_ne_bytes());
+      let stats = readback::Stats::from_bytes(&bytes.0[..20]).unwrap();
+      assert_eq!(stats.count, 7);
+      assert_eq!(
+        readback::Stats::from_bytes(&bytes.0[..12]).unwrap_err(),
+        readback::LayoutError::TooShort {
+          expected: 16,
+          actual: 12
+        }
+      );
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_from_bytes_zerocopy() -> Result<()> {
+  let output = generate_readback(WgslTypeSerializeStrategy::Zerocopy, GlamWgslTypeMap)?;
+  assert_rust_compilation!(output, {
+    #[repr(C, align(16))]
+    struct Aligned([u8; 96]);
+
+    fn samples_bytes() -> Aligned {
+      let mut bytes = Aligned([0; 96]);
+      bytes.0[..4].copy_from_slice(&2.0f32.to_ne_bytes());
+      for (i, value) in [1.0f32, 2.0, 3.0, 4.0, 5.0].iter().enumerate() {
+        bytes.0[64 + i * 4..68 + i * 4].copy_from_slice(&value.to_ne_bytes());
+      }
+      bytes
+    }
+
+    #[test]
+    fn runtime_sized_tails_get_the_elements_that_fit() {
+      let bytes = samples_bytes();
+      let samples = readback::Samples::from_bytes(&bytes.0[..76]).unwrap();
+      assert_eq!(&samples.values, []);
+      assert_eq!(samples.transform.x_axis.x, 2.0);
+
+      let samples = readback::Samples::from_bytes(&bytes.0[..87]).unwrap();
+      assert_eq!(&samples.values, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn tail_from_bytes_splits_the_fixed_size_fields() {
+      let bytes = samples_bytes();
+      let (header, values) = readback::Samples::tail_from_bytes(&bytes.0[..76]).unwrap();
+      assert_eq!(header.transform.x_axis.x, 2.0);
+      assert_eq!(&header.values, []);
+      assert_eq!(values, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn too_short_and_misaligned_bytes_are_errors() {
+      let bytes = samples_bytes();
+      assert_eq!(
+        readback::Samples::from_bytes(&bytes.0[..60]).unwrap_err(),
+        readback::LayoutError::TooShort {
+          expected: 64,
+          actual: 60
+        }
+      );
+      assert_eq!(
+        readback::Samples::from_bytes(&bytes.0[4..80]).unwrap_err(),
+        readback::LayoutError::Misaligned { alignment: 16 }
+      );
+    }
+
+    #[test]
+    fn sized_structs_ignore_trailing_bytes() {
+      let mut bytes = Aligned([0; 96]);
+      bytes.0[8..12].copy_from_slice(&7u32.to_ne_bytes());
+      let stats = readback::Stats::from_bytes(&bytes.0[..20]).unwrap();
+      assert_eq!(stats.count, 7);
+      assert_eq!(
+        readback::Stats::from_bytes(&bytes.0[..12]).unwrap_err(),
+        readback::LayoutError::TooShort {
+          expected: 16,
+          actual: 12
+        }
+      );
+    }
+  });
+  Ok(())
+}
+
+// encase implements no math crate types, so it reads the Rust type map.
+#[test]
+fn test_from_bytes_encase() -> Result<()> {
+  let output = generate_readback(WgslTypeSerializeStrategy::Encase, RustWgslTypeMap)?;
+  assert_rust_compilation!(output, {
+    fn samples_bytes() -> Vec<u8> {
+      let mut bytes = vec![0; 96];
+      bytes[..4].copy_from_slice(&2.0f32.to_ne_bytes());
+      for (i, value) in [1.0f32, 2.0, 3.0, 4.0, 5.0].iter().enumerate() {
+        bytes[64 + i * 4..68 + i * 4].copy_from_slice(&value.to_ne_bytes());
+      }
+      bytes
+    }
+
+    #[test]
+    fn runtime_sized_tails_get_the_elements_that_fit() {
+      let bytes = samples_bytes();
+      let samples = readback::Samples::from_bytes(&bytes[..76]).unwrap();
+      assert_eq!(samples.values, [1.0, 2.0, 3.0]);
+      assert_eq!(samples.transform[0][0], 2.0);
+    }
+
+    #[test]
+    fn too_short_bytes_are_errors() {
+      let bytes = samples_bytes();
+      assert!(matches!(
+        readback::Samples::from_bytes(&bytes[..60]),
+        Err(readback::LayoutError::TooShort { actual: 60, .. })
+      ));
+      assert!(matches!(
+        readback::Stats::from_bytes(&bytes[..12]),
+        Err(readback::LayoutError::TooShort {
+          expected: 16,
+          actual: 12
+        })
+      ));
+    }
+
+    #[test]
+    fn sized_structs_ignore_trailing_bytes() {
+      let mut bytes = vec![0; 20];
+      bytes[8..12].copy_from_slice(&7u32.to_ne_bytes());
+      let stats = readback::Stats::from_bytes(&bytes).unwrap();
+      assert_eq!(stats.count, 7);
+    }
+  });
+  Ok(())
+}
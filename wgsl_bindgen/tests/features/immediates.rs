@@ -0,0 +1,131 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const IMMEDIATES_DIR: &str = "tests/shaders/features/immediates";
+
+// `immediates.wgsl` has two immediate variables: `draw`, read by the render
+// entry points, and `dispatch`, read by the compute entry point. The tests
+// record passes on wgpu's noop backend, which checks that every byte a
+// pipeline reads was set before it draws or dispatches.
+#[test]
+fn test_immediates() -> Result<()> {
+  let actual = feature_test_builder(IMMEDIATES_DIR, WgslTypeSerializeStrategy::Bytemuck)
+    .add_entry_point(format!("{IMMEDIATES_DIR}/immediates.wgsl"))
+    .type_map(GlamWgslTypeMap)
+    .ir_capabilities(naga::valid::Capabilities::IMMEDIATES)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  let parsed_output: proc_macro2::TokenStream = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    // The queue is kept alive, as encoders are created from it.
+    fn device() -> (wgpu::Device, wgpu::Queue) {
+      wgpu::Device::noop(&wgpu::DeviceDescriptor {
+        required_features: wgpu::Features::IMMEDIATES,
+        required_limits: wgpu::Limits {
+          max_immediate_size: 128,
+          ..Default::default()
+        },
+        ..Default::default()
+      })
+    }
+
+    // Every entry point shares the pipeline layout, and so this bind group.
+    fn bind_group(device: &wgpu::Device) -> immediates::WgpuBindGroup0 {
+      let values = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 256,
+        usage: wgpu::BufferUsages::STORAGE,
+        mapped_at_creation: false,
+      });
+      immediates::WgpuBindGroup0::from_bindings(
+        device,
+        immediates::WgpuBindGroup0Entries::new(immediates::WgpuBindGroup0EntriesParams {
+          values: values.as_entire_buffer_binding(),
+        }),
+      )
+    }
+
+    #[test]
+    fn stages_follow_the_entry_points_reading_each_variable() {
+      // `draw` is read by the fragment stage through a helper function.
+      assert_eq!(immediates::DRAW_IMMEDIATE_STAGES, wgpu::ShaderStages::VERTEX_FRAGMENT);
+      assert_eq!(immediates::DISPATCH_IMMEDIATE_STAGES, wgpu::ShaderStages::COMPUTE);
+    }
+
+    #[test]
+    fn compute_pass_dispatches_with_its_immediates() {
+      let (device, _queue) = device();
+      let pipeline = immediates::compute::create_cs_main_pipeline_embed_source(&device);
+      let bind_group = bind_group(&device);
+
+      let mut encoder = device.create_command_encoder(&Default::default());
+      let mut pass = encoder.begin_compute_pass(&Default::default());
+      pass.set_pipeline(&pipeline);
+      bind_group.set(&mut pass);
+      immediates::set_dispatch_immediates(
+        &mut pass,
+        &immediates::DispatchImmediates::new(2.0, 64),
+      );
+      pass.dispatch_workgroups(1, 1, 1);
+      drop(pass);
+      encoder.finish();
+    }
+
+    // The layout holds the largest variable, so `draw` fits the same range
+    // as `dispatch`.
+    #[test]
+    fn render_bundle_draws_with_its_immediates() {
+      let (device, _queue) = device();
+      let module = immediates::create_shader_module_embed_source(&device);
+      let layout = immediates::create_pipeline_layout(&device);
+      let format = wgpu::TextureFormat::Rgba8Unorm;
+      let vertex_entry = immediates::vs_main_entry(wgpu::VertexStepMode::Vertex);
+      let fragment_entry = immediates::fs_main_entry([Some(format.into())]);
+      let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: None,
+        layout: Some(&layout),
+        vertex: immediates::vertex_state(&module, &vertex_entry),
+        primitive: Default::default(),
+        depth_stencil: None,
+        multisample: Default::default(),
+        fragment: Some(immediates::fragment_state(&module, &fragment_entry)),
+        multiview_mask: None,
+        cache: None,
+      });
+      let positions = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 3 * 12,
+        usage: wgpu::BufferUsages::VERTEX,
+        mapped_at_creation: false,
+      });
+      let bind_group = bind_group(&device);
+
+      let mut encoder =
+        device.create_render_bundle_encoder(&wgpu::RenderBundleEncoderDescriptor {
+          label: None,
+          color_formats: &[Some(format)],
+          depth_stencil: None,
+          sample_count: 1,
+          multiview: None,
+        });
+      encoder.set_pipeline(&pipeline);
+      bind_group.set(&mut encoder);
+      immediates::VsMainVertexBuffers {
+        position: positions.slice(..),
+      }
+      .set(&mut encoder);
+      immediates::set_draw_immediates(
+        &mut encoder,
+        &immediates::DrawImmediates::new(glam::Mat4::IDENTITY, glam::Vec4::ONE),
+      );
+      encoder.draw(0..3, 0..1);
+      encoder.finish(&Default::default());
+    }
+  });
+  Ok(())
+}
@@ -0,0 +1,74 @@
+use std::fs::read_to_string;
+
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+fn generate_layout_report(
+  strategy: WgslTypeSerializeStrategy,
+  output: &str,
+) -> Result<(String, String)> {
+  WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/field_layout")
+    .add_entry_point("tests/shaders/features/field_layout/frame.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(strategy)
+    .type_map(GlamWgslTypeMap)
+    .shader_source_type(WgslShaderSourceType::EmbedSource)
+    .layout_report(WgslLayoutReport::DocComments | WgslLayoutReport::Json)
+    .derive_serde(false)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .output(format!("tests/output/features/{output}.actual.rs"))
+    .build()?
+    .generate()
+    .into_diagnostic()?;
+
+  let actual =
+    read_to_string(format!("tests/output/features/{output}.actual.rs")).unwrap();
+  let json =
+    read_to_string(format!("tests/output/features/{output}.actual.layout.json")).unwrap();
+  Ok((actual, json))
+}
+
+#[test]
+fn test_layout_report_bytemuck() -> Result<()> {
+  let (actual, json) = generate_layout_report(
+    WgslTypeSerializeStrategy::Bytemuck,
+    "layout_report_bytemuck",
+  )?;
+
+  assert!(actual.contains(r#"#[doc = " WGSL layout: size 160, alignment 16."]"#));
+  assert!(
+    actual.contains(r#"#[doc = " | `camera_pos` | `vec3<f32>` | 64 | 12 | 16 | 0 |"]"#)
+  );
+  assert!(actual.contains(r#"#[doc = " | `exposure` | `f32` | 144 | 4 | 4 | 12 |"]"#));
+  assert_eq!(
+    json,
+    concat!(
+      "{\"structs\":[\n",
+      r#"  {"name":"frame::Frame","size":160,"alignment":16,"members":["#,
+      r#"{"name":"view_proj","type":"mat4x4<f32>","offset":0,"size":64,"stride":null,"alignment":16,"padding":0},"#,
+      r#"{"name":"camera_pos","type":"vec3<f32>","offset":64,"size":12,"stride":null,"alignment":16,"padding":0},"#,
+      r#"{"name":"time","type":"f32","offset":76,"size":4,"stride":null,"alignment":4,"padding":0},"#,
+      r#"{"name":"light_dirs","type":"array<vec4<f32>, 4>","offset":80,"size":64,"stride":null,"alignment":16,"padding":0},"#,
+      r#"{"name":"exposure","type":"f32","offset":144,"size":4,"stride":null,"alignment":4,"padding":12}"#,
+      "]}\n]}\n",
+    )
+  );
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
+
+#[test]
+fn test_layout_report_encase() -> Result<()> {
+  let (actual, json) =
+    generate_layout_report(WgslTypeSerializeStrategy::Encase, "layout_report_encase")?;
+
+  assert!(actual.contains(r#"#[doc = " WGSL layout: size 160, alignment 16."]"#));
+  assert!(json.contains(r#""name":"frame::Frame","size":160"#));
+  Ok(())
+}
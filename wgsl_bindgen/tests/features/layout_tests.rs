@@ -0,0 +1,86 @@
+use miette::{IntoDiagnostic, Result};
+use quote::quote;
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const LAYOUT_TESTS_DIR: &str = "tests/shaders/features/layout_tests";
+
+fn layout_tests_builder(
+  strategy: WgslTypeSerializeStrategy,
+  entry: &str,
+) -> WgslBindgenOptionBuilder {
+  let mut builder = feature_test_builder(LAYOUT_TESTS_DIR, strategy);
+  builder
+    .add_entry_point(format!("{LAYOUT_TESTS_DIR}/{entry}"))
+    .shader_source_type(WgslShaderSourceType::EmbedSource)
+    .layout_tests(true);
+  builder
+}
+
+// The generated `layout_tests` run with the tests of the compiled crate.
+
+#[test]
+fn test_layout_tests_bytemuck() -> Result<()> {
+  let actual = layout_tests_builder(WgslTypeSerializeStrategy::Bytemuck, "scene.wgsl")
+    .type_map(GlamWgslTypeMap)
+    .override_struct_field_type(
+      vec![("scene::Scene", "ambient", quote!([f32; 3])).into()],
+    )
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
+
+#[test]
+fn test_layout_tests_zerocopy() -> Result<()> {
+  let actual = layout_tests_builder(WgslTypeSerializeStrategy::Zerocopy, "scene.wgsl")
+    .type_map(GlamWgslTypeMap)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
+
+// encase implements no math crate types, and the arrays of the Rust type map
+// only match the WGSL layout of naturally aligned vectors, so the particles
+// have no `vec3`.
+#[test]
+fn test_layout_tests_encase() -> Result<()> {
+  let actual = layout_tests_builder(WgslTypeSerializeStrategy::Encase, "particles.wgsl")
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
+
+// Fields without sentinel bytes, like `bool`, are left out of the checks
+// instead of dropping the struct's test.
+#[test]
+fn test_layout_tests_skip_fields_without_host_layout() -> Result<()> {
+  let actual =
+    layout_tests_builder(WgslTypeSerializeStrategy::Bytemuck, "workgroup.wgsl")
+      .type_map(GlamWgslTypeMap)
+      .build()?
+      .generate_string()
+      .into_diagnostic()?;
+
+  assert!(actual.contains("fn workgroup_tile()"));
+  assert!(actual.contains(
+    "`visible` has no host-shareable layout, so it is left zeroed and not checked."
+  ));
+  let parsed_output: proc_macro2::TokenStream = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
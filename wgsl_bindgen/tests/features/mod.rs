@@ -1,2 +1,22 @@
+mod buffer_usages;
+mod doc_comments;
+mod dynamic_offsets;
+mod f16;
+mod field_layout;
+mod from_bytes;
+mod immediates;
+mod layout_report;
+mod layout_tests;
+mod multiple_vertex_entries;
+mod override_sized_arrays;
+mod padded_field_accessors;
 mod shader_defs;
 mod shared_bind_groups;
+mod type_maps;
+mod typed_buffer_bindings;
+mod typed_texture_views;
+mod uniform_layout;
+mod vertex_buffers;
+mod vertex_formats;
+mod vertex_pulling;
+mod zerocopy;
@@ -0,0 +1,39 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+#[test]
+fn test_multiple_vertex_entries_with_disjoint_inputs() -> Result<()> {
+  let actual = WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/multiple_vertex_entries")
+    .add_entry_point("tests/shaders/features/multiple_vertex_entries/scene.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .type_map(GlamWgslTypeMap)
+    .override_vertex_step_mode([(".*Instance$", VertexStepMode::Instance).into()])
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  // Each entry only takes the inputs it declares.
+  assert!(actual.contains("-> VertexEntry<2>"));
+  assert!(actual.contains("Some(MeshVertex::vertex_buffer_layout(mesh_vertex))"));
+  assert!(actual.contains("-> VertexEntry<3>"));
+  assert!(actual.contains("Some(SpriteVertex::vertex_buffer_layout(sprite_vertex))"));
+  assert!(actual.contains("Some(SpriteInstance::VERTEX_BUFFER_LAYOUT)"));
+  assert!(actual.contains("pub struct VsMeshVertexBuffers<'a>"));
+  assert!(actual.contains("pub struct VsSpriteVertexBuffers<'a>"));
+
+  // Every vertex input struct is generated once, whichever entry uses it.
+  for name in ["MeshVertex", "SpriteVertex", "SpriteInstance"] {
+    assert_eq!(actual.matches(&format!("pub struct {name} ")).count(), 1);
+  }
+  assert_eq!(actual.matches("pub const VERTEX_ATTRIBUTES").count(), 3);
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
@@ -0,0 +1,91 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const OVERRIDE_SIZED_ARRAYS_DIR: &str = "tests/shaders/features/override_sized_arrays";
+
+#[test]
+fn test_override_sized_workgroup_arrays() -> Result<()> {
+  let actual =
+    feature_test_builder(OVERRIDE_SIZED_ARRAYS_DIR, WgslTypeSerializeStrategy::Bytemuck)
+      .add_entry_point("tests/shaders/features/override_sized_arrays/reduce.wgsl")
+      .shader_source_type(WgslShaderSourceType::EmbedSource)
+      .build()?
+      .generate_string()
+      .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn override_defaults_size_the_arrays() {
+      let overrides = reduce::OverrideConstants::default();
+      assert_eq!(reduce::OverrideConstants::DEFAULT_BLOCK_SIZE, 64);
+      assert!(overrides.constants().is_empty());
+      overrides.assert_array_lengths::<64>();
+
+      let partial_sums: reduce::compute::PartialSums<64> = [0.0; 64];
+      assert_eq!(std::mem::size_of_val(&partial_sums), 256);
+    }
+
+    #[test]
+    fn set_overrides_are_passed_to_the_pipeline() {
+      let overrides = reduce::OverrideConstants {
+        block_size: Some(128),
+      };
+      assert_eq!(overrides.constants(), vec![("1", 128.0)]);
+      overrides.assert_array_lengths::<128>();
+    }
+
+    #[test]
+    #[should_panic(expected = "override `block_size` is 64, but the array length is 32")]
+    fn mismatched_array_lengths_panic() {
+      reduce::OverrideConstants::default().assert_array_lengths::<32>();
+    }
+
+    #[test]
+    fn embedded_shader_keeps_the_override_length() {
+      assert!(reduce::SHADER_STRING.contains("array<f32, block_size>"));
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_override_sized_arrays_use_the_written_override_name() -> Result<()> {
+  let actual =
+    feature_test_builder(OVERRIDE_SIZED_ARRAYS_DIR, WgslTypeSerializeStrategy::Bytemuck)
+      .add_entry_point("tests/shaders/features/override_sized_arrays/tiles.wgsl")
+      .shader_source_type(WgslShaderSourceType::EmbedSource)
+      .build()?
+      .generate_string()
+      .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn embedded_shader_uses_the_renamed_override() {
+      assert!(tiles::SHADER_STRING.contains("override tile_size2_: u32"));
+      assert!(tiles::SHADER_STRING.contains("array<u32, tile_size2_>"));
+      tiles::OverrideConstants::default().assert_array_lengths::<8>();
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_override_sized_arrays_with_imports_are_rejected() {
+  let err =
+    feature_test_builder(OVERRIDE_SIZED_ARRAYS_DIR, WgslTypeSerializeStrategy::Bytemuck)
+      .add_entry_point("tests/shaders/features/override_sized_arrays/with_imports.wgsl")
+      .build()
+      .unwrap()
+      .generate_string()
+      .unwrap_err();
+
+  assert_eq!(
+    err.to_string(),
+    "Entry `tests/shaders/features/override_sized_arrays/with_imports.wgsl` declares override-sized arrays, which are only supported in shaders without imports"
+  );
+}
@@ -0,0 +1,67 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+#[test]
+fn test_padded_field_accessors() -> Result<()> {
+  let actual = WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/padded_field_accessors")
+    .add_entry_point("tests/shaders/features/padded_field_accessors/lights.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .type_map(GlamWgslTypeMap)
+    .shader_source_type(WgslShaderSourceType::EmbedSource)
+    .derive_serde(false)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  // Padded fields can be read and written in their unpadded form, whole or
+  // one element at a time.
+  assert!(actual.contains("pub fn colors(&self) -> [glam::Vec3; 4]"));
+  assert!(
+    actual.contains("pub fn set_colors_at(&mut self, index: usize, value: glam::Vec3)")
+  );
+  assert!(actual.contains("self.cascades[index_0][index_1].field"));
+  assert!(actual.contains("self.cascades[index][index_0].field"));
+  // `Mat3A` already matches the WGSL layout, so it has no accessors.
+  assert!(!actual.contains("fn normal_matrix("));
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
+
+fn generate_err(entry: &str) -> WgslBindgenError {
+  WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/padded_field_accessors")
+    .add_entry_point(format!("tests/shaders/features/padded_field_accessors/{entry}"))
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .type_map(GlamWgslTypeMap)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()
+    .unwrap()
+    .generate_string()
+    .unwrap_err()
+}
+
+#[test]
+fn test_padded_field_accessor_clashing_with_a_generated_method() {
+  assert_eq!(
+    generate_err("from_bytes.wgsl").to_string(),
+    "field `from_bytes` of struct `from_bytes::Samples` would get an accessor `from_bytes`, which clashes with another method of the struct"
+  );
+}
+
+#[test]
+fn test_padded_field_accessors_clashing_with_each_other() {
+  assert_eq!(
+    generate_err("clash.wgsl").to_string(),
+    "field `points_at` of struct `clash::Path` would get an accessor `points_at`, which clashes with another method of the struct"
+  );
+}
@@ -35,6 +35,16 @@ fn test_shared_bind_groups_minimal() -> Result<()> {
 
   let actual =
     read_to_string("tests/output/features/shared_bind_groups_minimal.actual.rs").unwrap();
+
+  // Shared bindings are visible to the stages that use them in either shader:
+  // `vertex_data` is read by shader_a's vertex and shader_b's compute stage.
+  assert!(actual.contains(
+    "#[doc = \" @binding(1): \\\"_root::shared_data::vertex_data\\\"\"]\n          wgpu::BindGroupLayoutEntry {\n            binding: 1,\n            visibility: wgpu::ShaderStages::VERTEX.union(wgpu::ShaderStages::COMPUTE),"
+  ));
+  assert!(actual.contains(
+    "#[doc = \" @binding(3): \\\"_root::shared_data::shared_sampler\\\"\"]\n          wgpu::BindGroupLayoutEntry {\n            binding: 3,\n            visibility: wgpu::ShaderStages::FRAGMENT,"
+  ));
+
   let parsed_output = parse_str(&actual).unwrap();
   assert_tokens_snapshot!(parsed_output);
   assert_rust_compilation!(parsed_output);
@@ -0,0 +1,220 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const TYPE_MAPS_DIR: &str = "tests/shaders/features/type_maps";
+
+#[cfg(any(feature = "mint", feature = "cgmath", feature = "ultraviolet"))]
+fn generate_math_types(
+  serialization_strategy: WgslTypeSerializeStrategy,
+  type_map: impl WgslTypeMapBuild,
+) -> Result<String> {
+  feature_test_builder(TYPE_MAPS_DIR, serialization_strategy)
+    .add_entry_point("tests/shaders/features/type_maps/math_types.wgsl")
+    .type_map(type_map)
+    .build()?
+    .generate_string()
+    .into_diagnostic()
+}
+
+#[test]
+fn test_glam_f64_and_i64_types() -> Result<()> {
+  let actual = feature_test_builder(TYPE_MAPS_DIR, WgslTypeSerializeStrategy::Bytemuck)
+    .add_entry_point("tests/shaders/features/type_maps/f64_types.wgsl")
+    .type_map(GlamWgslTypeMap)
+    .ir_capabilities(
+      naga::valid::Capabilities::FLOAT64 | naga::valid::Capabilities::SHADER_INT64,
+    )
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn glam_types_sit_at_their_wgsl_offsets() {
+      let mut doubles: f64_types::Doubles = bytemuck::Zeroable::zeroed();
+      doubles.b = glam::DVec3::new(1.0, 2.0, 3.0);
+      doubles.g = glam::DMat4::from_diagonal(glam::DVec4::splat(4.0));
+      let bytes = bytemuck::bytes_of(&doubles);
+      let b = f64_types::Doubles::OFFSET_B as usize;
+      assert_eq!(&bytes[b..b + 24], bytemuck::bytes_of(&[1.0f64, 2.0, 3.0]));
+      let g = f64_types::Doubles::OFFSET_G as usize;
+      assert_eq!(&bytes[g..g + 128], bytemuck::bytes_of(&doubles.g));
+      assert_eq!(std::mem::size_of::<glam::DMat2>(), 32);
+
+      let mut longs: f64_types::Longs = bytemuck::Zeroable::zeroed();
+      longs.c = glam::I64Vec4::new(-1, -2, -3, -4);
+      longs.g = glam::U64Vec4::new(5, 6, 7, 8);
+      let bytes = bytemuck::bytes_of(&longs);
+      let c = f64_types::Longs::OFFSET_C as usize;
+      assert_eq!(&bytes[c..c + 32], bytemuck::bytes_of(&[-1i64, -2, -3, -4]));
+      let g = f64_types::Longs::OFFSET_G as usize;
+      assert_eq!(&bytes[g..g + 32], bytemuck::bytes_of(&[5u64, 6, 7, 8]));
+    }
+  });
+  Ok(())
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_mint_type_map() -> Result<()> {
+  let actual = generate_math_types(WgslTypeSerializeStrategy::Bytemuck, MintWgslTypeMap)?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn mint_types_sit_at_their_wgsl_offsets() {
+      let mut vectors: math_types::Vectors = bytemuck::Zeroable::zeroed();
+      vectors.h = mint::Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+      };
+      vectors.f = mint::Vector4 {
+        x: 4,
+        y: 5,
+        z: 6,
+        w: 7,
+      };
+      let bytes = bytemuck::bytes_of(&vectors);
+      let h = math_types::Vectors::OFFSET_H as usize;
+      assert_eq!(&bytes[h..h + 12], bytemuck::bytes_of(&[1.0f32, 2.0, 3.0]));
+      let f = math_types::Vectors::OFFSET_F as usize;
+      assert_eq!(&bytes[f..f + 16], bytemuck::bytes_of(&[4u32, 5, 6, 7]));
+
+      // `mat3x2<f32>` is three `vec2` columns, which mint calls 2x3.
+      let mut matrices: math_types::Matrices = bytemuck::Zeroable::zeroed();
+      matrices.b = mint::ColumnMatrix2x3 {
+        x: mint::Vector2 { x: 1.0, y: 2.0 },
+        y: mint::Vector2 { x: 3.0, y: 4.0 },
+        z: mint::Vector2 { x: 5.0, y: 6.0 },
+      };
+      let bytes = bytemuck::bytes_of(&matrices);
+      let b = math_types::Matrices::OFFSET_B as usize;
+      assert_eq!(
+        &bytes[b..b + 24],
+        bytemuck::bytes_of(&[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0])
+      );
+      assert_eq!(std::mem::size_of::<mint::ColumnMatrix4<f32>>(), 64);
+    }
+  });
+  Ok(())
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn test_cgmath_type_map() -> Result<()> {
+  let actual =
+    generate_math_types(WgslTypeSerializeStrategy::Bytemuck, CgmathWgslTypeMap)?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn cgmath_types_sit_at_their_wgsl_offsets() {
+      let mut vectors: math_types::Vectors = bytemuck::Zeroable::zeroed();
+      vectors.h = cgmath::Vector3::new(1.0, 2.0, 3.0);
+      let bytes = bytemuck::bytes_of(&vectors);
+      let h = math_types::Vectors::OFFSET_H as usize;
+      assert_eq!(&bytes[h..h + 12], bytemuck::bytes_of(&[1.0f32, 2.0, 3.0]));
+
+      let mut matrices: math_types::Matrices = bytemuck::Zeroable::zeroed();
+      matrices.a = cgmath::Matrix2::new(1.0, 2.0, 3.0, 4.0);
+      matrices.i = cgmath::Matrix4::from_scale(5.0);
+      let bytes = bytemuck::bytes_of(&matrices);
+      let a = math_types::Matrices::OFFSET_A as usize;
+      assert_eq!(&bytes[a..a + 16], bytemuck::bytes_of(&[1.0f32, 2.0, 3.0, 4.0]));
+      let i = math_types::Matrices::OFFSET_I as usize;
+      let columns: [[f32; 4]; 4] = matrices.i.into();
+      assert_eq!(&bytes[i..i + 64], bytemuck::bytes_of(&columns));
+    }
+  });
+  Ok(())
+}
+
+#[cfg(feature = "ultraviolet")]
+#[test]
+fn test_ultraviolet_type_map() -> Result<()> {
+  let actual =
+    generate_math_types(WgslTypeSerializeStrategy::Bytemuck, UltravioletWgslTypeMap)?;
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn ultraviolet_types_sit_at_their_wgsl_offsets() {
+      let mut vectors: math_types::Vectors = bytemuck::Zeroable::zeroed();
+      vectors.h = ultraviolet::Vec3::new(1.0, 2.0, 3.0);
+      vectors.f = ultraviolet::UVec4::new(4, 5, 6, 7);
+      let bytes = bytemuck::bytes_of(&vectors);
+      let h = math_types::Vectors::OFFSET_H as usize;
+      assert_eq!(&bytes[h..h + 12], bytemuck::bytes_of(&[1.0f32, 2.0, 3.0]));
+      let f = math_types::Vectors::OFFSET_F as usize;
+      assert_eq!(&bytes[f..f + 16], bytemuck::bytes_of(&[4u32, 5, 6, 7]));
+
+      let mut matrices: math_types::Matrices = bytemuck::Zeroable::zeroed();
+      matrices.i = ultraviolet::Mat4::identity();
+      let bytes = bytemuck::bytes_of(&matrices);
+      let i = math_types::Matrices::OFFSET_I as usize;
+      assert_eq!(&bytes[i..i + 64], bytemuck::bytes_of(&matrices.i));
+    }
+  });
+  Ok(())
+}
+
+#[cfg(all(feature = "mint", feature = "cgmath", feature = "ultraviolet"))]
+/// zerocopy and encase need trait impls the math crates lack, so every map
+/// falls back to Rust arrays.
+fn generate_with_every_math_type_map(
+  serialization_strategy: WgslTypeSerializeStrategy,
+) -> Result<proc_macro2::TokenStream> {
+  let actual = feature_test_builder(TYPE_MAPS_DIR, serialization_strategy)
+    .add_entry_point("tests/shaders/features/type_maps/math_types.wgsl")
+    .type_map(MintWgslTypeMap)
+    .type_map(CgmathWgslTypeMap)
+    .type_map(UltravioletWgslTypeMap)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+  Ok(parse_str(&actual).unwrap())
+}
+
+#[cfg(all(feature = "mint", feature = "cgmath", feature = "ultraviolet"))]
+#[test]
+fn test_math_type_maps_skip_zerocopy() -> Result<()> {
+  let parsed_output =
+    generate_with_every_math_type_map(WgslTypeSerializeStrategy::Zerocopy)?;
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn math_types_are_arrays() {
+      let vectors: math_types::Vectors = zerocopy::FromZeros::new_zeroed();
+      let h: [f32; 4] = vectors.h;
+      let matrices: math_types::Matrices = zerocopy::FromZeros::new_zeroed();
+      let i: [[f32; 4]; 4] = matrices.i;
+      assert_eq!((h, i), ([0.0; 4], [[0.0; 4]; 4]));
+    }
+  });
+  Ok(())
+}
+
+#[cfg(all(feature = "mint", feature = "cgmath", feature = "ultraviolet"))]
+#[test]
+fn test_math_type_maps_skip_encase() -> Result<()> {
+  let parsed_output =
+    generate_with_every_math_type_map(WgslTypeSerializeStrategy::Encase)?;
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn math_types_are_arrays() {
+      fn fields(vectors: &math_types::Vectors, matrices: &math_types::Matrices) {
+        let _: [f32; 4] = vectors.h;
+        let _: [[f32; 4]; 4] = matrices.i;
+      }
+      let _ = fields;
+    }
+  });
+  Ok(())
+}
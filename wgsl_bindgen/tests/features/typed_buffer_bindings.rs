@@ -0,0 +1,149 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const BUFFER_USAGES_DIR: &str = "tests/shaders/features/buffer_usages";
+
+fn generate_typed_buffer_bindings_with(
+  strategy: WgslTypeSerializeStrategy,
+  type_map: impl WgslTypeMapBuild + 'static,
+) -> Result<String> {
+  feature_test_builder(BUFFER_USAGES_DIR, strategy)
+    .entry_points(vec![
+      format!("{BUFFER_USAGES_DIR}/render.wgsl"),
+      format!("{BUFFER_USAGES_DIR}/simulate.wgsl"),
+    ])
+    .type_map(type_map)
+    .typed_buffer_bindings(true)
+    .build()?
+    .generate_string()
+    .into_diagnostic()
+}
+
+fn generate_typed_buffer_bindings() -> Result<String> {
+  generate_typed_buffer_bindings_with(
+    WgslTypeSerializeStrategy::Bytemuck,
+    GlamWgslTypeMap,
+  )
+}
+
+#[test]
+fn test_typed_buffer_bindings() -> Result<()> {
+  let parsed_output = parse_str(&generate_typed_buffer_bindings()?).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    fn camera() -> common::Camera {
+      common::Camera::new(glam::Mat4::IDENTITY, 0.0)
+    }
+
+    fn particle() -> common::Particle {
+      common::Particle::new(glam::Vec4::ZERO, glam::Vec4::ONE)
+    }
+
+    // Buffers made by the helpers bind without naming their type again.
+    #[test]
+    fn helper_buffers_bind_to_their_entries() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let camera = common::Camera::create_buffer(&device, &camera());
+      let particle = common::Particle::create_buffer(&device, &particle());
+      let trail = common::Trail::create_storage_buffer_with_len(&device, 64);
+      simulate::WgpuBindGroup0::from_bindings(
+        &device,
+        simulate::WgpuBindGroup0Entries::new(simulate::WgpuBindGroup0EntriesParams {
+          camera: camera.binding(),
+          particle: particle.binding(),
+          trail: trail.binding(),
+        }),
+      );
+    }
+
+    #[test]
+    fn runtime_sized_arrays_bind_buffers_from_slices() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let camera = common::Camera::create_buffer(&device, &camera());
+      let particles =
+        typed_buffer::Buffer::<[common::Particle]>::from_slice(&device, &[particle(); 3]);
+      assert_eq!(particles.size(), 3 * common::Particle::SIZE);
+      assert_eq!(
+        particles.usage(),
+        wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST
+      );
+
+      // Sized arrays have no helper, so their buffer is wrapped unchecked.
+      let lights = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 4 * common::Light::SIZE,
+        usage: wgpu::BufferUsages::STORAGE,
+        mapped_at_creation: false,
+      });
+      render::WgpuBindGroup0::from_bindings(
+        &device,
+        render::WgpuBindGroup0Entries::new(render::WgpuBindGroup0EntriesParams {
+          camera: camera.binding(),
+          particles: particles.binding(),
+          lights: typed_buffer::TypedBuffer::new_unchecked(
+            lights.as_entire_buffer_binding(),
+          ),
+        }),
+      );
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_typed_buffer_from_slice_zerocopy() -> Result<()> {
+  let actual = generate_typed_buffer_bindings_with(
+    WgslTypeSerializeStrategy::Zerocopy,
+    GlamWgslTypeMap,
+  )?;
+  let parsed_output: proc_macro2::TokenStream = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn runtime_sized_arrays_hold_the_slice_bytes() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let particle = common::Particle::new(glam::Vec4::ZERO, glam::Vec4::ONE);
+      let particles =
+        typed_buffer::Buffer::<[common::Particle]>::from_slice(&device, &[particle; 3]);
+      assert_eq!(particles.size(), 3 * common::Particle::SIZE);
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_typed_buffer_from_slice_encase() -> Result<()> {
+  let actual = generate_typed_buffer_bindings_with(
+    WgslTypeSerializeStrategy::Encase,
+    RustWgslTypeMap,
+  )?;
+  let parsed_output: proc_macro2::TokenStream = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn runtime_sized_arrays_hold_the_slice_bytes() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let particle = common::Particle {
+        position: [0.0; 4],
+        velocity: [1.0; 4],
+      };
+      let particles =
+        typed_buffer::Buffer::<[common::Particle]>::from_slice(&device, &[particle; 3]);
+      assert_eq!(particles.size(), 3 * common::Particle::SIZE);
+    }
+  });
+  Ok(())
+}
+
+// The compile-fail tests include the generated bindings, and check that an
+// entry only takes a binding of the type it holds.
+#[test]
+fn test_typed_buffer_bindings_reject_other_buffers() -> Result<()> {
+  std::fs::write(
+    "tests/output/typed_buffer_bindings.actual.rs",
+    generate_typed_buffer_bindings()?,
+  )
+  .into_diagnostic()?;
+  trybuild::TestCases::new().compile_fail("tests/ui/typed_buffer_*.rs");
+  Ok(())
+}
@@ -0,0 +1,228 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const TYPED_TEXTURE_VIEWS_DIR: &str = "tests/shaders/features/typed_texture_views";
+
+fn generate_typed_texture_views() -> Result<String> {
+  feature_test_builder(TYPED_TEXTURE_VIEWS_DIR, WgslTypeSerializeStrategy::Bytemuck)
+    .add_entry_point(format!("{TYPED_TEXTURE_VIEWS_DIR}/textures.wgsl"))
+    .type_map(GlamWgslTypeMap)
+    .typed_texture_views(true)
+    .override_texture_filterability([("height_texture", false).into()])
+    .build()?
+    .generate_string()
+    .into_diagnostic()
+}
+
+#[test]
+fn test_typed_texture_views() -> Result<()> {
+  let actual = generate_typed_texture_views()?;
+  assert!(actual
+    .contains("&'a typed_texture::TextureView2d<typed_texture::UnfilterableFloat>,"));
+  assert!(actual.contains(
+    "typed_texture::TextureView2d<typed_texture::Multisampled<typed_texture::Uint>>"
+  ));
+  // Samplers are not texture views.
+  assert!(actual.contains("pub color_sampler: &'a wgpu::Sampler,"));
+
+  let parsed_output: proc_macro2::TokenStream = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  // The views are created on wgpu's noop backend, which keeps the usage,
+  // format and layers of its textures.
+  assert_rust_compilation!(parsed_output, {
+    fn texture(
+      device: &wgpu::Device,
+      layers: u32,
+      format: wgpu::TextureFormat,
+      usage: wgpu::TextureUsages,
+    ) -> wgpu::Texture {
+      device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+          width: 4,
+          height: 4,
+          depth_or_array_layers: layers,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage,
+        view_formats: &[],
+      })
+    }
+
+    fn sampled(device: &wgpu::Device, layers: u32) -> wgpu::Texture {
+      texture(
+        device,
+        layers,
+        wgpu::TextureFormat::Rgba8Unorm,
+        wgpu::TextureUsages::TEXTURE_BINDING,
+      )
+    }
+
+    #[test]
+    fn default_views_take_the_dimension_wgpu_picks() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      typed_texture::TextureView2d::<typed_texture::Float>::new(
+        &sampled(&device, 1),
+        &Default::default(),
+      )
+      .unwrap();
+      typed_texture::TextureView2dArray::<typed_texture::Float>::new(
+        &sampled(&device, 4),
+        &Default::default(),
+      )
+      .unwrap();
+
+      // A single layer of an array texture is a 2D view.
+      let desc = wgpu::TextureViewDescriptor {
+        base_array_layer: 3,
+        ..Default::default()
+      };
+      typed_texture::TextureView2d::<typed_texture::Float>::new(
+        &sampled(&device, 4),
+        &desc,
+      )
+      .unwrap();
+    }
+
+    #[test]
+    fn cube_views_are_no_2d_views() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let texture = sampled(&device, 6);
+      let desc = wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::Cube),
+        ..Default::default()
+      };
+      typed_texture::TextureViewCube::<typed_texture::Float>::new(&texture, &desc)
+        .unwrap();
+      assert_eq!(
+        typed_texture::TextureView2d::<typed_texture::Float>::new(&texture, &desc)
+          .unwrap_err(),
+        typed_texture::TextureViewError::Dimension {
+          expected: wgpu::TextureViewDimension::D2,
+          actual: wgpu::TextureViewDimension::Cube,
+        }
+      );
+    }
+
+    #[test]
+    fn views_past_the_last_array_layer_are_rejected() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let desc = wgpu::TextureViewDescriptor {
+        base_array_layer: 5,
+        ..Default::default()
+      };
+      assert_eq!(
+        typed_texture::TextureView2dArray::<typed_texture::Float>::new(
+          &sampled(&device, 4),
+          &desc
+        )
+        .unwrap_err(),
+        typed_texture::TextureViewError::BaseArrayLayer { base: 5, layers: 4 }
+      );
+    }
+
+    #[test]
+    fn views_check_usage_and_sample_type() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let storage_only = texture(
+        &device,
+        1,
+        wgpu::TextureFormat::Rgba8Unorm,
+        wgpu::TextureUsages::STORAGE_BINDING,
+      );
+      assert_eq!(
+        typed_texture::TextureView2d::<typed_texture::Float>::new(
+          &storage_only,
+          &Default::default()
+        )
+        .unwrap_err(),
+        typed_texture::TextureViewError::MissingUsage {
+          usage: wgpu::TextureUsages::TEXTURE_BINDING,
+        }
+      );
+
+      let r32float = texture(
+        &device,
+        1,
+        wgpu::TextureFormat::R32Float,
+        wgpu::TextureUsages::TEXTURE_BINDING,
+      );
+      assert!(matches!(
+        typed_texture::TextureView2d::<typed_texture::Float>::new(
+          &r32float,
+          &Default::default()
+        ),
+        Err(typed_texture::TextureViewError::SampleType { .. })
+      ));
+      typed_texture::TextureView2d::<typed_texture::UnfilterableFloat>::new(
+        &r32float,
+        &Default::default(),
+      )
+      .unwrap();
+    }
+
+    #[test]
+    fn storage_views_bind_where_the_shader_expects_them() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let storage = wgpu::TextureUsages::STORAGE_BINDING;
+      let output = texture(&device, 1, wgpu::TextureFormat::Rgba8Unorm, storage);
+      let counters = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+          width: 4,
+          height: 4,
+          depth_or_array_layers: 4,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D3,
+        format: wgpu::TextureFormat::R32Uint,
+        usage: storage,
+        view_formats: &[],
+      });
+
+      assert_eq!(
+        typed_texture::StorageTextureView::<
+          typed_texture::R32Uint,
+          typed_texture::ReadWrite,
+        >::new(&output, &Default::default())
+        .unwrap_err(),
+        typed_texture::TextureViewError::Format {
+          expected: wgpu::TextureFormat::R32Uint,
+          actual: wgpu::TextureFormat::Rgba8Unorm,
+        }
+      );
+
+      let output =
+        typed_texture::StorageTextureView::new(&output, &Default::default()).unwrap();
+      let counters =
+        typed_texture::StorageTextureView::new(&counters, &Default::default()).unwrap();
+      textures::WgpuBindGroup1::from_bindings(
+        &device,
+        textures::WgpuBindGroup1Entries::new(textures::WgpuBindGroup1EntriesParams {
+          output: &output,
+          counters: &counters,
+        }),
+      );
+    }
+  });
+  Ok(())
+}
+
+// The compile-fail tests include the generated bindings, and check that an
+// entry only takes a view of the dimension and sample type it holds.
+#[test]
+fn test_typed_texture_views_reject_other_views() -> Result<()> {
+  std::fs::write(
+    "tests/output/typed_texture_views.actual.rs",
+    generate_typed_texture_views()?,
+  )
+  .into_diagnostic()?;
+  trybuild::TestCases::new().compile_fail("tests/ui/typed_texture_*.rs");
+  Ok(())
+}
@@ -0,0 +1,39 @@
+use miette::Diagnostic;
+use wgsl_bindgen::*;
+
+fn generate_err(entry: &str) -> WgslBindgenError {
+  WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/uniform_layout")
+    .add_entry_point(format!("tests/shaders/features/uniform_layout/{entry}"))
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()
+    .unwrap()
+    .generate_string()
+    .unwrap_err()
+}
+
+#[test]
+fn test_invalid_uniform_layout_names_the_field() {
+  let err = generate_err("material.wgsl");
+
+  assert_eq!(
+    err.to_string(),
+    "uniform binding `material` has an invalid layout: field `material.weights` its array stride 4 is not a multiple of 16"
+  );
+  let help = err.help().unwrap().to_string();
+  assert!(help.contains("`vec4<f32>`"), "{help}");
+  assert!(help.contains("var<storage, read>"), "{help}");
+}
+
+#[test]
+fn test_other_naga_errors_are_not_replaced_by_the_uniform_layout_error() {
+  // naga rejects `flags` before it gets to the invalid `material` layout.
+  let err = generate_err("other_error.wgsl");
+
+  assert!(matches!(err, WgslBindgenError::NagaModuleComposeError { .. }), "{err}");
+  let msg = err.to_string();
+  assert!(msg.contains("'flags' is invalid"), "{msg}");
+}
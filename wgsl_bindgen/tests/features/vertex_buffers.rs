@@ -0,0 +1,169 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::*;
+
+const VERTEX_BUFFERS_DIR: &str = "tests/shaders/features/vertex_buffers";
+
+fn generate_instanced(
+  strategy: WgslTypeSerializeStrategy,
+  step_modes: Vec<OverrideVertexStepMode>,
+) -> Result<proc_macro2::TokenStream> {
+  let actual = feature_test_builder(VERTEX_BUFFERS_DIR, strategy)
+    .add_entry_point(format!("{VERTEX_BUFFERS_DIR}/instanced.wgsl"))
+    .type_map(GlamWgslTypeMap)
+    .override_vertex_step_mode(step_modes)
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+  Ok(parse_str(&actual).unwrap())
+}
+
+// The tests run against wgpu's noop backend, so creating the pipeline checks
+// the generated vertex buffer layouts against the shader's vertex inputs.
+// `Vertex` is only read as a vertex input, so it keeps the Rust layout: its
+// `vec3<f32>` is 12 bytes and the struct 20.
+
+#[test]
+fn test_vertex_buffers_follow_vertex_state_slots() -> Result<()> {
+  let output = generate_instanced(WgslTypeSerializeStrategy::Bytemuck, Vec::new())?;
+  assert_rust_compilation!(output, {
+    struct RecordSlots(Vec<(u32, wgpu::BufferAddress)>);
+
+    impl _root::SetVertexBuffer for RecordSlots {
+      fn set_vertex_buffer(&mut self, slot: u32, buffer_slice: wgpu::BufferSlice<'_>) {
+        self.0.push((slot, buffer_slice.size()));
+      }
+    }
+
+    #[test]
+    fn vertex_attributes_match_the_struct_layout() {
+      let attributes = instanced::Vertex::VERTEX_ATTRIBUTES;
+      assert_eq!(attributes[0].shader_location, 0);
+      assert_eq!(attributes[0].offset, 0);
+      assert_eq!(attributes[1].shader_location, 1);
+      assert_eq!(attributes[1].offset, 12);
+
+      let layout = instanced::Vertex::vertex_buffer_layout(wgpu::VertexStepMode::Vertex);
+      assert_eq!(layout.array_stride, 20);
+      assert_eq!(
+        instanced::Instance::VERTEX_ATTRIBUTES.map(|attribute| attribute.shader_location),
+        [2, 3]
+      );
+    }
+
+    #[test]
+    fn vertex_buffers_are_set_at_their_slots() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let vertex = instanced::Vertex::create_vertex_buffer(
+        &device,
+        &[instanced::Vertex::new(glam::Vec3::ONE, glam::Vec2::ZERO); 3],
+      );
+      let instance = instanced::Instance::create_vertex_buffer(
+        &device,
+        &[instanced::Instance::new(glam::Vec4::ZERO, glam::Vec4::ONE); 2],
+      );
+      let scale = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 4,
+        usage: wgpu::BufferUsages::VERTEX,
+        mapped_at_creation: false,
+      });
+      assert_eq!(vertex.size(), 3 * 20);
+      assert_eq!(vertex.usage(), instanced::Vertex::BUFFER_USAGES);
+
+      let mut slots = RecordSlots(Vec::new());
+      instanced::VsMainVertexBuffers {
+        vertex: vertex.slice(..),
+        instance: instance.slice(..),
+        scale: scale.slice(..),
+      }
+      .set(&mut slots);
+      assert_eq!(slots.0, [(0, 60), (1, 64), (2, 4)]);
+    }
+
+    #[test]
+    fn vertex_entry_creates_a_pipeline() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let module = instanced::create_shader_module_embed_source(&device);
+      let layout = instanced::create_pipeline_layout(&device);
+      let vertex_entry = instanced::vs_main_entry(
+        wgpu::VertexStepMode::Vertex,
+        wgpu::VertexStepMode::Instance,
+        wgpu::VertexStepMode::Instance,
+      );
+      let fragment_entry =
+        instanced::fs_main_entry([Some(wgpu::TextureFormat::Rgba8Unorm.into())]);
+      device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: None,
+        layout: Some(&layout),
+        vertex: instanced::vertex_state(&module, &vertex_entry),
+        primitive: Default::default(),
+        depth_stencil: None,
+        multisample: Default::default(),
+        fragment: Some(instanced::fragment_state(&module, &fragment_entry)),
+        multiview_mask: None,
+        cache: None,
+      });
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_vertex_buffers_zerocopy() -> Result<()> {
+  let output = generate_instanced(WgslTypeSerializeStrategy::Zerocopy, Vec::new())?;
+  assert_rust_compilation!(output, {
+    #[test]
+    fn vertex_buffers_hold_the_vertex_bytes() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let vertices = [instanced::Vertex::new(glam::Vec3::ONE, glam::Vec2::ZERO); 3];
+      let buffer = instanced::Vertex::create_vertex_buffer(&device, &vertices);
+      assert_eq!(
+        buffer.size(),
+        zerocopy::IntoBytes::as_bytes(&vertices[..]).len() as u64
+      );
+      assert_eq!(
+        instanced::Vertex::vertex_buffer_layout(wgpu::VertexStepMode::Vertex)
+          .array_stride,
+        std::mem::size_of::<instanced::Vertex>() as u64
+      );
+    }
+  });
+  Ok(())
+}
+
+#[test]
+fn test_vertex_step_modes_fixed_at_generation() -> Result<()> {
+  let output = generate_instanced(
+    WgslTypeSerializeStrategy::Bytemuck,
+    vec![
+      (".*Instance$", VertexStepMode::Instance).into(),
+      ("^scale$", VertexStepMode::Instance).into(),
+    ],
+  )?;
+  assert_rust_compilation!(output, {
+    #[test]
+    fn fixed_step_modes_are_no_parameters() {
+      assert_eq!(
+        instanced::Instance::VERTEX_BUFFER_LAYOUT.step_mode,
+        wgpu::VertexStepMode::Instance
+      );
+
+      // Only the vertex struct without a fixed step mode remains a parameter.
+      let entry = instanced::vs_main_entry(wgpu::VertexStepMode::Vertex);
+      let step_modes = entry
+        .buffers
+        .map(|buffer| buffer.map(|buffer| buffer.step_mode));
+      assert_eq!(
+        step_modes,
+        [
+          Some(wgpu::VertexStepMode::Vertex),
+          Some(wgpu::VertexStepMode::Instance),
+          Some(wgpu::VertexStepMode::Instance),
+        ]
+      );
+    }
+  });
+  Ok(())
+}
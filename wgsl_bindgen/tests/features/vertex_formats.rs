@@ -0,0 +1,65 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::*;
+
+fn mesh_bindgen(overrides: Vec<OverrideVertexFormat>) -> Result<WGSLBindgen> {
+  WgslBindgenOptionBuilder::default()
+    .workspace_root("tests/shaders/features/vertex_formats")
+    .add_entry_point("tests/shaders/features/vertex_formats/mesh.wgsl")
+    .skip_hash_check(true)
+    .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
+    .type_map(GlamWgslTypeMap)
+    .override_vertex_format(overrides)
+    .emit_rerun_if_change(false)
+    .skip_header_comments(true)
+    .build()
+    .into_diagnostic()
+}
+
+#[test]
+fn test_vertex_formats_override_field_types_and_attributes() -> Result<()> {
+  let actual = mesh_bindgen(vec![
+    ("mesh::MeshVertex", "normal", VertexFormat::Snorm16x4).into(),
+    ("mesh::MeshVertex", "color", VertexFormat::Unorm8x4).into(),
+    (".*", "uv", VertexFormat::Unorm16x2).into(),
+    (".*", "material", VertexFormat::Uint8x4).into(),
+  ])?
+  .generate_string()
+  .into_diagnostic()?;
+
+  assert!(actual.contains("pub position: glam::Vec3,"));
+  assert!(actual.contains("pub normal: [i16; 4],"));
+  assert!(actual.contains("pub color: [u8; 4],"));
+  assert!(actual.contains("pub uv: [u16; 2],"));
+  assert!(actual.contains("pub material: [u8; 4],"));
+  for (format, field) in [
+    ("Float32x3", "position"),
+    ("Snorm16x4", "normal"),
+    ("Unorm8x4", "color"),
+    ("Unorm16x2", "uv"),
+    ("Uint8x4", "material"),
+  ] {
+    let attribute = format!(
+      "format: wgpu::VertexFormat::{format},\n        offset: std::mem::offset_of!(Self, {field}) as u64,"
+    );
+    assert!(actual.contains(&attribute), "{attribute}");
+  }
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_rust_compilation!(parsed_output);
+  Ok(())
+}
+
+#[test]
+fn test_vertex_format_must_match_shader_scalar_kind() -> Result<()> {
+  let err =
+    mesh_bindgen(vec![("mesh::MeshVertex", "color", VertexFormat::Uint8x4).into()])?
+      .generate_string()
+      .unwrap_err();
+
+  assert_eq!(
+    err.to_string(),
+    "vertex attribute `mesh::MeshVertex::color` of type `vec4<f32>` cannot be read from format `Uint8x4`"
+  );
+  Ok(())
+}
@@ -0,0 +1,140 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+const VERTEX_PULLING_DIR: &str = "tests/shaders/features/vertex_pulling";
+
+fn generate_vertex_pulling(
+  strategy: WgslTypeSerializeStrategy,
+  type_map: impl WgslTypeMapBuild + 'static,
+  entry: &str,
+  vertex_pulling: &str,
+) -> Result<proc_macro2::TokenStream> {
+  let actual = feature_test_builder(VERTEX_PULLING_DIR, strategy)
+    .add_entry_point(format!("{VERTEX_PULLING_DIR}/{entry}"))
+    .type_map(type_map)
+    .vertex_pulling([(vertex_pulling, 1, 0).into()])
+    .build()?
+    .generate_string()
+    .into_diagnostic()?;
+  Ok(parse_str(&actual).unwrap())
+}
+
+// A shader switched to vertex pulling only needs the library and the vertex
+// index. The tests build it on wgpu's noop backend, which validates the
+// library and checks the pulling buffer against its storage binding.
+
+#[test]
+fn test_vertex_pulling_library_and_storage_struct() -> Result<()> {
+  let output = generate_vertex_pulling(
+    WgslTypeSerializeStrategy::Bytemuck,
+    GlamWgslTypeMap,
+    "mesh.wgsl",
+    "^mesh::MeshVertex$",
+  )?;
+  assert_tokens_snapshot!(output);
+  assert_rust_compilation!(output, {
+    const VS_MAIN: &str = "
+      @vertex
+      fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+          let vertex = fetch_mesh_vertex(index);
+          return vec4<f32>(vertex.position + vec3<f32>(vertex.uv, f32(vertex.material)), 1.0);
+      }
+
+      @fragment
+      fn fs_main() -> @location(0) vec4<f32> {
+          return vec4<f32>(1.0);
+      }
+    ";
+
+    #[test]
+    fn pulled_struct_follows_the_storage_buffer_layout() {
+      // The attribute struct stays tightly packed.
+      assert_eq!(std::mem::size_of::<mesh::MeshVertex>(), 24);
+      assert_eq!(std::mem::size_of::<mesh::MeshVertexPulled>(), 32);
+      assert_eq!(mesh::MeshVertexPulled::OFFSET_MATERIAL, 24);
+      assert_eq!(
+        mesh::MeshVertexPulled::BUFFER_USAGES,
+        wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST
+      );
+    }
+
+    #[test]
+    fn pulling_buffer_binds_to_the_library() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: None,
+        source: wgpu::ShaderSource::Wgsl(
+          format!("{}\n{VS_MAIN}", mesh::MeshVertexPulled::PULLING_SHADER).into(),
+        ),
+      });
+      let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: None,
+        layout: None,
+        vertex: wgpu::VertexState {
+          module: &module,
+          entry_point: Some("vs_main"),
+          buffers: &[],
+          compilation_options: Default::default(),
+        },
+        primitive: Default::default(),
+        depth_stencil: None,
+        multisample: Default::default(),
+        fragment: Some(wgpu::FragmentState {
+          module: &module,
+          entry_point: Some("fs_main"),
+          targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+          compilation_options: Default::default(),
+        }),
+        multiview_mask: None,
+        cache: None,
+      });
+
+      let vertex = mesh::MeshVertexPulled::new(glam::Vec3::ONE, glam::Vec2::ZERO, 7);
+      let buffer = mesh::MeshVertexPulled::create_pulling_buffer(&device, &[vertex; 3]);
+      assert_eq!(buffer.size(), 3 * mesh::MeshVertexPulled::SIZE);
+      let _bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(mesh::MeshVertexPulled::PULLING_GROUP),
+        entries: &[wgpu::BindGroupEntry {
+          binding: mesh::MeshVertexPulled::PULLING_BINDING,
+          resource: buffer.as_entire_binding(),
+        }],
+      });
+    }
+  });
+  Ok(())
+}
+
+// encase implements no math crate types, and the arrays of the Rust type map
+// only match the WGSL layout of naturally aligned vectors, so the sprite
+// vertices have no `vec3`.
+#[test]
+fn test_vertex_pulling_encase() -> Result<()> {
+  let output = generate_vertex_pulling(
+    WgslTypeSerializeStrategy::Encase,
+    RustWgslTypeMap,
+    "sprite.wgsl",
+    "^sprite::SpriteVertex$",
+  )?;
+  assert_rust_compilation!(output, {
+    #[test]
+    fn pulling_buffer_holds_the_storage_buffer_layout() {
+      let (device, _queue) = wgpu::Device::noop(&Default::default());
+      let vertex = sprite::SpriteVertexPulled::new([1.0; 4], [0.0; 2], 7, 1);
+      let buffer =
+        sprite::SpriteVertexPulled::create_pulling_buffer(&device, &[vertex; 3]);
+      assert_eq!(sprite::SpriteVertexPulled::SIZE, 32);
+      assert_eq!(buffer.size(), 3 * sprite::SpriteVertexPulled::SIZE);
+      assert_eq!(
+        (
+          sprite::SpriteVertexPulled::PULLING_GROUP,
+          sprite::SpriteVertexPulled::PULLING_BINDING
+        ),
+        (1, 0)
+      );
+    }
+  });
+  Ok(())
+}
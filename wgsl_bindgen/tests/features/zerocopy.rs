@@ -0,0 +1,53 @@
+use miette::{IntoDiagnostic, Result};
+use syn::parse_str;
+use wgsl_bindgen::test_helper::feature_test_builder;
+use wgsl_bindgen::{assert_tokens_snapshot, *};
+
+#[test]
+fn test_zerocopy_structs_and_runtime_sized_tails() -> Result<()> {
+  let actual = feature_test_builder(
+    "tests/shaders/features/zerocopy",
+    WgslTypeSerializeStrategy::Zerocopy,
+  )
+  .add_entry_point("tests/shaders/features/zerocopy/particles.wgsl")
+  .type_map(GlamWgslTypeMap)
+  .shader_source_type(WgslShaderSourceType::EmbedSource)
+  .build()?
+  .generate_string()
+  .into_diagnostic()?;
+
+  assert!(!actual.contains("bytemuck::"));
+
+  let parsed_output = parse_str(&actual).unwrap();
+  assert_tokens_snapshot!(parsed_output);
+  assert_rust_compilation!(parsed_output, {
+    #[test]
+    fn padding_free_tails_derive_into_bytes() {
+      let particle: particles::Particle = zerocopy::FromZeros::new_zeroed();
+      let list = particles::ParticleList::new(7, &[particle, particle]);
+      let bytes = zerocopy::IntoBytes::as_bytes(&*list);
+      assert_eq!(bytes.len(), particles::ParticleList::OFFSET_PARTICLES as usize + 64);
+      assert_eq!(&bytes[..4], zerocopy::IntoBytes::as_bytes(&7u32));
+    }
+
+    #[test]
+    fn padded_tails_copy_their_fields_in_order() {
+      let histogram =
+        particles::Histogram::new(glam::Vec4::new(1.0, 2.0, 3.0, 4.0), &[5, 6, 7]);
+      let bytes = histogram.to_bytes();
+      assert_eq!(bytes.len(), 16 + 3 * 4);
+      assert_eq!(&bytes[..16], zerocopy::IntoBytes::as_bytes(&[1.0f32, 2.0, 3.0, 4.0]));
+      assert_eq!(&bytes[16..], zerocopy::IntoBytes::as_bytes(&[5u32, 6, 7]));
+
+      let normals = particles::Normals::new(2.0, &[glam::Vec3::new(8.0, 9.0, 10.0)]);
+      let bytes = zerocopy::IntoBytes::as_bytes(&*normals);
+      let offset = particles::Normals::OFFSET_NORMALS as usize;
+      assert_eq!(bytes.len(), offset + 16);
+      assert_eq!(
+        &bytes[offset..offset + 12],
+        zerocopy::IntoBytes::as_bytes(&[8.0f32, 9.0, 10.0])
+      );
+    }
+  });
+  Ok(())
+}
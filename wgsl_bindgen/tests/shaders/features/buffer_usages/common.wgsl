@@ -0,0 +1,25 @@
+#define_import_path common
+
+struct Camera {
+    view_proj: mat4x4<f32>,
+    time: f32,
+}
+
+struct Particle {
+    @location(0) position: vec4<f32>,
+    @location(1) velocity: vec4<f32>,
+}
+
+struct Instance {
+    @location(2) offset: vec4<f32>,
+}
+
+struct Light {
+    color: vec4<f32>,
+    intensity: f32,
+}
+
+struct Trail {
+    count: u32,
+    points: array<vec4<f32>>,
+}
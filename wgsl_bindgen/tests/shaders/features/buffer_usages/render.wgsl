@@ -0,0 +1,19 @@
+#import common::{Camera, Instance, Light, Particle}
+
+// The camera is a uniform here, but a storage buffer in the simulation.
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<storage, read> particles: array<Particle>;
+// Only bound as an array.
+@group(0) @binding(2) var<storage, read> lights: array<Light, 4>;
+
+// `Instance` is only a vertex input.
+@vertex
+fn vs_main(particle: Particle, instance: Instance) -> @builtin(position) vec4<f32> {
+    return camera.view_proj * (particle.position + instance.offset);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    let light = lights[0].color * lights[0].intensity;
+    return light + vec4<f32>(camera.time, f32(arrayLength(&particles)), 0.0, 1.0);
+}
@@ -0,0 +1,13 @@
+#import common::{Camera, Particle, Trail}
+
+@group(0) @binding(0) var<storage, read_write> camera: Camera;
+@group(0) @binding(1) var<storage, read_write> particle: Particle;
+@group(0) @binding(2) var<storage, read_write> trail: Trail;
+
+@compute @workgroup_size(1)
+fn cs_main() {
+    camera.time += 1.0;
+    particle.position += particle.velocity;
+    trail.points[trail.count % arrayLength(&trail.points)] = particle.position;
+    trail.count += 1u;
+}
@@ -0,0 +1,39 @@
+#import types::{Light, MAX_LIGHTS}
+
+/// Scene wide lighting parameters.
+struct Scene {
+    /// Ambient color added to every fragment.
+    ambient: vec3<f32>,
+    /// Number of entries used in `lights`.
+    light_count: u32,
+    lights: array<Light, MAX_LIGHTS>,
+}
+
+/// Exposure applied before tonemapping.
+override exposure: f32 = 1.0;
+
+@group(0) @binding(0)
+var<uniform> scene: Scene;
+
+/// Draws a fullscreen triangle.
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+/// Accumulates the contribution of every light.
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    var color = scene.ambient;
+    for (var i = 0u; i < scene.light_count; i++) {
+        color += scene.lights[i].color * scene.lights[i].intensity;
+    }
+    return vec4<f32>(color * exposure, 1.0);
+}
+
+/// Clears the light list.
+@compute @workgroup_size(1)
+fn cs_main() {
+    let count = scene.light_count;
+}
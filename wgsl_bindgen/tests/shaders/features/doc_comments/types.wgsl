@@ -0,0 +1,14 @@
+#define_import_path types
+
+/// Upper bound on the number of lights in a scene.
+const MAX_LIGHTS: u32 = 4u;
+
+/// A point light.
+struct Light {
+    /// Light position in world space.
+    position: vec3<f32>,
+    /// Brightness multiplier.
+    intensity: f32,
+    /// Linear RGB color.
+    color: vec3<f32>,
+}
@@ -0,0 +1,13 @@
+#define_import_path frame
+
+struct Globals {
+    time: f32,
+}
+
+struct Camera {
+    view_proj: mat4x4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+// One camera per view, all in one buffer.
+@group(0) @binding(1) var<uniform> camera: Camera;
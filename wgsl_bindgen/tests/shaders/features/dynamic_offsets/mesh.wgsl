@@ -0,0 +1,20 @@
+#import frame::{globals, camera}
+
+struct Material {
+    color: vec4<f32>,
+}
+
+// Per-draw data, all draws sharing one buffer each.
+@group(1) @binding(1) var<uniform> material: Material;
+@group(1) @binding(0) var<storage, read> skin: array<mat4x4<f32>>;
+@group(1) @binding(2) var color_texture: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    return camera.view_proj * skin[index] * vec4<f32>(globals.time);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return material.color * textureLoad(color_texture, vec2<u32>(0u), 0);
+}
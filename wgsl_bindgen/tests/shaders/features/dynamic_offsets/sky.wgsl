@@ -0,0 +1,11 @@
+#import frame::{globals, camera}
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    return camera.view_proj * vec4<f32>(f32(index), globals.time, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(globals.time);
+}
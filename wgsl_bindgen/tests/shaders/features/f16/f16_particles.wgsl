@@ -0,0 +1,36 @@
+enable f16;
+
+struct Particle {
+  position: vec3<f16>,
+  size: f16,
+  color: vec4<f16>,
+}
+
+@group(0) @binding(0) var<storage, read> particles: array<Particle>;
+
+struct VertexInput {
+  @location(0) uv: vec2<f16>,
+  @location(1) tint: vec4<f16>,
+  @location(2) scale: f16,
+}
+
+struct VertexOutput {
+  @builtin(position) position: vec4<f32>,
+  @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(in: VertexInput, @builtin(instance_index) instance: u32) -> VertexOutput {
+  let particle = particles[instance];
+  let offset = vec3<f16>(in.uv * in.scale * particle.size, 0.0h);
+
+  var out: VertexOutput;
+  out.position = vec4<f32>(vec3<f32>(particle.position + offset), 1.0);
+  out.color = vec4<f32>(particle.color * in.tint);
+  return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+  return in.color;
+}
@@ -0,0 +1,15 @@
+struct Frame {
+  view_proj: mat4x4<f32>,
+  camera_pos: vec3<f32>,
+  time: f32,
+  light_dirs: array<vec4<f32>, 4>,
+  exposure: f32,
+};
+
+@group(0) @binding(0)
+var<uniform> frame: Frame;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  return vec4<f32>(frame.camera_pos * frame.time * frame.exposure, 1.0) + frame.light_dirs[0];
+}
@@ -0,0 +1,14 @@
+struct Material {
+  roughness: f32,
+  metallic: f32,
+  layer: u32,
+  flags: u32,
+};
+
+@group(0) @binding(0)
+var<uniform> material: Material;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  return vec4<f32>(material.roughness, material.metallic, f32(material.layer), f32(material.flags));
+}
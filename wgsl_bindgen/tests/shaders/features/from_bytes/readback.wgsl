@@ -0,0 +1,26 @@
+struct Samples {
+  transform: mat4x4<f32>,
+  values: array<f32>,
+};
+
+struct Stats {
+  min: f32,
+  max: f32,
+  count: u32,
+  flags: u32,
+};
+
+@group(0) @binding(0)
+var<storage, read_write> samples: Samples;
+
+@group(0) @binding(1)
+var<storage, read_write> stats: Stats;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+  let value = (samples.transform * vec4<f32>(samples.values[id.x])).x;
+  samples.values[id.x] = value;
+  stats.min = min(stats.min, value);
+  stats.max = max(stats.max, value);
+  stats.count += 1u;
+}
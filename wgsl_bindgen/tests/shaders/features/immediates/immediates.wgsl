@@ -0,0 +1,37 @@
+struct DrawImmediates {
+  transform: mat4x4<f32>,
+  tint: vec4<f32>,
+}
+
+struct DispatchImmediates {
+  scale: f32,
+  count: u32,
+}
+
+var<immediate> draw: DrawImmediates;
+var<immediate> dispatch: DispatchImmediates;
+
+@group(0) @binding(0)
+var<storage, read_write> values: array<f32>;
+
+// The fragment stage reads `draw` through this helper only.
+fn tint() -> vec4<f32> {
+  return draw.tint;
+}
+
+@vertex
+fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
+  return draw.transform * vec4<f32>(position, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  return tint();
+}
+
+@compute @workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+  if id.x < dispatch.count {
+    values[id.x] = values[id.x] * dispatch.scale;
+  }
+}
@@ -0,0 +1,24 @@
+struct Particle {
+  position: vec4<f32>,
+  mass: f32,
+  id: u32,
+  age: f32,
+  flags: u32,
+};
+
+struct Particles {
+  time: f32,
+  count: u32,
+  seed: vec2<u32>,
+  particles: array<Particle>,
+};
+
+@group(0) @binding(0)
+var<storage, read_write> particles: Particles;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+  if id.x < particles.count {
+    particles.particles[id.x].age += particles.time;
+  }
+}
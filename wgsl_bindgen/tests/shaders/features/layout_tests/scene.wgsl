@@ -0,0 +1,36 @@
+struct Light {
+  position: vec3<f32>,
+  intensity: f32,
+  color: vec3<f32>,
+};
+
+struct Scene {
+  view: mat4x4<f32>,
+  normal: mat3x3<f32>,
+  ambient: vec3<f32>,
+  frame: u32,
+  jitter: vec2<i32>,
+  directions: array<vec3<f32>, 2>,
+  main_light: Light,
+  exposure: f32,
+};
+
+struct Lights {
+  count: u32,
+  directions: array<vec3<f32>>,
+};
+
+@group(0) @binding(0)
+var<uniform> scene: Scene;
+
+@group(0) @binding(1)
+var<storage, read> lights: Lights;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  var color = scene.ambient * scene.exposure;
+  for (var i = 0u; i < lights.count; i++) {
+    color += scene.main_light.color * max(dot(lights.directions[i], scene.ambient), 0.0);
+  }
+  return vec4<f32>(color, 1.0);
+}
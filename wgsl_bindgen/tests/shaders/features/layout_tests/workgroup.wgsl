@@ -0,0 +1,15 @@
+// `bool` has no host-shareable layout, so the layout test skips `visible`.
+struct Tile {
+  depth: f32,
+  visible: bool,
+  count: u32,
+}
+
+var<workgroup> tile: Tile;
+
+@compute @workgroup_size(1)
+fn main() {
+  if tile.visible {
+    tile.count = u32(tile.depth);
+  }
+}
@@ -0,0 +1,43 @@
+struct MeshVertex {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+}
+
+struct SpriteVertex {
+    @location(0) corner: vec2<f32>,
+}
+
+struct SpriteInstance {
+    @location(1) center: vec2<f32>,
+    @location(2) size: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_mesh(vertex: MeshVertex, @location(2) tint: vec4<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(vertex.position, 1.0);
+    out.color = vec4<f32>(vertex.normal * 0.5 + 0.5, 1.0) * tint;
+    return out;
+}
+
+@vertex
+fn vs_sprite(
+    vertex: SpriteVertex,
+    instance: SpriteInstance,
+    @location(3) layer: u32,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(instance.center + vertex.corner * instance.size, f32(layer), 1.0);
+    out.color = vec4<f32>(1.0);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
@@ -0,0 +1,26 @@
+override block_size: u32 = 64u;
+
+var<workgroup> partial_sums: array<f32, block_size>;
+
+@group(0) @binding(0)
+var<storage, read> input: array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output: array<f32>;
+
+@compute @workgroup_size(64)
+fn reduce(
+  @builtin(local_invocation_index) local_index: u32,
+  @builtin(workgroup_id) workgroup_id: vec3<u32>,
+) {
+  partial_sums[local_index] = input[workgroup_id.x * block_size + local_index];
+  workgroupBarrier();
+
+  if local_index == 0u {
+    var sum = 0.0;
+    for (var i = 0u; i < block_size; i++) {
+      sum += partial_sums[i];
+    }
+    output[workgroup_id.x] = sum;
+  }
+}
@@ -0,0 +1,3 @@
+#define_import_path shared
+
+const SCALE: f32 = 2.0;
@@ -0,0 +1,14 @@
+// naga's WGSL writer renames identifiers ending in a digit.
+override tile_size2: u32 = 8u;
+
+var<workgroup> tile: array<u32, tile_size2>;
+
+@group(0) @binding(0)
+var<storage, read_write> counts: array<u32>;
+
+@compute @workgroup_size(8)
+fn count(@builtin(local_invocation_index) local_index: u32) {
+  tile[local_index] = counts[local_index];
+  workgroupBarrier();
+  counts[local_index] = tile[tile_size2 - 1u - local_index];
+}
@@ -0,0 +1,10 @@
+#import shared::SCALE
+
+override block_size: u32 = 64u;
+
+var<workgroup> partial_sums: array<f32, block_size>;
+
+@compute @workgroup_size(64)
+fn scale(@builtin(local_invocation_index) local_index: u32) {
+  partial_sums[local_index] *= SCALE;
+}
@@ -0,0 +1,12 @@
+struct Path {
+  points: array<vec3<f32>, 4>,
+  points_at: array<vec3<f32>, 4>,
+};
+
+@group(0) @binding(0)
+var<uniform> path: Path;
+
+@compute @workgroup_size(1)
+fn main() {
+  let length = distance(path.points[0], path.points_at[3]);
+}
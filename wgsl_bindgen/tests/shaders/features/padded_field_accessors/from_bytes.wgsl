@@ -0,0 +1,12 @@
+struct Samples {
+  from_bytes: array<vec3<f32>, 2>,
+  from_texels: array<vec3<f32>, 2>,
+};
+
+@group(0) @binding(0)
+var<uniform> samples: Samples;
+
+@compute @workgroup_size(1)
+fn main() {
+  let delta = distance(samples.from_bytes[0], samples.from_texels[0]);
+}
@@ -0,0 +1,15 @@
+struct Lights {
+  colors: array<vec3<f32>, 4>,
+  cascades: array<array<vec3<f32>, 2>, 3>,
+  normal_matrix: mat3x3<f32>,
+  count: u32,
+};
+
+@group(0) @binding(0)
+var<uniform> lights: Lights;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  let n = lights.normal_matrix * lights.cascades[2][1];
+  return vec4<f32>(lights.colors[lights.count] + n, 1.0);
+}
@@ -0,0 +1,28 @@
+struct Doubles {
+  a: vec2<f64>,
+  b: vec3<f64>,
+  c: f64,
+  d: vec4<f64>,
+  e: mat2x2<f64>,
+  f: mat3x3<f64>,
+  g: mat4x4<f64>,
+}
+
+struct Longs {
+  a: vec2<i64>,
+  b: vec3<i64>,
+  c: vec4<i64>,
+  d: vec2<u64>,
+  e: vec3<u64>,
+  f: u64,
+  g: vec4<u64>,
+}
+
+@group(0) @binding(0) var<storage, read_write> doubles: Doubles;
+@group(0) @binding(1) var<storage, read_write> longs: Longs;
+
+@compute @workgroup_size(1)
+fn main() {
+  doubles.d = doubles.g * doubles.d;
+  longs.g = longs.g + vec4<u64>(longs.f);
+}
@@ -0,0 +1,31 @@
+struct Vectors {
+  a: vec2<i32>,
+  b: vec3<i32>,
+  c: vec4<i32>,
+  d: vec2<u32>,
+  e: vec3<u32>,
+  f: vec4<u32>,
+  g: vec2<f32>,
+  h: vec3<f32>,
+  i: vec4<f32>,
+}
+
+struct Matrices {
+  a: mat2x2<f32>,
+  b: mat3x2<f32>,
+  c: mat4x2<f32>,
+  d: mat2x3<f32>,
+  e: mat3x3<f32>,
+  f: mat4x3<f32>,
+  g: mat2x4<f32>,
+  h: mat3x4<f32>,
+  i: mat4x4<f32>,
+}
+
+@group(0) @binding(0) var<storage, read_write> vectors: Vectors;
+@group(0) @binding(1) var<uniform> matrices: Matrices;
+
+@compute @workgroup_size(1)
+fn main() {
+  vectors.i = matrices.i * vectors.i;
+}
@@ -0,0 +1,22 @@
+@group(0) @binding(0) var color_texture: texture_2d<f32>;
+@group(0) @binding(1) var height_texture: texture_2d<f32>;
+@group(0) @binding(2) var environment: texture_cube<f32>;
+@group(0) @binding(3) var shadow_maps: texture_depth_2d_array;
+@group(0) @binding(4) var msaa_ids: texture_multisampled_2d<u32>;
+@group(0) @binding(5) var volume: texture_3d<i32>;
+@group(0) @binding(6) var color_sampler: sampler;
+
+@group(1) @binding(0) var output: texture_storage_2d<rgba8unorm, write>;
+@group(1) @binding(1) var counters: texture_storage_3d<r32uint, read_write>;
+
+@compute @workgroup_size(1)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let color = textureSampleLevel(color_texture, color_sampler, vec2<f32>(0.5), 0.0)
+        + textureLoad(height_texture, id.xy, 0)
+        + textureSampleLevel(environment, color_sampler, vec3<f32>(1.0), 0.0)
+        + vec4<f32>(textureLoad(shadow_maps, id.xy, 0, 0))
+        + vec4<f32>(textureLoad(msaa_ids, id.xy, 0))
+        + vec4<f32>(textureLoad(volume, id, 0));
+    textureStore(output, id.xy, color);
+    textureStore(counters, id, textureLoad(counters, id) + vec4<u32>(1u));
+}
@@ -0,0 +1,13 @@
+struct Material {
+  base_color: vec4<f32>,
+  roughness: f32,
+  weights: array<f32, 4>,
+};
+
+@group(0) @binding(0)
+var<uniform> material: Material;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  return material.base_color * material.roughness * material.weights[0];
+}
@@ -0,0 +1,18 @@
+@group(0) @binding(1)
+var<storage, read> flags: array<bool, 4>;
+
+struct Material {
+  base_color: vec4<f32>,
+  weights: array<f32, 4>,
+};
+
+@group(0) @binding(0)
+var<uniform> material: Material;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  if flags[0] {
+    return material.base_color * material.weights[0];
+  }
+  return material.base_color;
+}
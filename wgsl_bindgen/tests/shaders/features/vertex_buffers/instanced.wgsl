@@ -0,0 +1,27 @@
+struct Vertex {
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+}
+
+struct Instance {
+    @location(2) offset: vec4<f32>,
+    @location(3) color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(vertex: Vertex, instance: Instance, @location(4) scale: f32) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(vertex.position * scale, 1.0) + instance.offset;
+    out.color = instance.color * vec4<f32>(vertex.uv, 0.0, 1.0);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
@@ -0,0 +1,25 @@
+struct MeshVertex {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec4<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) uv: vec2<f32>,
+    @location(4) material: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(vertex: MeshVertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(vertex.position + vertex.normal.xyz, 1.0);
+    out.color = vertex.color * vec4<f32>(vertex.uv, f32(vertex.material), 1.0);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
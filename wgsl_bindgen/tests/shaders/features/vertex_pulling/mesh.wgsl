@@ -0,0 +1,25 @@
+struct MeshVertex {
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) material: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) @interpolate(flat) material: u32,
+}
+
+@vertex
+fn vs_main(vertex: MeshVertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(vertex.position, 1.0);
+    out.uv = vertex.uv;
+    out.material = vertex.material;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(in.uv, f32(in.material), 1.0);
+}
@@ -0,0 +1,26 @@
+struct SpriteVertex {
+    @location(0) position: vec4<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) material: u32,
+    @location(3) layer: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) @interpolate(flat) material: u32,
+}
+
+@vertex
+fn vs_main(vertex: SpriteVertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vertex.position + vec4<f32>(0.0, 0.0, f32(vertex.layer), 0.0);
+    out.uv = vertex.uv;
+    out.material = vertex.material;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(in.uv, f32(in.material), 1.0);
+}
@@ -0,0 +1,46 @@
+struct Globals {
+  light_dirs: array<vec3<f32>, 4>,
+  time: f32,
+  exposure: f32,
+};
+
+struct Particle {
+  position: vec3<f32>,
+  mass: f32,
+  velocity: vec3<f32>,
+};
+
+// The tail stride matches the struct alignment, so this derives `IntoBytes`.
+struct ParticleList {
+  count: u32,
+  particles: array<Particle>,
+};
+
+// A `vec4` header with a scalar tail leaves trailing padding for most lengths.
+struct Histogram {
+  range: vec4<f32>,
+  bins: array<u32>,
+};
+
+// Runtime-sized tail whose elements need padding to their stride.
+struct Normals {
+  scale: f32,
+  normals: array<vec3<f32>>,
+};
+
+struct VertexInput {
+  @location(0) position: vec3<f32>,
+  @location(1) uv: vec2<f32>,
+};
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+@group(0) @binding(1) var<storage, read_write> particles: ParticleList;
+@group(0) @binding(2) var<storage, read_write> histogram: Histogram;
+@group(0) @binding(3) var<storage, read> normals: Normals;
+
+@vertex
+fn vs_main(in: VertexInput) -> @builtin(position) vec4<f32> {
+  let p = particles.particles[0].position + normals.normals[0] * normals.scale;
+  histogram.bins[0] = particles.count;
+  return vec4<f32>(in.position + p + globals.light_dirs[0] * globals.time, in.uv.x);
+}
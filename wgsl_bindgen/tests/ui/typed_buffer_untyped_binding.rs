@@ -0,0 +1,18 @@
+include!("../output/typed_buffer_bindings.actual.rs");
+
+// A plain `wgpu::BufferBinding` has no type to check, so it must be wrapped.
+fn bind(
+  device: &wgpu::Device,
+  camera: &common::Camera,
+  particles: &wgpu::Buffer,
+  lights: &wgpu::Buffer,
+) {
+  let camera = common::Camera::create_buffer(device, camera);
+  render::WgpuBindGroup0Entries::new(render::WgpuBindGroup0EntriesParams {
+    camera: camera.binding(),
+    particles: particles.as_entire_buffer_binding(),
+    lights: typed_buffer::TypedBuffer::new_unchecked(lights.as_entire_buffer_binding()),
+  });
+}
+
+fn main() {}
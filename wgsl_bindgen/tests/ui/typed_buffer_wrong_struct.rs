@@ -0,0 +1,15 @@
+include!("../output/typed_buffer_bindings.actual.rs");
+
+// A buffer of `Particle` cannot be bound where the shader reads a `Camera`.
+fn bind(device: &wgpu::Device, camera: &common::Camera, particle: &common::Particle) {
+  let camera = common::Camera::create_buffer(device, camera);
+  let particle = common::Particle::create_buffer(device, particle);
+  let trail = common::Trail::create_storage_buffer_with_len(device, 64);
+  simulate::WgpuBindGroup0Entries::new(simulate::WgpuBindGroup0EntriesParams {
+    camera: particle.binding(),
+    particle: camera.binding(),
+    trail: trail.binding(),
+  });
+}
+
+fn main() {}
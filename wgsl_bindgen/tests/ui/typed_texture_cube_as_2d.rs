@@ -0,0 +1,25 @@
+include!("../output/typed_texture_views.actual.rs");
+
+// A cube view cannot be bound where the shader samples a 2D texture.
+fn bind(
+  environment: &typed_texture::TextureViewCube<typed_texture::Float>,
+  height_texture: &typed_texture::TextureView2d<typed_texture::UnfilterableFloat>,
+  shadow_maps: &typed_texture::TextureView2dArray<typed_texture::Depth>,
+  msaa_ids: &typed_texture::TextureView2d<
+    typed_texture::Multisampled<typed_texture::Uint>,
+  >,
+  volume: &typed_texture::TextureView3d<typed_texture::Sint>,
+  color_sampler: &wgpu::Sampler,
+) {
+  textures::WgpuBindGroup0Entries::new(textures::WgpuBindGroup0EntriesParams {
+    color_texture: environment,
+    height_texture,
+    environment,
+    shadow_maps,
+    msaa_ids,
+    volume,
+    color_sampler,
+  });
+}
+
+fn main() {}